@@ -6,6 +6,8 @@ use crate::benchmark::{BenchmarkSubmission, SubmissionResponse};
 use crate::config::Config;
 use crate::feedback::{FeedbackBackendResponse, FeedbackSubmission};
 use crate::idempotency;
+use crate::import::bottleneck::BottleneckBreakdown;
+use crate::import::TelemetrySummary;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -48,6 +50,26 @@ struct TrackerHardwarePayload {
     os: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     driver_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gpu_pci_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gpu_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gpu_selection: Option<crate::hardware::adapters::GpuSelectionReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cpu_l3_cache_mb: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cpu_ccx_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cpu_p_cores: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cpu_e_cores: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    motherboard: Option<crate::hardware::board::MotherboardInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    drives: Option<Vec<crate::hardware::drives::DriveInfo>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -79,6 +101,16 @@ struct TrackerSessionPayload {
     #[serde(skip_serializing_if = "Option::is_none")]
     synthetic_extended: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    telemetry: Option<TelemetrySummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bottleneck: Option<BottleneckBreakdown>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_hz: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frames_slower_than_refresh_pct: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    power: Option<crate::hardware::power::PowerSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     session_duration_minutes: Option<u32>,
 }
 
@@ -603,15 +635,67 @@ fn to_tracker_submission_payload_with_extended(
         (None, None) => None,
     };
 
+    let topology = submission
+        .system_info
+        .cpu
+        .topology
+        .as_ref()
+        .filter(|_| include_extended);
+
     TrackerSubmissionPayload {
         hardware: TrackerHardwarePayload {
             gpu: submission.system_info.gpu.name.clone(),
             cpu: submission.system_info.cpu.name.clone(),
             ram_gb,
             ram_speed,
-            storage_type: None,
+            storage_type: submission
+                .system_info
+                .fastest_drive_kind()
+                .map(|kind| kind.to_string()),
             os,
             driver_version: submission.system_info.gpu.driver_version.clone(),
+            fingerprint: if include_extended {
+                submission
+                    .hardware_fingerprint
+                    .clone()
+                    .or_else(|| Some(submission.system_info.hardware_fingerprint()))
+            } else {
+                None
+            },
+            gpu_pci_id: if include_extended {
+                submission.system_info.gpu.pci_id.clone()
+            } else {
+                None
+            },
+            gpu_count: if include_extended && !submission.system_info.gpus.is_empty() {
+                Some(submission.system_info.gpus.len() as u32)
+            } else {
+                None
+            },
+            gpu_selection: if include_extended {
+                submission
+                    .system_info
+                    .gpu_selection
+                    .as_ref()
+                    .map(|selection| selection.reason)
+            } else {
+                None
+            },
+            cpu_l3_cache_mb: topology
+                .and_then(|topology| topology.l3_cache_kb())
+                .map(|kb| (kb / 1024) as u32),
+            cpu_ccx_count: topology
+                .map(|topology| topology.l3_domains.len() as u32)
+                .filter(|count| *count > 0),
+            cpu_p_cores: topology.and_then(|topology| topology.performance_cores.map(|n| n as u32)),
+            cpu_e_cores: topology.and_then(|topology| topology.efficiency_cores.map(|n| n as u32)),
+            motherboard: submission
+                .system_info
+                .motherboard
+                .clone()
+                .filter(|_| include_extended),
+            drives: Some(submission.system_info.drives.clone())
+                .filter(|drives| include_extended && !drives.is_empty()),
         },
         sessions: vec![TrackerSessionPayload {
             game: submission.game.clone(),
@@ -654,6 +738,27 @@ fn to_tracker_submission_payload_with_extended(
             } else {
                 None
             },
+            telemetry: if include_extended {
+                submission.telemetry.clone()
+            } else {
+                None
+            },
+            bottleneck: if include_extended {
+                submission.bottleneck.clone()
+            } else {
+                None
+            },
+            refresh_hz: submission
+                .refresh_pacing
+                .as_ref()
+                .filter(|_| include_extended)
+                .map(|pacing| pacing.refresh_hz),
+            frames_slower_than_refresh_pct: submission
+                .refresh_pacing
+                .as_ref()
+                .filter(|_| include_extended)
+                .map(|pacing| pacing.frames_slower_than_refresh_pct),
+            power: submission.power.clone().filter(|_| include_extended),
             session_duration_minutes,
         }],
         tracker_version: Some(env!("CARGO_PKG_VERSION").to_string()),
@@ -931,6 +1036,7 @@ mod tests {
                     memory_clock_mhz: None,
                     temperature_c: None,
                     utilization_percent: None,
+                    power_w: None,
                 },
                 cpu: CpuInfo {
                     name: "AMD Ryzen 7 7800X3D".to_string(),
@@ -940,6 +1046,7 @@ mod tests {
                     vendor: "AMD".to_string(),
                     architecture: Some("x86_64".to_string()),
                     max_frequency_mhz: Some(5000),
+                    topology: None,
                 },
                 ram: RamInfo {
                     installed_mb: Some(32_768),
@@ -951,6 +1058,13 @@ mod tests {
                 },
                 os: "Windows".to_string(),
                 os_version: Some("11".to_string()),
+                kernel_version: None,
+                gpus: Vec::new(),
+                gpu_selection: None,
+                graphics_stack: None,
+                displays: Vec::new(),
+                motherboard: None,
+                drives: Vec::new(),
             },
             game: "Cyberpunk 2077".to_string(),
             resolution: "1440p".to_string(),
@@ -980,6 +1094,11 @@ mod tests {
             synthetic_profile: None,
             synthetic_suite_version: None,
             synthetic_extended: None,
+            telemetry: None,
+            bottleneck: None,
+            hardware_fingerprint: None,
+            refresh_pacing: None,
+            power: None,
             notes: None,
         }
     }
@@ -1081,6 +1200,160 @@ mod tests {
         assert_eq!(payload.sessions[0].synthetic_disk_score, Some(777));
     }
 
+    #[test]
+    fn test_payload_telemetry_is_optional_and_dropped_for_legacy() {
+        let mut submission = submission_with_scores(None, None, None, None);
+        let payload = serde_json::to_value(to_tracker_submission_payload(&submission, true, true))
+            .expect("payload json");
+        assert!(payload["sessions"][0].get("telemetry").is_none());
+
+        submission.telemetry = crate::import::FrameTelemetry {
+            gpu_load_pct: vec![97.0, 99.0],
+            ..Default::default()
+        }
+        .summary();
+        let payload = serde_json::to_value(to_tracker_submission_payload(&submission, true, true))
+            .expect("payload json");
+        assert_eq!(
+            payload["sessions"][0]["telemetry"]["gpu_load_pct"]["max"],
+            serde_json::json!(99.0)
+        );
+
+        let legacy = to_tracker_submission_payload_with_extended(&submission, true, true, false);
+        assert!(legacy.sessions[0].telemetry.is_none());
+    }
+
+    #[test]
+    fn test_payload_hardware_fingerprint_falls_back_to_system_info() {
+        let submission = submission_with_scores(None, None, None, None);
+        assert!(submission.hardware_fingerprint.is_none());
+
+        let payload = to_tracker_submission_payload(&submission, true, true);
+        assert_eq!(
+            payload.hardware.fingerprint,
+            Some(submission.system_info.hardware_fingerprint())
+        );
+
+        let legacy = to_tracker_submission_payload_with_extended(&submission, true, true, false);
+        assert!(legacy.hardware.fingerprint.is_none());
+    }
+
+    #[test]
+    fn test_payload_records_selected_gpu_on_hybrid_systems() {
+        use crate::hardware::adapters::{GpuAdapter, GpuSelection, GpuSelectionReason};
+        use crate::hardware::gpu::GpuVendor;
+
+        let mut submission = submission_with_scores(None, None, None, None);
+        let payload = serde_json::to_value(to_tracker_submission_payload(&submission, true, true))
+            .expect("payload json");
+        assert!(payload["hardware"].get("gpu_selection").is_none());
+
+        let adapter = |name: &str, vendor, integrated| GpuAdapter {
+            name: name.to_string(),
+            vendor,
+            vram_mb: None,
+            pci_id: None,
+            pci_slot: None,
+            driver: None,
+            driver_version: None,
+            integrated,
+            boot_vga: integrated,
+        };
+        submission.system_info.gpus = vec![
+            adapter("Intel Iris Xe Graphics", GpuVendor::Intel, true),
+            adapter(
+                "NVIDIA GeForce RTX 4060 Laptop GPU",
+                GpuVendor::Nvidia,
+                false,
+            ),
+        ];
+        submission.system_info.gpu_selection = Some(GpuSelection {
+            adapter_index: 1,
+            reason: GpuSelectionReason::DriPrime,
+        });
+        submission.system_info.gpu.pci_id = Some("10de:28e0".to_string());
+
+        let payload = serde_json::to_value(to_tracker_submission_payload(&submission, true, true))
+            .expect("payload json");
+        assert_eq!(payload["hardware"]["gpu_selection"], "dri_prime");
+        assert_eq!(payload["hardware"]["gpu_count"], 2);
+        assert_eq!(payload["hardware"]["gpu_pci_id"], "10de:28e0");
+
+        let legacy = to_tracker_submission_payload_with_extended(&submission, true, true, false);
+        assert!(legacy.hardware.gpu_selection.is_none());
+        assert!(legacy.hardware.gpu_count.is_none());
+    }
+
+    #[test]
+    fn test_payload_includes_cpu_topology_when_extended() {
+        use crate::hardware::cpu::{CpuTopology, L3Domain};
+
+        let mut submission = submission_with_scores(None, None, None, None);
+        submission.system_info.cpu.topology = Some(CpuTopology {
+            l2_cache_kb: Some(1024),
+            l3_domains: vec![
+                L3Domain {
+                    size_kb: 98304,
+                    cores: 8,
+                },
+                L3Domain {
+                    size_kb: 32768,
+                    cores: 8,
+                },
+            ],
+            performance_cores: None,
+            efficiency_cores: None,
+        });
+
+        let payload = to_tracker_submission_payload(&submission, true, true);
+        assert_eq!(payload.hardware.cpu_l3_cache_mb, Some(128));
+        assert_eq!(payload.hardware.cpu_ccx_count, Some(2));
+        assert!(payload.hardware.cpu_e_cores.is_none());
+
+        let legacy = to_tracker_submission_payload_with_extended(&submission, true, true, false);
+        assert!(legacy.hardware.cpu_l3_cache_mb.is_none());
+    }
+
+    #[test]
+    fn test_payload_includes_board_and_drives_when_extended() {
+        use crate::hardware::board::MotherboardInfo;
+        use crate::hardware::drives::{DriveInfo, DriveKind};
+
+        let mut submission = submission_with_scores(None, None, None, None);
+        submission.system_info.motherboard = Some(MotherboardInfo {
+            vendor: Some("Micro-Star International Co., Ltd.".to_string()),
+            model: Some("MAG B650 TOMAHAWK WIFI (MS-7D75)".to_string()),
+            bios_vendor: None,
+            bios_version: Some("1.A0".to_string()),
+            bios_date: None,
+        });
+        submission.system_info.drives = vec![
+            DriveInfo {
+                device: "sda".to_string(),
+                model: Some("ST4000DM004".to_string()),
+                kind: DriveKind::Hdd,
+                capacity_gb: 4000,
+            },
+            DriveInfo {
+                device: "nvme0n1".to_string(),
+                model: Some("WD_BLACK SN850X 1000GB".to_string()),
+                kind: DriveKind::Nvme,
+                capacity_gb: 1000,
+            },
+        ];
+
+        let payload = serde_json::to_value(to_tracker_submission_payload(&submission, true, true))
+            .expect("payload json");
+        assert_eq!(payload["hardware"]["storage_type"], "NVMe SSD");
+        assert_eq!(payload["hardware"]["motherboard"]["bios_version"], "1.A0");
+        assert_eq!(payload["hardware"]["drives"][1]["kind"], "nvme");
+
+        let legacy = to_tracker_submission_payload_with_extended(&submission, true, true, false);
+        assert_eq!(legacy.hardware.storage_type.as_deref(), Some("NVMe SSD"));
+        assert!(legacy.hardware.motherboard.is_none());
+        assert!(legacy.hardware.drives.is_none());
+    }
+
     #[test]
     fn test_payload_scene_tag_includes_synthetic_profile() {
         let mut submission = submission_with_scores(Some(5000), None, None, None);
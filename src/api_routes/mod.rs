@@ -4,7 +4,7 @@ use crate::deps;
 use crate::feedback::{
     self, FeedbackCategory, FeedbackSchema, FeedbackSubmission, FeedbackSurface,
 };
-use crate::games::KNOWN_GAMES;
+use crate::games::{known_games, Upscaler};
 use crate::hardware::SystemInfo;
 use axum::{
     extract::{Json, Query},
@@ -115,7 +115,10 @@ struct GameResponse {
     supports_rt: bool,
     supports_dlss: bool,
     supports_fsr: bool,
+    upscalers: Vec<Upscaler>,
+    frame_generation: Vec<Upscaler>,
     anti_cheat_risk: String,
+    process_names: Vec<String>,
     benchmark_notes: String,
 }
 
@@ -254,7 +257,7 @@ async fn detect_hardware() -> impl IntoResponse {
 }
 
 async fn list_games(Query(filter): Query<GameFilter>) -> Json<Vec<GameResponse>> {
-    let games = KNOWN_GAMES
+    let games = known_games()
         .iter()
         .filter(|g| {
             if let Some(diff) = &filter.difficulty {
@@ -271,37 +274,23 @@ async fn list_games(Query(filter): Query<GameFilter>) -> Json<Vec<GameResponse>>
         })
         .map(|g| GameResponse {
             id: g.name.to_lowercase().replace(" ", "-"),
-            name: g.name.to_string(),
+            name: g.name.clone(),
             has_benchmark: g.has_benchmark,
             difficulty: g.difficulty.to_string().to_lowercase(),
             supports_rt: g.supports_rt,
-            supports_dlss: g.supports_dlss,
-            supports_fsr: g.supports_fsr,
-            anti_cheat_risk: game_anti_cheat_risk(g.name).to_string(),
-            benchmark_notes: g.benchmark_notes.to_string(),
+            supports_dlss: g.supports(Upscaler::Dlss),
+            supports_fsr: g.supports(Upscaler::Fsr),
+            upscalers: g.upscalers.clone(),
+            frame_generation: g.frame_generation.clone(),
+            anti_cheat_risk: g.anti_cheat.to_string().to_lowercase(),
+            process_names: g.process_names.clone(),
+            benchmark_notes: g.benchmark_notes.clone(),
         })
         .collect();
 
     Json(games)
 }
 
-fn game_anti_cheat_risk(game_name: &str) -> &'static str {
-    match game_name {
-        "Valorant" | "League of Legends" => "high",
-        "Counter-Strike 2"
-        | "Fortnite"
-        | "Apex Legends"
-        | "Call of Duty: Warzone"
-        | "PUBG: BATTLEGROUNDS"
-        | "Tom Clancy's Rainbow Six Siege"
-        | "Destiny 2"
-        | "The Finals"
-        | "Delta Force"
-        | "Marvel Rivals" => "medium",
-        _ => "low",
-    }
-}
-
 async fn consent_status() -> impl IntoResponse {
     let config = Config::load().unwrap_or_default();
     let consent = &config.consent;
@@ -931,6 +920,7 @@ mod tests {
                 memory_clock_mhz: None,
                 temperature_c: None,
                 utilization_percent: None,
+                power_w: None,
             },
             cpu: CpuInfo {
                 name: "AMD Ryzen 7 7800X3D".to_string(),
@@ -940,6 +930,7 @@ mod tests {
                 vendor: "AMD".to_string(),
                 architecture: Some("x86_64".to_string()),
                 max_frequency_mhz: Some(5000),
+                topology: None,
             },
             ram: RamInfo {
                 installed_mb: Some(32_768),
@@ -951,6 +942,13 @@ mod tests {
             },
             os: "Linux".to_string(),
             os_version: Some("6.8".to_string()),
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
         }
     }
 
@@ -0,0 +1,489 @@
+//! A/B comparison of two captures
+//!
+//! Every frame metric is reported side by side with its delta. Two tests
+//! judge whether the difference is real. The first is a Mann-Whitney U test
+//! on the frame time distributions. The second is a bootstrap confidence
+//! interval on the average FPS delta.
+//!
+//! Neighbouring frames are correlated, so the bootstrap resamples blocks of
+//! consecutive frames rather than single frames. The resampler uses a fixed
+//! seed, so comparing the same two captures always prints the same interval.
+//! Sampled captures weight every row equally, as `calculate_stats` does.
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::import::{BenchmarkResult, FrameData};
+
+/// Frame-time ceiling used when validating frames, matching `calculate_stats`.
+const MAX_FRAME_TIME_MS: f64 = 1000.0;
+/// Significance level for the Mann-Whitney test.
+pub const SIGNIFICANCE_ALPHA: f64 = 0.05;
+/// Confidence level of the bootstrap interval.
+const CONFIDENCE: f64 = 0.95;
+/// Bootstrap resamples per comparison.
+const BOOTSTRAP_RESAMPLES: usize = 1000;
+const BOOTSTRAP_SEED: u64 = 0x5eed_f00d_cafe_b0ba;
+
+/// One capture in the comparison.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparedCapture {
+    /// File path or capture id as given on the command line
+    pub label: String,
+    pub source: String,
+    pub application: Option<String>,
+    pub frame_count: usize,
+    pub duration_secs: f64,
+}
+
+/// A metric measured on both captures.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricDelta {
+    pub metric: &'static str,
+    pub unit: &'static str,
+    pub a: f64,
+    pub b: f64,
+    /// `b - a`
+    pub delta: f64,
+    /// `(b - a) / a` in percent; `None` when `a` is zero
+    pub delta_pct: Option<f64>,
+    /// Whether a larger value is an improvement (FPS) or a regression (frame time)
+    pub higher_is_better: bool,
+}
+
+impl MetricDelta {
+    fn new(
+        metric: &'static str,
+        unit: &'static str,
+        a: f64,
+        b: f64,
+        higher_is_better: bool,
+    ) -> Self {
+        Self {
+            metric,
+            unit,
+            a,
+            b,
+            delta: b - a,
+            delta_pct: if a != 0.0 {
+                Some((b - a) / a * 100.0)
+            } else {
+                None
+            },
+            higher_is_better,
+        }
+    }
+
+    /// Whether B is better than A on this metric.
+    pub fn improved(&self) -> bool {
+        if self.higher_is_better {
+            self.delta > 0.0
+        } else {
+            self.delta < 0.0
+        }
+    }
+}
+
+/// Mann-Whitney U test on frame times (normal approximation, tie-corrected).
+#[derive(Debug, Clone, Serialize)]
+pub struct MannWhitney {
+    /// U statistic for capture A
+    pub u: f64,
+    pub z: f64,
+    /// Two-sided p-value
+    pub p_value: f64,
+    /// Probability that a random B frame is slower than a random A frame
+    pub prob_b_slower: f64,
+}
+
+/// Bootstrap confidence interval.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfidenceInterval {
+    pub confidence: f64,
+    pub low: f64,
+    pub high: f64,
+}
+
+impl ConfidenceInterval {
+    pub fn excludes_zero(&self) -> bool {
+        self.low > 0.0 || self.high < 0.0
+    }
+}
+
+/// Full result of `fps-tracker compare`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureComparison {
+    pub a: ComparedCapture,
+    pub b: ComparedCapture,
+    pub metrics: Vec<MetricDelta>,
+    pub mann_whitney: Option<MannWhitney>,
+    /// Interval for the average FPS delta (B - A)
+    pub avg_fps_delta_ci: Option<ConfidenceInterval>,
+    /// Both tests agree that the captures differ
+    pub significant: bool,
+}
+
+/// Compare two captures; `a` is the baseline.
+pub fn compare_captures(
+    a_label: &str,
+    a: &FrameData,
+    b_label: &str,
+    b: &FrameData,
+) -> Result<CaptureComparison> {
+    let stats_a = a
+        .calculate_stats_with_max_frame_time(MAX_FRAME_TIME_MS)
+        .ok_or_else(|| anyhow::anyhow!("Capture A ({}) has no valid frame times", a_label))?;
+    let stats_b = b
+        .calculate_stats_with_max_frame_time(MAX_FRAME_TIME_MS)
+        .ok_or_else(|| anyhow::anyhow!("Capture B ({}) has no valid frame times", b_label))?;
+
+    let frames_a = a.sanitized_frame_times(MAX_FRAME_TIME_MS);
+    let frames_b = b.sanitized_frame_times(MAX_FRAME_TIME_MS);
+    let mann_whitney = mann_whitney(&frames_a, &frames_b);
+    let avg_fps_delta_ci = bootstrap_avg_fps_delta((&frames_a, a.sampled), (&frames_b, b.sampled));
+    let significant = mann_whitney
+        .as_ref()
+        .is_some_and(|test| test.p_value < SIGNIFICANCE_ALPHA)
+        && avg_fps_delta_ci
+            .as_ref()
+            .is_some_and(ConfidenceInterval::excludes_zero);
+
+    Ok(CaptureComparison {
+        a: compared_capture(a_label, &stats_a),
+        b: compared_capture(b_label, &stats_b),
+        metrics: metric_deltas(&stats_a, &stats_b),
+        mann_whitney,
+        avg_fps_delta_ci,
+        significant,
+    })
+}
+
+fn compared_capture(label: &str, stats: &BenchmarkResult) -> ComparedCapture {
+    ComparedCapture {
+        label: label.to_string(),
+        source: stats.source.clone(),
+        application: stats.application.clone(),
+        frame_count: stats.frame_count,
+        duration_secs: stats.duration_secs,
+    }
+}
+
+fn metric_deltas(a: &BenchmarkResult, b: &BenchmarkResult) -> Vec<MetricDelta> {
+    let (pa, pb) = (&a.frame_time_stats, &b.frame_time_stats);
+    let mut metrics = vec![MetricDelta::new(
+        "Average FPS",
+        "fps",
+        a.avg_fps,
+        b.avg_fps,
+        true,
+    )];
+    if let (Some(low_a), Some(low_b)) = (a.fps_1_low, b.fps_1_low) {
+        metrics.push(MetricDelta::new("1% Low FPS", "fps", low_a, low_b, true));
+    }
+    if let (Some(low_a), Some(low_b)) = (a.fps_01_low, b.fps_01_low) {
+        metrics.push(MetricDelta::new("0.1% Low FPS", "fps", low_a, low_b, true));
+    }
+    if let (Some(low_a), Some(low_b)) = (pa.fps_1_low_avg, pb.fps_1_low_avg) {
+        metrics.push(MetricDelta::new("1% Low (avg)", "fps", low_a, low_b, true));
+    }
+    metrics.extend([
+        MetricDelta::new("Min FPS", "fps", a.min_fps, b.min_fps, true),
+        MetricDelta::new("Max FPS", "fps", a.max_fps, b.max_fps, true),
+        MetricDelta::new(
+            "Avg frame time",
+            "ms",
+            a.avg_frame_time_ms,
+            b.avg_frame_time_ms,
+            false,
+        ),
+        MetricDelta::new("Median frame time", "ms", pa.median_ms, pb.median_ms, false),
+        MetricDelta::new("P95 frame time", "ms", pa.p95_ms, pb.p95_ms, false),
+        MetricDelta::new("P99 frame time", "ms", pa.p99_ms, pb.p99_ms, false),
+    ]);
+    if let (Some(p999_a), Some(p999_b)) = (pa.p999_ms, pb.p999_ms) {
+        metrics.push(MetricDelta::new(
+            "P99.9 frame time",
+            "ms",
+            p999_a,
+            p999_b,
+            false,
+        ));
+    }
+    metrics.extend([
+        MetricDelta::new("Std dev", "ms", pa.stddev_ms, pb.stddev_ms, false),
+        MetricDelta::new(
+            "Jitter avg",
+            "ms",
+            pa.jitter_avg_ms,
+            pb.jitter_avg_ms,
+            false,
+        ),
+        MetricDelta::new(
+            "Jitter p99",
+            "ms",
+            pa.jitter_p99_ms,
+            pb.jitter_p99_ms,
+            false,
+        ),
+        MetricDelta::new(
+            "Jitter max",
+            "ms",
+            pa.jitter_max_ms,
+            pb.jitter_max_ms,
+            false,
+        ),
+        MetricDelta::new(
+            "Time below threshold",
+            "%",
+            pa.time_below_threshold_pct,
+            pb.time_below_threshold_pct,
+            false,
+        ),
+    ]);
+    metrics
+}
+
+/// Mann-Whitney U on two samples, or `None` if either is empty.
+fn mann_whitney(a: &[f64], b: &[f64]) -> Option<MannWhitney> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let n = n_a + n_b;
+
+    let mut combined: Vec<(f64, bool)> = a
+        .iter()
+        .map(|v| (*v, true))
+        .chain(b.iter().map(|v| (*v, false)))
+        .collect();
+    combined.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Average ranks over ties, collecting the tie correction as we go.
+    let mut rank_sum_a = 0.0;
+    let mut tie_term = 0.0;
+    let mut start = 0;
+    while start < combined.len() {
+        let mut end = start + 1;
+        while end < combined.len() && combined[end].0 == combined[start].0 {
+            end += 1;
+        }
+        let ties = (end - start) as f64;
+        let avg_rank = (start + end + 1) as f64 / 2.0;
+        rank_sum_a += avg_rank
+            * combined[start..end]
+                .iter()
+                .filter(|(_, in_a)| *in_a)
+                .count() as f64;
+        tie_term += ties.powi(3) - ties;
+        start = end;
+    }
+
+    let u = rank_sum_a - n_a * (n_a + 1.0) / 2.0;
+    let mean = n_a * n_b / 2.0;
+    let variance = n_a * n_b / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)).max(1.0));
+    let z = if variance > 0.0 {
+        let corrected = (u - mean).abs() - 0.5;
+        corrected.max(0.0).copysign(u - mean) / variance.sqrt()
+    } else {
+        0.0
+    };
+
+    Some(MannWhitney {
+        u,
+        z,
+        p_value: erfc(z.abs() / std::f64::consts::SQRT_2).clamp(0.0, 1.0),
+        prob_b_slower: (n_a * n_b - u) / (n_a * n_b),
+    })
+}
+
+/// Moving-block bootstrap interval for `avg_fps(b) - avg_fps(a)`. Each side
+/// is its frame times and whether they are polled samples.
+fn bootstrap_avg_fps_delta(
+    (a, a_sampled): (&[f64], bool),
+    (b, b_sampled): (&[f64], bool),
+) -> Option<ConfidenceInterval> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let mut rng = SplitMix64(BOOTSTRAP_SEED);
+    let mut deltas: Vec<f64> = (0..BOOTSTRAP_RESAMPLES)
+        .map(|_| {
+            resampled_avg_fps(b, b_sampled, &mut rng) - resampled_avg_fps(a, a_sampled, &mut rng)
+        })
+        .collect();
+    deltas.sort_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+
+    let tail = (1.0 - CONFIDENCE) / 2.0;
+    let index = |q: f64| ((deltas.len() as f64 * q) as usize).min(deltas.len() - 1);
+    Some(ConfidenceInterval {
+        confidence: CONFIDENCE,
+        low: deltas[index(tail)],
+        high: deltas[index(1.0 - tail)],
+    })
+}
+
+/// Average FPS of one block resample: frames over time, or the mean of the
+/// per-row FPS for sampled captures.
+fn resampled_avg_fps(frames: &[f64], sampled: bool, rng: &mut SplitMix64) -> f64 {
+    let block = ((frames.len() as f64).sqrt() as usize).clamp(1, frames.len());
+    let starts = frames.len() - block + 1;
+    let mut total_ms = 0.0;
+    let mut total_fps = 0.0;
+    let mut taken = 0;
+    while taken < frames.len() {
+        let start = rng.below(starts);
+        let len = block.min(frames.len() - taken);
+        let rows = &frames[start..start + len];
+        total_ms += rows.iter().sum::<f64>();
+        total_fps += rows.iter().map(|ft| 1000.0 / ft).sum::<f64>();
+        taken += len;
+    }
+    if sampled {
+        total_fps / taken as f64
+    } else {
+        1000.0 * taken as f64 / total_ms
+    }
+}
+
+/// Small deterministic PRNG for the bootstrap.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+/// Complementary error function (Numerical Recipes `erfcc`, |error| < 1.2e-7).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let result = t * poly.exp();
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::FrameTelemetry;
+
+    fn capture(frame_times_ms: Vec<f64>) -> FrameData {
+        FrameData {
+            duration_secs: frame_times_ms.iter().sum::<f64>() / 1000.0,
+            frame_times_ms,
+            application: Some("test-game".to_string()),
+            source: "test".to_string(),
+            telemetry: FrameTelemetry::default(),
+            sampled: false,
+        }
+    }
+
+    /// Deterministic wobble around a base frame time.
+    fn wobbly(base: f64, count: usize) -> Vec<f64> {
+        (0..count)
+            .map(|i| base + ((i * 7919) % 13) as f64 * 0.1 - 0.6)
+            .collect()
+    }
+
+    #[test]
+    fn faster_capture_is_a_significant_improvement() {
+        let a = capture(wobbly(16.7, 3_000));
+        let b = capture(wobbly(15.2, 3_000));
+
+        let comparison = compare_captures("before.csv", &a, "after.csv", &b).unwrap();
+        let avg = &comparison.metrics[0];
+        assert_eq!(avg.metric, "Average FPS");
+        assert!(avg.delta > 5.0 && avg.improved());
+
+        let test = comparison.mann_whitney.as_ref().unwrap();
+        assert!(test.p_value < 1e-6);
+        assert!(test.prob_b_slower < 0.05);
+        let ci = comparison.avg_fps_delta_ci.as_ref().unwrap();
+        assert!(ci.low > 0.0 && ci.low <= avg.delta && avg.delta <= ci.high);
+        assert!(comparison.significant);
+
+        let frame_time = comparison
+            .metrics
+            .iter()
+            .find(|m| m.metric == "Avg frame time")
+            .unwrap();
+        assert!(frame_time.delta < 0.0 && frame_time.improved());
+    }
+
+    #[test]
+    fn identical_distributions_are_not_significant() {
+        let frames = wobbly(16.7, 2_000);
+        let a = capture(frames.clone());
+        let mut shuffled = frames;
+        shuffled.reverse();
+        let b = capture(shuffled);
+
+        let comparison = compare_captures("a", &a, "b", &b).unwrap();
+        let test = comparison.mann_whitney.unwrap();
+        assert!(test.z.abs() < 1e-9);
+        assert!((test.p_value - 1.0).abs() < 1e-6);
+        assert!(!comparison.significant);
+    }
+
+    #[test]
+    fn sampled_captures_bootstrap_the_reported_average() {
+        // Alternating 10/20 ms polls: 75 FPS per-row mean, 66.7 FPS over time.
+        let mut a = capture(
+            (0..400)
+                .map(|i| if i % 2 == 0 { 10.0 } else { 20.0 })
+                .collect(),
+        );
+        let mut b = capture(wobbly(15.0, 400));
+        a.sampled = true;
+        b.sampled = true;
+
+        let comparison = compare_captures("a.hml", &a, "b.hml", &b).unwrap();
+        let avg = &comparison.metrics[0];
+        assert!(avg.delta < -5.0);
+        let ci = comparison.avg_fps_delta_ci.unwrap();
+        assert!(ci.low <= avg.delta && avg.delta <= ci.high);
+        assert!(ci.high < 0.0);
+
+        let below = comparison
+            .metrics
+            .iter()
+            .find(|m| m.metric == "Time below threshold")
+            .unwrap();
+        // Half the polls are slower than 60 FPS, though they cover 2/3 of the time.
+        assert!((below.a - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_capture_is_rejected() {
+        let a = capture(vec![16.7; 10]);
+        let b = capture(vec![f64::NAN]);
+        assert!(compare_captures("a", &a, "b", &b).is_err());
+    }
+
+    #[test]
+    fn erfc_matches_reference_values() {
+        assert!((erfc(0.0) - 1.0).abs() < 1e-6);
+        assert!((erfc(1.0) - 0.157_299_2).abs() < 1e-6);
+        assert!((erfc(-1.0) - 1.842_700_8).abs() < 1e-6);
+    }
+}
@@ -1,17 +1,18 @@
-use std::fs;
 use std::path::Path;
-use std::process::Command;
 use std::sync::OnceLock;
 
-pub(super) fn foreground_process_name_impl() -> Option<String> {
-    match *preferred_backend() {
-        LinuxFocusBackend::Xdotool => foreground_pid_xdotool()
-            .or_else(foreground_pid_xprop)
-            .and_then(process_name_from_pid),
-        LinuxFocusBackend::Xprop => foreground_pid_xprop().and_then(process_name_from_pid),
-        LinuxFocusBackend::Hyprctl => foreground_pid_hyprctl().and_then(process_name_from_pid),
+use crate::hardware::probe::Probe;
+
+pub(super) fn foreground_process_name_impl(probe: &Probe) -> Option<String> {
+    let pid = match preferred_backend(probe) {
+        LinuxFocusBackend::Xdotool => {
+            foreground_pid_xdotool(probe).or_else(|| foreground_pid_xprop(probe))
+        }
+        LinuxFocusBackend::Xprop => foreground_pid_xprop(probe),
+        LinuxFocusBackend::Hyprctl => foreground_pid_hyprctl(probe),
         LinuxFocusBackend::Unavailable => None,
-    }
+    };
+    pid.and_then(|pid| process_name_from_pid(probe, pid))
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -24,49 +25,50 @@ enum LinuxFocusBackend {
 
 static LINUX_BACKEND: OnceLock<LinuxFocusBackend> = OnceLock::new();
 
-fn preferred_backend() -> &'static LinuxFocusBackend {
-    LINUX_BACKEND.get_or_init(detect_backend)
+/// The live backend is looked up once per process; snapshots are probed afresh.
+fn preferred_backend(probe: &Probe) -> LinuxFocusBackend {
+    if probe.is_live() {
+        *LINUX_BACKEND.get_or_init(|| detect_backend(probe))
+    } else {
+        detect_backend(probe)
+    }
 }
 
-fn detect_backend() -> LinuxFocusBackend {
-    if command_exists("xdotool") {
+fn detect_backend(probe: &Probe) -> LinuxFocusBackend {
+    if command_exists(probe, "xdotool") {
         LinuxFocusBackend::Xdotool
-    } else if command_exists("xprop") {
+    } else if command_exists(probe, "xprop") {
         LinuxFocusBackend::Xprop
-    } else if command_exists("hyprctl") {
+    } else if command_exists(probe, "hyprctl") {
         LinuxFocusBackend::Hyprctl
     } else {
         LinuxFocusBackend::Unavailable
     }
 }
 
-fn command_exists(command: &str) -> bool {
-    Command::new("which")
-        .arg(command)
-        .status()
-        .map(|status| status.success())
-        .unwrap_or(false)
+fn command_exists(probe: &Probe, command: &str) -> bool {
+    probe.run("which", &[command]).is_some()
 }
 
-fn foreground_pid_xdotool() -> Option<u32> {
-    let output = run_command_capture("xdotool", &["getwindowfocus", "getwindowpid"])?;
+fn foreground_pid_xdotool(probe: &Probe) -> Option<u32> {
+    let output = run_command_capture(probe, "xdotool", &["getwindowfocus", "getwindowpid"])?;
     output.trim().parse::<u32>().ok().filter(|pid| *pid > 0)
 }
 
-fn foreground_pid_xprop() -> Option<u32> {
-    let root = run_command_capture("xprop", &["-root", "_NET_ACTIVE_WINDOW"])?;
+fn foreground_pid_xprop(probe: &Probe) -> Option<u32> {
+    let root = run_command_capture(probe, "xprop", &["-root", "_NET_ACTIVE_WINDOW"])?;
     let window_id = root
         .split('#')
         .nth(1)
         .map(str::trim)
         .filter(|value| !value.is_empty())?;
 
-    let pid_line = run_command_capture("xprop", &["-id", window_id, "_NET_WM_PID"])?;
+    let pid_line = run_command_capture(probe, "xprop", &["-id", window_id, "_NET_WM_PID"])?;
     parse_number_after_equals(&pid_line)
 }
 
-fn foreground_pid_hyprctl() -> Option<u32> {
-    let output = run_command_capture("hyprctl", &["activewindow", "-j"])?;
+fn foreground_pid_hyprctl(probe: &Probe) -> Option<u32> {
+    let output = run_command_capture(probe, "hyprctl", &["activewindow", "-j"])?;
     parse_hyprctl_pid(&output)
 }
 
@@ -93,23 +95,20 @@ fn parse_number_after_equals(text: &str) -> Option<u32> {
         .filter(|pid| *pid > 0)
 }
 
-fn process_name_from_pid(pid: u32) -> Option<String> {
-    let comm_path = format!("/proc/{pid}/comm");
-    if let Ok(comm) = fs::read_to_string(comm_path) {
+fn process_name_from_pid(probe: &Probe, pid: u32) -> Option<String> {
+    if let Some(comm) = probe.read_to_string(format!("/proc/{pid}/comm")) {
         let trimmed = comm.trim();
         if !trimmed.is_empty() {
             return Some(trimmed.to_string());
         }
     }
 
-    let cmdline_path = format!("/proc/{pid}/cmdline");
-    let cmdline = fs::read(cmdline_path).ok()?;
-    let first = cmdline
-        .split(|byte| *byte == 0)
+    let cmdline = probe.read_to_string(format!("/proc/{pid}/cmdline"))?;
+    let command = cmdline
+        .split('\0')
         .next()
         .filter(|value| !value.is_empty())?;
-    let command = String::from_utf8_lossy(first);
-    let binary = Path::new(command.as_ref())
+    let binary = Path::new(command)
         .file_name()
         .and_then(|name| name.to_str())?;
     let trimmed = binary.trim();
@@ -120,12 +119,8 @@ fn process_name_from_pid(pid: u32) -> Option<String> {
     }
 }
 
-fn run_command_capture(command: &str, args: &[&str]) -> Option<String> {
-    let output = Command::new(command).args(args).output().ok()?;
-    if !output.status.success() {
-        return None;
-    }
-    let stdout = String::from_utf8_lossy(&output.stdout);
+fn run_command_capture(probe: &Probe, command: &str, args: &[&str]) -> Option<String> {
+    let stdout = probe.run(command, args)?;
     let trimmed = stdout.trim();
     if trimmed.is_empty() {
         None
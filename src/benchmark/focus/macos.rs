@@ -1,32 +1,32 @@
 use std::path::Path;
-use std::process::Command;
 use std::sync::{Mutex, OnceLock};
 
 use super::normalize_process_name;
+use crate::hardware::probe::Probe;
 
-pub(super) fn foreground_process_name_impl() -> Option<String> {
-    if let Some(pid) = foreground_pid() {
-        if let Some(cached) = cached_process_name(pid) {
+pub(super) fn foreground_process_name_impl(probe: &Probe) -> Option<String> {
+    if let Some(pid) = foreground_pid(probe) {
+        if let Some(cached) = cached_process_name(pid).filter(|_| probe.is_live()) {
             return Some(cached);
         }
 
-        let resolved = process_name_from_pid(pid).or_else(foreground_app_name)?;
+        let resolved = process_name_from_pid(probe, pid).or_else(|| foreground_app_name(probe))?;
         update_cached_process_name(pid, &resolved);
         return Some(resolved);
     }
 
-    foreground_app_name()
+    foreground_app_name(probe)
 }
 
-fn foreground_pid() -> Option<u32> {
+fn foreground_pid(probe: &Probe) -> Option<u32> {
     let script = r#"tell application "System Events" to get unix id of first process whose frontmost is true"#;
-    let output = run_command_capture("osascript", &["-e", script])?;
+    let output = run_command_capture(probe, "osascript", &["-e", script])?;
     output.trim().parse::<u32>().ok().filter(|pid| *pid > 0)
 }
 
-fn process_name_from_pid(pid: u32) -> Option<String> {
+fn process_name_from_pid(probe: &Probe, pid: u32) -> Option<String> {
     let pid_text = pid.to_string();
-    let output = run_command_capture("ps", &["-p", &pid_text, "-o", "comm="])?;
+    let output = run_command_capture(probe, "ps", &["-p", &pid_text, "-o", "comm="])?;
     let command = output.trim();
     if command.is_empty() {
         return None;
@@ -42,10 +42,10 @@ fn process_name_from_pid(pid: u32) -> Option<String> {
     }
 }
 
-fn foreground_app_name() -> Option<String> {
+fn foreground_app_name(probe: &Probe) -> Option<String> {
     let script =
         r#"tell application "System Events" to get name of first process whose frontmost is true"#;
-    let output = run_command_capture("osascript", &["-e", script])?;
+    let output = run_command_capture(probe, "osascript", &["-e", script])?;
     let normalized = normalize_process_name(output.trim());
     if normalized.is_empty() {
         None
@@ -72,12 +72,8 @@ fn update_cached_process_name(pid: u32, name: &str) {
     }
 }
 
-fn run_command_capture(command: &str, args: &[&str]) -> Option<String> {
-    let output = Command::new(command).args(args).output().ok()?;
-    if !output.status.success() {
-        return None;
-    }
-    let stdout = String::from_utf8_lossy(&output.stdout);
+fn run_command_capture(probe: &Probe, command: &str, args: &[&str]) -> Option<String> {
+    let stdout = probe.run(command, args)?;
     let trimmed = stdout.trim();
     if trimmed.is_empty() {
         None
@@ -1,7 +1,11 @@
 //! Foreground window/process detection.
 //!
 //! This module uses best-effort platform-specific process lookup for the
-//! currently focused window.
+//! currently focused window. Commands and /proc reads go through a
+//! [`Probe`], but hardware snapshots never record them: window titles and
+//! process lists are personal.
+
+use crate::hardware::probe::Probe;
 
 #[cfg(target_os = "linux")]
 mod linux;
@@ -19,11 +23,11 @@ use windows::foreground_process_name_impl;
 
 /// Best-effort foreground process name.
 pub fn foreground_process_name() -> Option<String> {
-    foreground_process_name_impl()
+    foreground_process_name_impl(&Probe::live())
 }
 
 #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
-fn foreground_process_name_impl() -> Option<String> {
+fn foreground_process_name_impl(_probe: &Probe) -> Option<String> {
     None
 }
 
@@ -1,12 +1,15 @@
 use std::path::Path;
 
+use crate::hardware::probe::Probe;
+
 use windows_sys::Win32::Foundation::CloseHandle;
 use windows_sys::Win32::System::Threading::{
     OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
 };
 use windows_sys::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
 
-pub(super) fn foreground_process_name_impl() -> Option<String> {
+/// The Win32 calls cannot be replayed from a snapshot, so `_probe` is unused.
+pub(super) fn foreground_process_name_impl(_probe: &Probe) -> Option<String> {
     let hwnd = unsafe { GetForegroundWindow() };
     if hwnd.is_null() {
         return None;
@@ -6,7 +6,6 @@
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use std::collections::VecDeque;
 use std::fmt;
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom, Write};
@@ -22,13 +21,17 @@ use uuid::Uuid;
 use crate::benchmark::focus;
 #[cfg(target_os = "windows")]
 use crate::deps;
+use crate::hardware::power::{PowerSampler, PowerSummary};
 use crate::import;
+use crate::import::bottleneck::BottleneckReport;
+use crate::import::trim::{trim_frame_times, TrimOptions, TrimReport};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CaptureSource {
     Auto,
     MangoHud,
     PresentMon,
+    Replay,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -59,6 +62,10 @@ pub struct LiveCaptureOptions {
     pub process_validation: bool,
     pub max_frame_time_ms: f64,
     pub strict_unfocus_grace_ms: u64,
+    /// Playback speed multiplier for `CaptureSource::Replay`.
+    pub replay_speed: f64,
+    /// Start/end trimming applied before stats are computed.
+    pub trim: TrimOptions,
 }
 
 #[derive(Debug, Clone)]
@@ -75,6 +82,13 @@ pub struct LiveCaptureResult {
     pub fps_01_low: Option<f64>,
     pub min_fps: f64,
     pub max_fps: f64,
+    pub frame_time_stats: import::FrameTimeStats,
+    /// Hardware telemetry (mean/max) over the capture window, when logged.
+    pub telemetry: Option<import::TelemetrySummary>,
+    /// CPU- vs GPU-bound verdict, when GPU utilisation was logged.
+    pub bottleneck: Option<BottleneckReport>,
+    /// Battery, thermal and throttling state sampled during the capture.
+    pub power: Option<PowerSummary>,
     pub target_process: Option<String>,
     pub focus_pauses: u32,
     pub samples_dropped_unfocused: usize,
@@ -85,6 +99,12 @@ pub struct LiveCaptureResult {
     pub stutter_spike_count: usize,
     pub stutter_spike_ratio: f64,
     pub unstable_capture: bool,
+    /// Frame times (ms) that passed validation, in capture order.
+    pub frame_times_ms: Vec<f64>,
+    /// Per-frame hardware telemetry aligned with `frame_times_ms`, when logged.
+    pub frame_telemetry: import::FrameTelemetry,
+    /// What trimming removed, if any was requested.
+    pub trim: Option<TrimReport>,
 }
 
 impl fmt::Display for LiveCaptureResult {
@@ -104,6 +124,9 @@ impl fmt::Display for LiveCaptureResult {
         writeln!(f, "  Ended: {}", self.ended_at.to_rfc3339())?;
         writeln!(f, "  Duration: {:.1}s", self.duration_secs)?;
         writeln!(f, "  Samples: {}", self.frame_count)?;
+        if let Some(trim) = self.trim.as_ref().filter(|trim| !trim.is_empty()) {
+            writeln!(f, "  {}", trim.summary())?;
+        }
         writeln!(f, "  Avg FPS: {:.1}", self.avg_fps)?;
         writeln!(f, "  1% Low: {:.1}", self.fps_1_low)?;
         if let Some(fps_01) = self.fps_01_low {
@@ -114,6 +137,44 @@ impl fmt::Display for LiveCaptureResult {
             "  Min / Max FPS: {:.1} / {:.1}",
             self.min_fps, self.max_fps
         )?;
+        let pacing = &self.frame_time_stats;
+        if let Some(fps_1_avg) = pacing.fps_1_low_avg {
+            writeln!(f, "  1% Low (avg of slowest 1%): {:.1}", fps_1_avg)?;
+        }
+        writeln!(
+            f,
+            "  Frame time median / p95 / p99: {:.2} / {:.2} / {:.2} ms",
+            pacing.median_ms, pacing.p95_ms, pacing.p99_ms
+        )?;
+        if let Some(p999) = pacing.p999_ms {
+            writeln!(f, "  Frame time p99.9: {:.2} ms", p999)?;
+        }
+        writeln!(f, "  Frame time std dev: {:.2} ms", pacing.stddev_ms)?;
+        writeln!(
+            f,
+            "  Jitter (avg / p99 / max): {:.2} / {:.2} / {:.2} ms",
+            pacing.jitter_avg_ms, pacing.jitter_p99_ms, pacing.jitter_max_ms
+        )?;
+        writeln!(
+            f,
+            "  Time below {:.0} FPS: {:.1}%",
+            pacing.threshold_fps, pacing.time_below_threshold_pct
+        )?;
+        if let Some(telemetry) = &self.telemetry {
+            for line in telemetry.to_string().lines() {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+        if let Some(bottleneck) = &self.bottleneck {
+            for line in bottleneck.to_string().lines() {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+        if let Some(power) = &self.power {
+            for line in power.to_string().lines() {
+                writeln!(f, "  {}", line)?;
+            }
+        }
         writeln!(
             f,
             "  Focus pauses / dropped samples: {} / {}",
@@ -156,6 +217,12 @@ struct CaptureDiagnostics {
     total_unfocused_ms: u64,
 }
 
+/// How often power/thermal state is sampled during a live capture.
+const POWER_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+pub const MIN_REPLAY_SPEED: f64 = 0.1;
+pub const MAX_REPLAY_SPEED: f64 = 100.0;
+
 pub fn run_live_capture(options: &LiveCaptureOptions) -> Result<LiveCaptureResult> {
     if options.duration_secs < 10 {
         anyhow::bail!("Duration must be at least 10 seconds");
@@ -172,11 +239,27 @@ pub fn run_live_capture(options: &LiveCaptureOptions) -> Result<LiveCaptureResul
     if !options.max_frame_time_ms.is_finite() || options.max_frame_time_ms <= 0.0 {
         anyhow::bail!("max-frame-time-ms must be a positive number");
     }
+    if options.source == CaptureSource::Replay
+        && !(MIN_REPLAY_SPEED..=MAX_REPLAY_SPEED).contains(&options.replay_speed)
+    {
+        anyhow::bail!(
+            "replay-speed must be between {} and {}",
+            MIN_REPLAY_SPEED,
+            MAX_REPLAY_SPEED
+        );
+    }
 
     let source = resolve_source(options)?;
     match source {
-        CaptureSource::MangoHud => capture_from_mangohud(options),
+        CaptureSource::MangoHud => {
+            let mut backend = MangoHudBackend::new(options)?;
+            run_capture_loop(&mut backend, options)
+        }
         CaptureSource::PresentMon => capture_from_presentmon(options),
+        CaptureSource::Replay => {
+            let mut backend = ReplayBackend::new(options)?;
+            run_capture_loop(&mut backend, options)
+        }
         CaptureSource::Auto => unreachable!("resolve_source must not return Auto"),
     }
 }
@@ -221,40 +304,70 @@ On Linux/macOS, --source auto requires a MangoHud log path, or omit --file."
     )
 }
 
-fn capture_from_mangohud(options: &LiveCaptureOptions) -> Result<LiveCaptureResult> {
-    let capture_path = if let Some(path) = options.file.clone() {
-        path
-    } else {
-        import::mangohud::find_latest_mangohud_log().ok_or_else(|| {
-            anyhow::anyhow!(
-                "No MangoHud log found. Enable logging: MANGOHUD=1 MANGOHUD_LOG=1 <game>"
-            )
-        })?
-    };
+/// A frametime source driven by the shared live capture loop.
+///
+/// Backends only deliver samples; focus tracking, poll adaptation, deadline
+/// handling and result building live in `run_capture_loop`.
+pub trait CaptureBackend {
+    /// Source label recorded on the result, e.g. "MangoHud Live".
+    fn label(&self) -> &str;
 
-    if !capture_path.exists() {
-        anyhow::bail!("Capture file does not exist: {}", capture_path.display());
+    /// File the backend reads from or writes to, if any.
+    fn capture_path(&self) -> Option<PathBuf>;
+
+    /// Whether samples arrive while capturing. Batch sources that only
+    /// deliver frames in `finish` cannot have focus-loss windows trimmed out.
+    fn streams_samples(&self) -> bool {
+        true
     }
 
-    let mut parser = MangoHudStreamParser::default();
-    prime_mangohud_parser_with_existing_header(&capture_path, &mut parser)?;
-    let mut partial_line = String::new();
-    let mut frame_times_ms: VecDeque<f64> = VecDeque::new();
-    let mut read_offset = File::open(&capture_path)
-        .with_context(|| format!("Failed to open {}", capture_path.display()))?
-        .metadata()
-        .context("Failed to read capture file metadata")?
-        .len();
+    /// Called once when the capture window opens.
+    fn start(&mut self) -> Result<()> {
+        Ok(())
+    }
 
-    let started_at = Utc::now();
-    let start_instant = Instant::now();
-    let deadline = start_instant + Duration::from_secs(options.duration_secs);
-    let mut last_printed_sec = u64::MAX;
-    let mut dynamic_poll_ms = options.poll_ms.clamp(50, 500);
+    /// Frame times (ms) produced since the previous poll.
+    fn poll(&mut self) -> Result<Vec<f64>>;
+
+    /// Whether the source stopped on its own before the deadline.
+    fn is_finished(&mut self) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Capture time covered after `wall_secs` of wall-clock capturing. Sources
+    /// that play frames back faster or slower than real time override this.
+    fn capture_duration_secs(&self, wall_secs: f64) -> f64 {
+        wall_secs
+    }
+
+    /// Whether frames are being rendered on this machine right now, so its
+    /// power and thermal state describe the capture.
+    fn samples_live_hardware(&self) -> bool {
+        true
+    }
+
+    /// Stop the source and return anything not yet delivered by `poll`.
+    fn finish(&mut self) -> Result<CaptureTail>;
+}
+
+/// Samples and metadata a backend hands over when the capture ends.
+#[derive(Debug, Clone, Default)]
+pub struct CaptureTail {
+    pub frame_times_ms: Vec<f64>,
+    /// Application the source attributed the frames to, if it knows.
+    pub application: Option<String>,
+    /// Capture duration as measured by the source itself.
+    pub duration_secs: Option<f64>,
+    /// Hardware telemetry logged over the capture window, if the source has any.
+    pub telemetry: import::FrameTelemetry,
+}
 
+fn run_capture_loop<B: CaptureBackend + ?Sized>(
+    backend: &mut B,
+    options: &LiveCaptureOptions,
+) -> Result<LiveCaptureResult> {
     let mut diagnostics = init_capture_diagnostics(options);
     let monitor_focus = monitor_focus_enabled(options, &diagnostics);
-
     let mut focus_tracker = FocusTracker::new(
         options.process_name.clone(),
         options.focus_policy,
@@ -263,16 +376,38 @@ fn capture_from_mangohud(options: &LiveCaptureOptions) -> Result<LiveCaptureResu
         options.strict_unfocus_grace_ms,
     );
 
-    println!(
-        "Live capture started ({}) from {}",
-        options.duration_secs,
-        capture_path.display()
-    );
+    let mut frame_times_ms: Vec<f64> = Vec::new();
+    // One flag per sample the backend produced: was it kept?
+    let mut collected_mask: Vec<bool> = Vec::new();
+    let mut last_printed_sec = u64::MAX;
+    let mut dynamic_poll_ms = options.poll_ms.clamp(50, 500);
+
+    let started_at = Utc::now();
+    let start_instant = Instant::now();
+    let deadline = start_instant + Duration::from_secs(options.duration_secs);
+    backend.start()?;
+    let power_sampler = backend
+        .samples_live_hardware()
+        .then(|| PowerSampler::start(POWER_SAMPLE_INTERVAL));
+
+    match backend.capture_path() {
+        Some(path) => println!(
+            "Live capture started ({}s) via {} from {}",
+            options.duration_secs,
+            backend.label(),
+            path.display()
+        ),
+        None => println!(
+            "Live capture started ({}s) via {}",
+            options.duration_secs,
+            backend.label()
+        ),
+    }
     println!(
         "Run your game benchmark path now. Capturing fresh samples only (adaptive poll, focus-aware)...\n"
     );
 
-    while Instant::now() < deadline {
+    while Instant::now() < deadline && !backend.is_finished()? {
         let elapsed = start_instant.elapsed().as_secs();
         if elapsed != last_printed_sec {
             let remaining = options.duration_secs.saturating_sub(elapsed);
@@ -287,17 +422,10 @@ fn capture_from_mangohud(options: &LiveCaptureOptions) -> Result<LiveCaptureResu
             last_printed_sec = elapsed;
         }
 
-        let mut file = File::open(&capture_path)
-            .with_context(|| format!("Failed to open {}", capture_path.display()))?;
-        file.seek(SeekFrom::Start(read_offset))?;
-
-        let mut chunk = String::new();
-        file.read_to_string(&mut chunk)?;
-        read_offset = read_offset.saturating_add(chunk.len() as u64);
-
-        let new_samples = parse_chunk_frametimes(&mut parser, &chunk, &mut partial_line);
+        let new_samples = backend.poll()?;
 
         let collecting = focus_tracker.should_collect();
+        collected_mask.resize(collected_mask.len() + new_samples.len(), collecting);
         if collecting {
             frame_times_ms.extend(new_samples.iter().copied());
         } else {
@@ -312,22 +440,41 @@ fn capture_from_mangohud(options: &LiveCaptureOptions) -> Result<LiveCaptureResu
         thread::sleep(Duration::from_millis(dynamic_poll_ms));
     }
     println!();
+    let power = power_sampler.and_then(PowerSampler::finish);
 
-    if !partial_line.is_empty() {
-        let trailing = partial_line.trim();
-        if let Some(ft) = parser.parse_line(trailing) {
-            if focus_tracker.should_collect() {
-                frame_times_ms.push_back(ft);
-            } else {
-                diagnostics.samples_dropped_unfocused =
-                    diagnostics.samples_dropped_unfocused.saturating_add(1);
-            }
+    let tail = backend.finish()?;
+    if !tail.frame_times_ms.is_empty() {
+        let collecting = !backend.streams_samples() || focus_tracker.should_collect();
+        collected_mask.resize(collected_mask.len() + tail.frame_times_ms.len(), collecting);
+        if collecting {
+            frame_times_ms.extend(tail.frame_times_ms.iter().copied());
+        } else {
+            diagnostics.samples_dropped_unfocused = diagnostics
+                .samples_dropped_unfocused
+                .saturating_add(tail.frame_times_ms.len());
         }
     }
 
     focus_tracker.finalize_unfocused_tracking();
     update_diagnostics_from_focus_tracker(&mut diagnostics, &focus_tracker);
 
+    if !backend.streams_samples() && options.pause_on_unfocus && diagnostics.focus_pauses > 0 {
+        anyhow::bail!(
+            "Capture paused due to focus loss while using {}. \
+Its output cannot be safely trimmed by focus windows; re-run while keeping the target window focused, \
+or set --pause-on-unfocus false.",
+            backend.label()
+        );
+    }
+
+    if let (Some(target), Some(observed)) =
+        (options.process_name.as_deref(), tail.application.as_deref())
+    {
+        if !focus::process_name_matches(observed, target) {
+            diagnostics.process_mismatch_detected = true;
+        }
+    }
+
     if diagnostics.process_validation_enabled && diagnostics.process_mismatch_detected {
         anyhow::bail!(
             "Capture process validation failed: active foreground process did not consistently match target '{}'.",
@@ -335,24 +482,254 @@ fn capture_from_mangohud(options: &LiveCaptureOptions) -> Result<LiveCaptureResu
         );
     }
     if focus_tracker.strict_focus_violation() {
-        anyhow::bail!(strict_focus_violation_message(
-            options.strict_unfocus_grace_ms
-        ));
+        let message = strict_focus_violation_message(options.strict_unfocus_grace_ms);
+        if backend.streams_samples() {
+            anyhow::bail!(message);
+        }
+        // Batch sources cannot drop the unfocused stretch, so say how to recover.
+        anyhow::bail!(
+            "{} Re-run while keeping target window focused, increase --strict-unfocus-grace-ms, or use --focus-policy lenient.",
+            message
+        );
     }
 
     let ended_at = Utc::now();
-    let duration_secs = start_instant.elapsed().as_secs_f64();
-    build_result(
-        frame_times_ms.into_iter().collect(),
-        "MangoHud Live",
-        Some(capture_path),
-        options.game_hint.clone(),
+    let mut duration_secs = backend
+        .capture_duration_secs(start_instant.elapsed().as_secs_f64())
+        .max(tail.duration_secs.unwrap_or(0.0));
+
+    let mut telemetry = tail.telemetry.select(&collected_mask);
+    let (frame_times_ms, trim) = if options.trim.is_noop() {
+        (frame_times_ms, None)
+    } else {
+        let (kept, report) = trim_frame_times(&frame_times_ms, &options.trim)?;
+        duration_secs = (duration_secs - report.leading_secs - report.trailing_secs).max(0.0);
+        telemetry = telemetry.slice(
+            report.leading_frames,
+            report.leading_frames + report.kept_frames,
+        );
+        (kept, Some(report))
+    };
+    let bottleneck =
+        BottleneckReport::classify(&frame_times_ms, &telemetry, options.max_frame_time_ms);
+    let valid_mask: Vec<bool> = frame_times_ms
+        .iter()
+        .map(|value| is_valid_frame_time(*value, options.max_frame_time_ms))
+        .collect();
+    let frame_telemetry = telemetry.select(&valid_mask);
+
+    let mut result = build_result(
+        frame_times_ms,
+        backend.label(),
+        backend.capture_path(),
+        options.game_hint.clone().or(tail.application),
         started_at,
         ended_at,
         duration_secs,
         options.max_frame_time_ms,
         diagnostics,
-    )
+    )?;
+    result.trim = trim;
+    result.frame_telemetry = frame_telemetry;
+    // Telemetry the backend could not line up with frames is still worth a summary.
+    result.telemetry = telemetry.summary().or_else(|| tail.telemetry.summary());
+    result.bottleneck = bottleneck;
+    apply_power_summary(&mut result, power);
+    Ok(result)
+}
+
+/// Record the power/thermal state and penalise captures taken on battery or
+/// while throttling.
+fn apply_power_summary(result: &mut LiveCaptureResult, power: Option<PowerSummary>) {
+    if let Some(power) = &power {
+        result.capture_quality_score = result
+            .capture_quality_score
+            .saturating_sub(power.quality_penalty());
+        result.unstable_capture |= result.capture_quality_score < 70;
+    }
+    result.power = power;
+}
+
+/// Tails a MangoHud CSV log, picking up only rows appended after `start`.
+struct MangoHudBackend {
+    capture_path: PathBuf,
+    parser: MangoHudStreamParser,
+    partial_line: String,
+    read_offset: u64,
+}
+
+impl MangoHudBackend {
+    fn new(options: &LiveCaptureOptions) -> Result<Self> {
+        let capture_path = if let Some(path) = options.file.clone() {
+            path
+        } else {
+            import::mangohud::find_latest_mangohud_log().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No MangoHud log found. Enable logging: MANGOHUD=1 MANGOHUD_LOG=1 <game>"
+                )
+            })?
+        };
+
+        if !capture_path.exists() {
+            anyhow::bail!("Capture file does not exist: {}", capture_path.display());
+        }
+
+        let mut parser = MangoHudStreamParser::default();
+        prime_mangohud_parser_with_existing_header(&capture_path, &mut parser)?;
+
+        Ok(Self {
+            capture_path,
+            parser,
+            partial_line: String::new(),
+            read_offset: 0,
+        })
+    }
+}
+
+impl CaptureBackend for MangoHudBackend {
+    fn label(&self) -> &str {
+        "MangoHud Live"
+    }
+
+    fn capture_path(&self) -> Option<PathBuf> {
+        Some(self.capture_path.clone())
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.read_offset = File::open(&self.capture_path)
+            .with_context(|| format!("Failed to open {}", self.capture_path.display()))?
+            .metadata()
+            .context("Failed to read capture file metadata")?
+            .len();
+        Ok(())
+    }
+
+    fn poll(&mut self) -> Result<Vec<f64>> {
+        let mut file = File::open(&self.capture_path)
+            .with_context(|| format!("Failed to open {}", self.capture_path.display()))?;
+        file.seek(SeekFrom::Start(self.read_offset))?;
+
+        let mut chunk = String::new();
+        file.read_to_string(&mut chunk)?;
+        self.read_offset = self.read_offset.saturating_add(chunk.len() as u64);
+
+        Ok(parse_chunk_frametimes(
+            &mut self.parser,
+            &chunk,
+            &mut self.partial_line,
+        ))
+    }
+
+    fn finish(&mut self) -> Result<CaptureTail> {
+        let mut tail = CaptureTail::default();
+        if !self.partial_line.is_empty() {
+            let trailing = std::mem::take(&mut self.partial_line);
+            if let Some(ft) = self.parser.parse_line(trailing.trim()) {
+                tail.frame_times_ms.push(ft);
+            }
+        }
+        tail.telemetry = std::mem::take(&mut self.parser.telemetry);
+        Ok(tail)
+    }
+}
+
+/// Streams a recorded MangoHud or CapFrameX log at wall-clock pace (scaled by
+/// `replay_speed`), so the capture loop can be exercised without a game.
+struct ReplayBackend {
+    label: String,
+    capture_path: PathBuf,
+    frame_times_ms: Vec<f64>,
+    telemetry: import::FrameTelemetry,
+    next_frame: usize,
+    replayed_ms: f64,
+    speed: f64,
+    started: Option<Instant>,
+}
+
+impl ReplayBackend {
+    fn new(options: &LiveCaptureOptions) -> Result<Self> {
+        let capture_path = options
+            .file
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("--source replay requires --file <capture log>"))?;
+        if !capture_path.exists() {
+            anyhow::bail!("Capture file does not exist: {}", capture_path.display());
+        }
+
+        let frame_data = if import::mangohud::looks_like_mangohud_capture_file(&capture_path) {
+            import::parse_mangohud_log(&capture_path)
+                .or_else(|_| import::parse_capframex_csv(&capture_path))
+        } else {
+            import::parse_capframex_csv(&capture_path)
+                .or_else(|_| import::parse_mangohud_log(&capture_path))
+        }
+        .with_context(|| format!("Failed to parse {}", capture_path.display()))?;
+
+        Ok(Self {
+            label: format!("Replay ({})", frame_data.source),
+            capture_path,
+            frame_times_ms: frame_data.frame_times_ms,
+            telemetry: frame_data.telemetry,
+            next_frame: 0,
+            replayed_ms: 0.0,
+            speed: options.replay_speed,
+            started: None,
+        })
+    }
+}
+
+impl CaptureBackend for ReplayBackend {
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn capture_path(&self) -> Option<PathBuf> {
+        Some(self.capture_path.clone())
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.started = Some(Instant::now());
+        Ok(())
+    }
+
+    fn poll(&mut self) -> Result<Vec<f64>> {
+        let Some(started) = self.started else {
+            return Ok(Vec::new());
+        };
+        let due_ms = started.elapsed().as_secs_f64() * 1000.0 * self.speed;
+
+        let mut batch = Vec::new();
+        while let Some(&frame_time) = self.frame_times_ms.get(self.next_frame) {
+            if self.replayed_ms + frame_time > due_ms {
+                break;
+            }
+            self.replayed_ms += frame_time;
+            self.next_frame += 1;
+            batch.push(frame_time);
+        }
+        Ok(batch)
+    }
+
+    fn is_finished(&mut self) -> Result<bool> {
+        Ok(self.next_frame >= self.frame_times_ms.len())
+    }
+
+    /// The recorded time of the frames played so far, independent of `speed`.
+    fn capture_duration_secs(&self, _wall_secs: f64) -> f64 {
+        self.replayed_ms / 1000.0
+    }
+
+    fn samples_live_hardware(&self) -> bool {
+        false
+    }
+
+    fn finish(&mut self) -> Result<CaptureTail> {
+        // Frames not yet due when the deadline hit were never "rendered".
+        Ok(CaptureTail {
+            telemetry: self.telemetry.slice(0, self.next_frame),
+            ..CaptureTail::default()
+        })
+    }
 }
 
 fn capture_from_presentmon(options: &LiveCaptureOptions) -> Result<LiveCaptureResult> {
@@ -364,6 +741,26 @@ fn capture_from_presentmon(options: &LiveCaptureOptions) -> Result<LiveCaptureRe
 
     #[cfg(target_os = "windows")]
     {
+        let mut backend = PresentMonBackend::new(options)?;
+        run_capture_loop(&mut backend, options)
+    }
+}
+
+/// Runs PresentMon for the capture duration and parses its CSV at the end.
+#[cfg(target_os = "windows")]
+struct PresentMonBackend {
+    presentmon_path: PathBuf,
+    output_path: PathBuf,
+    duration_secs: u64,
+    process_name: Option<String>,
+    /// Restrict parsed rows to `process_name` (process validation enabled).
+    filter_process: bool,
+    child: Option<std::process::Child>,
+}
+
+#[cfg(target_os = "windows")]
+impl PresentMonBackend {
+    fn new(options: &LiveCaptureOptions) -> Result<Self> {
         let presentmon_path = deps::locate_presentmon_executable().ok_or_else(|| {
             anyhow::anyhow!(
                 "presentmon is not available. Run `fps-tracker doctor --fix` \
@@ -406,54 +803,76 @@ to install Intel.PresentMon.Console or bootstrap a local fallback."
             captures_dir.join(file_name)
         };
 
-        let mut diagnostics = init_capture_diagnostics(options);
-        let monitor_focus = monitor_focus_enabled(options, &diagnostics);
+        Ok(Self {
+            presentmon_path,
+            output_path,
+            duration_secs: options.duration_secs,
+            process_name: options.process_name.clone(),
+            filter_process: init_capture_diagnostics(options).process_validation_enabled,
+            child: None,
+        })
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl CaptureBackend for PresentMonBackend {
+    fn label(&self) -> &str {
+        "PresentMon"
+    }
 
-        let started_at = Utc::now();
-        let start_instant = Instant::now();
+    fn capture_path(&self) -> Option<PathBuf> {
+        Some(self.output_path.clone())
+    }
+
+    fn streams_samples(&self) -> bool {
+        false
+    }
 
-        let mut cmd = Command::new(&presentmon_path);
+    fn start(&mut self) -> Result<()> {
+        let mut cmd = Command::new(&self.presentmon_path);
         cmd.arg("-timed")
-            .arg(options.duration_secs.to_string())
+            .arg(self.duration_secs.to_string())
             .arg("-output_file")
-            .arg(&output_path);
+            .arg(&self.output_path);
 
-        if let Some(process_name) = options.process_name.as_deref() {
+        if let Some(process_name) = self.process_name.as_deref() {
             cmd.args(["-process_name", process_name]);
         }
 
-        let mut child = cmd.spawn().map_err(|err| {
+        let child = cmd.spawn().map_err(|err| {
             anyhow::anyhow!(
                 "Failed to run presentmon: {err}. \
 Ensure PresentMon is installed and runnable (`presentmon --help`). \
 If it still fails, repair the Microsoft Visual C++ Redistributable and try again."
             )
         })?;
+        self.child = Some(child);
+        Ok(())
+    }
 
-        let mut focus_tracker = FocusTracker::new(
-            options.process_name.clone(),
-            options.focus_policy,
-            monitor_focus,
-            options.pause_on_unfocus,
-            options.strict_unfocus_grace_ms,
-        );
+    fn poll(&mut self) -> Result<Vec<f64>> {
+        Ok(Vec::new())
+    }
 
-        while child
-            .try_wait()
-            .context("Failed waiting for presentmon")?
-            .is_none()
-        {
-            let _ = focus_tracker.should_collect();
-            thread::sleep(Duration::from_millis(500));
+    fn is_finished(&mut self) -> Result<bool> {
+        match self.child.as_mut() {
+            Some(child) => Ok(child
+                .try_wait()
+                .context("Failed waiting for presentmon")?
+                .is_some()),
+            None => Ok(true),
         }
+    }
 
+    fn finish(&mut self) -> Result<CaptureTail> {
+        let mut child = self
+            .child
+            .take()
+            .ok_or_else(|| anyhow::anyhow!("PresentMon was not started"))?;
         let status = child
             .wait()
             .context("Failed to collect PresentMon process status")?;
 
-        focus_tracker.finalize_unfocused_tracking();
-        update_diagnostics_from_focus_tracker(&mut diagnostics, &focus_tracker);
-
         if !status.success() {
             anyhow::bail!(
                 "PresentMon failed with status {}. \
@@ -462,65 +881,32 @@ Confirm it runs directly (`presentmon --help`), then retry with `fps-tracker doc
             );
         }
 
-        if options.pause_on_unfocus && diagnostics.focus_pauses > 0 {
-            anyhow::bail!(
-                "Capture paused due to focus loss while using PresentMon. \
-PresentMon output cannot be safely trimmed by focus windows; re-run while keeping the target window focused, \
-or set --pause-on-unfocus false."
-            );
-        }
-
-        if options.focus_policy == FocusPolicy::Strict && focus_tracker.strict_focus_violation() {
-            anyhow::bail!(
-                "{} Re-run while keeping target window focused, increase --strict-unfocus-grace-ms, or use --focus-policy lenient.",
-                strict_focus_violation_message(options.strict_unfocus_grace_ms)
-            );
-        }
-
-        let frame_data = import::capframex::parse_capframex_csv_for_process(
-            &output_path,
-            if diagnostics.process_validation_enabled {
-                options.process_name.as_deref()
+        let process_filter = if self.filter_process {
+            self.process_name.as_deref()
+        } else {
+            None
+        };
+        // PresentMon 2.x renamed MsBetweenPresents to FrameTime.
+        let frame_data =
+            if import::presentmon::looks_like_presentmon2_capture_file(&self.output_path) {
+                import::presentmon::parse_presentmon2_csv_for_process(
+                    &self.output_path,
+                    process_filter,
+                )
             } else {
-                None
-            },
-        )
-        .with_context(|| format!("Failed to parse {}", output_path.display()))?;
-
-        if let (Some(target), Some(observed)) = (
-            options.process_name.as_deref(),
-            frame_data.application.as_deref(),
-        ) {
-            if !focus::process_name_matches(observed, target) {
-                diagnostics.process_mismatch_detected = true;
+                import::capframex::parse_capframex_csv_for_process(
+                    &self.output_path,
+                    process_filter,
+                )
             }
-        }
-
-        if diagnostics.process_validation_enabled && diagnostics.process_mismatch_detected {
-            anyhow::bail!(
-                "PresentMon process validation failed: capture did not match target process '{}'.",
-                options
-                    .process_name
-                    .as_deref()
-                    .unwrap_or("(unknown target process)")
-            );
-        }
+            .with_context(|| format!("Failed to parse {}", self.output_path.display()))?;
 
-        let ended_at = Utc::now();
-        build_result(
-            frame_data.frame_times_ms,
-            "PresentMon",
-            Some(output_path),
-            options.game_hint.clone().or(frame_data.application),
-            started_at,
-            ended_at,
-            start_instant
-                .elapsed()
-                .as_secs_f64()
-                .max(frame_data.duration_secs),
-            options.max_frame_time_ms,
-            diagnostics,
-        )
+        Ok(CaptureTail {
+            frame_times_ms: frame_data.frame_times_ms,
+            application: frame_data.application,
+            duration_secs: Some(frame_data.duration_secs),
+            telemetry: frame_data.telemetry,
+        })
     }
 }
 
@@ -538,7 +924,7 @@ fn build_result(
 ) -> Result<LiveCaptureResult> {
     let valid_frame_times: Vec<f64> = frame_times_ms
         .into_iter()
-        .filter(|value| value.is_finite() && *value > 0.0 && *value <= max_frame_time_ms)
+        .filter(|value| is_valid_frame_time(*value, max_frame_time_ms))
         .collect();
 
     if valid_frame_times.len() < 60 {
@@ -549,33 +935,17 @@ fn build_result(
     }
 
     let frame_count = valid_frame_times.len();
-    let avg_frame_time_ms = valid_frame_times.iter().sum::<f64>() / frame_count as f64;
-    let avg_fps = 1000.0 / avg_frame_time_ms;
-
-    let mut sorted = valid_frame_times.clone();
-    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-
-    let idx_99 = ((frame_count as f64 * 0.99) as usize).min(frame_count - 1);
-    let p99_ft = sorted[idx_99];
-    let fps_1_low = 1000.0 / p99_ft;
-
-    let low_0_1_fps = if frame_count >= 1000 {
-        let idx_999 = ((frame_count as f64 * 0.999) as usize).min(frame_count - 1);
-        Some(1000.0 / sorted[idx_999])
-    } else {
-        None
-    };
-
-    let fps_values: Vec<f64> = valid_frame_times.iter().map(|ft| 1000.0 / ft).collect();
-    let min_fps = fps_values.iter().copied().fold(f64::INFINITY, f64::min);
-    let max_fps = fps_values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-
     let quality_data = import::FrameData {
         frame_times_ms: valid_frame_times.clone(),
         application: game_hint.clone(),
         duration_secs,
         source: source.to_string(),
+        telemetry: import::FrameTelemetry::default(),
+        sampled: false,
     };
+    let stats = quality_data
+        .calculate_stats_with_max_frame_time(max_frame_time_ms)
+        .context("Failed to compute live capture statistics")?;
     let stutter_spike_count = quality_data.stutter_spike_count(max_frame_time_ms);
     let stutter_spike_ratio = quality_data.stutter_spike_ratio(max_frame_time_ms);
     let dropped_sample_ratio =
@@ -601,11 +971,15 @@ fn build_result(
         ended_at,
         duration_secs,
         frame_count,
-        avg_fps,
-        fps_1_low,
-        fps_01_low: low_0_1_fps,
-        min_fps,
-        max_fps,
+        avg_fps: stats.avg_fps,
+        fps_1_low: stats.fps_1_low.context("Live capture produced no 1% low")?,
+        fps_01_low: stats.fps_01_low,
+        min_fps: stats.min_fps,
+        max_fps: stats.max_fps,
+        frame_time_stats: stats.frame_time_stats,
+        telemetry: None,
+        bottleneck: None,
+        power: None,
         target_process: diagnostics.target_process,
         focus_pauses: diagnostics.focus_pauses,
         samples_dropped_unfocused: diagnostics.samples_dropped_unfocused,
@@ -616,9 +990,16 @@ fn build_result(
         stutter_spike_count,
         stutter_spike_ratio,
         unstable_capture,
+        frame_times_ms: quality_data.frame_times_ms,
+        frame_telemetry: import::FrameTelemetry::default(),
+        trim: None,
     })
 }
 
+fn is_valid_frame_time(value: f64, max_frame_time_ms: f64) -> bool {
+    value.is_finite() && value > 0.0 && value <= max_frame_time_ms
+}
+
 fn compute_capture_quality_score(
     frame_count: usize,
     focus_pauses: u32,
@@ -665,7 +1046,9 @@ fn compute_dropped_sample_ratio(frame_count: usize, dropped: usize) -> f64 {
 #[derive(Debug, Default)]
 struct MangoHudStreamParser {
     frametime_col: Option<usize>,
-    header_seen: bool,
+    telemetry_columns: import::mangohud::MangoHudTelemetryColumns,
+    /// Telemetry for every frame returned by `parse_line`.
+    telemetry: import::FrameTelemetry,
 }
 
 impl MangoHudStreamParser {
@@ -677,17 +1060,23 @@ impl MangoHudStreamParser {
 
         let fields: Vec<&str> = trimmed.split(',').collect();
 
-        if !self.header_seen {
+        // The header may follow MangoHud's system-info preamble rows.
+        if self.frametime_col.is_none() {
             if let Some(idx) = find_frametime_column(&fields) {
                 self.frametime_col = Some(idx);
-                self.header_seen = true;
+                self.telemetry_columns =
+                    import::mangohud::MangoHudTelemetryColumns::from_header(&fields);
                 return None;
             }
-            self.header_seen = true;
         }
 
         if let Some(idx) = self.frametime_col {
-            return fields.get(idx).and_then(|value| parse_frametime(value));
+            let frame_time = fields.get(idx).and_then(|value| parse_frametime(value));
+            if frame_time.is_some() {
+                self.telemetry_columns
+                    .push_row(&fields, &mut self.telemetry);
+            }
+            return frame_time;
         }
 
         if fields.len() == 1 {
@@ -979,14 +1368,18 @@ impl FocusTracker {
 #[cfg(test)]
 mod tests {
     use super::{
-        adjust_poll_interval, build_result, compute_capture_quality_score,
+        adjust_poll_interval, apply_power_summary, build_result, compute_capture_quality_score,
         compute_dropped_sample_ratio, find_frametime_column, parse_chunk_frametimes,
-        parse_frametime, resolve_source, should_collect_for_target_process, CaptureDiagnostics,
-        CaptureSource, FocusPolicy, FocusTracker, LiveCaptureOptions, MangoHudStreamParser,
+        parse_frametime, resolve_source, run_capture_loop, should_collect_for_target_process,
+        CaptureBackend, CaptureDiagnostics, CaptureSource, CaptureTail, FocusPolicy, FocusTracker,
+        LiveCaptureOptions, MangoHudStreamParser, ReplayBackend, TrimOptions,
     };
-    use crate::import::FrameData;
+    use crate::import::{FrameData, FrameTelemetry};
+    use anyhow::Result;
     use chrono::Utc;
+    use std::collections::VecDeque;
     use std::io::Write;
+    use std::path::PathBuf;
     use std::time::{Duration, Instant};
     use tempfile::NamedTempFile;
 
@@ -1010,6 +1403,26 @@ mod tests {
         assert_eq!(parser.parse_line("120,8.33,40"), Some(8.33));
     }
 
+    #[test]
+    fn stream_parser_finds_header_after_preamble_and_keeps_telemetry() {
+        let mut parser = MangoHudStreamParser::default();
+        assert_eq!(parser.parse_line("os,cpu,gpu,ram,kernel,driver"), None);
+        assert_eq!(
+            parser.parse_line("Arch Linux,Ryzen 7,RX 7900 XTX,32,6.9,Mesa"),
+            None
+        );
+        assert_eq!(
+            parser.parse_line("fps,frametime,cpu_load,gpu_load,gpu_power"),
+            None
+        );
+        assert_eq!(parser.parse_line("144,6.94,35,97,310"), Some(6.94));
+        assert_eq!(parser.parse_line("143,6.99,37,,315"), Some(6.99));
+
+        assert_eq!(parser.telemetry.gpu_power_w, vec![310.0, 315.0]);
+        assert_eq!(parser.telemetry.gpu_load_pct.len(), 2);
+        assert!(parser.telemetry.gpu_load_pct[1].is_nan());
+    }
+
     #[test]
     fn chunk_parser_keeps_partial_lines_between_reads() {
         let mut parser = MangoHudStreamParser::default();
@@ -1046,6 +1459,8 @@ mod tests {
             application: None,
             duration_secs: 1.0,
             source: "test".to_string(),
+            telemetry: FrameTelemetry::default(),
+            sampled: false,
         };
         let count = frame_data.stutter_spike_count(1000.0);
         let ratio = frame_data.stutter_spike_ratio(1000.0);
@@ -1184,6 +1599,41 @@ mod tests {
         assert!(result.unstable_capture);
     }
 
+    #[test]
+    fn power_summary_penalises_battery_and_throttling() {
+        use crate::hardware::power::{PowerProfile, PowerSample, PowerSummary};
+
+        let mut result = build_result(
+            vec![4.0; 2_000],
+            "test",
+            None,
+            Some("test-game".to_string()),
+            Utc::now(),
+            Utc::now(),
+            8.0,
+            1000.0,
+            CaptureDiagnostics::default(),
+        )
+        .expect("expected clean result");
+        let clean_score = result.capture_quality_score;
+
+        let power = PowerSummary::from_samples(
+            &PowerProfile::default(),
+            &[PowerSample {
+                on_battery: Some(true),
+                cpu_temp_c: Some(98.0),
+                ..PowerSample::default()
+            }],
+            3,
+        );
+        apply_power_summary(&mut result, power);
+
+        assert_eq!(result.capture_quality_score, clean_score - 40);
+        assert!(result.unstable_capture);
+        assert!(result.power.as_ref().is_some_and(|p| p.on_battery));
+        assert!(result.to_string().contains("Ran on battery power"));
+    }
+
     #[test]
     fn resolve_source_prefers_explicit_mangohud_file_in_auto_mode() {
         let mut file = NamedTempFile::new().expect("temp file");
@@ -1202,6 +1652,8 @@ mod tests {
             process_validation: false,
             max_frame_time_ms: 1000.0,
             strict_unfocus_grace_ms: 1000,
+            replay_speed: 1.0,
+            trim: TrimOptions::default(),
         };
 
         assert_eq!(
@@ -1228,6 +1680,8 @@ mod tests {
             process_validation: false,
             max_frame_time_ms: 1000.0,
             strict_unfocus_grace_ms: 1000,
+            replay_speed: 1.0,
+            trim: TrimOptions::default(),
         };
 
         assert_eq!(
@@ -1254,8 +1708,183 @@ mod tests {
             process_validation: false,
             max_frame_time_ms: 1000.0,
             strict_unfocus_grace_ms: 1000,
+            replay_speed: 1.0,
+            trim: TrimOptions::default(),
         };
 
         assert!(resolve_source(&options).is_err());
     }
+
+    struct FakeBackend {
+        batches: VecDeque<Vec<f64>>,
+        tail: CaptureTail,
+        streams: bool,
+        started: bool,
+    }
+
+    impl FakeBackend {
+        fn new(batches: Vec<Vec<f64>>, tail: CaptureTail, streams: bool) -> Self {
+            Self {
+                batches: batches.into(),
+                tail,
+                streams,
+                started: false,
+            }
+        }
+    }
+
+    impl CaptureBackend for FakeBackend {
+        fn label(&self) -> &str {
+            "Fake"
+        }
+
+        fn capture_path(&self) -> Option<PathBuf> {
+            None
+        }
+
+        fn streams_samples(&self) -> bool {
+            self.streams
+        }
+
+        fn start(&mut self) -> Result<()> {
+            self.started = true;
+            Ok(())
+        }
+
+        fn poll(&mut self) -> Result<Vec<f64>> {
+            assert!(self.started, "poll before start");
+            Ok(self.batches.pop_front().unwrap_or_default())
+        }
+
+        fn is_finished(&mut self) -> Result<bool> {
+            Ok(self.batches.is_empty())
+        }
+
+        // Keep results independent of the test machine's power state.
+        fn samples_live_hardware(&self) -> bool {
+            false
+        }
+
+        fn finish(&mut self) -> Result<CaptureTail> {
+            Ok(std::mem::take(&mut self.tail))
+        }
+    }
+
+    fn unmonitored_options() -> LiveCaptureOptions {
+        LiveCaptureOptions {
+            source: CaptureSource::Auto,
+            duration_secs: 30,
+            file: None,
+            game_hint: None,
+            process_name: None,
+            focus_policy: FocusPolicy::Lenient,
+            pause_on_unfocus: false,
+            poll_ms: 50,
+            process_validation: false,
+            max_frame_time_ms: 1000.0,
+            strict_unfocus_grace_ms: 1000,
+            replay_speed: 1.0,
+            trim: TrimOptions::default(),
+        }
+    }
+
+    #[test]
+    fn capture_loop_collects_streamed_batches_and_tail() {
+        let mut backend = FakeBackend::new(
+            vec![vec![10.0; 40], vec![10.0; 40], vec![20.0; 40]],
+            CaptureTail {
+                frame_times_ms: vec![10.0; 5],
+                ..CaptureTail::default()
+            },
+            true,
+        );
+        let mut options = unmonitored_options();
+        options.game_hint = Some("test-game".to_string());
+
+        let result = run_capture_loop(&mut backend, &options).expect("fake capture");
+
+        assert_eq!(result.source, "Fake");
+        assert_eq!(result.frame_count, 125);
+        assert_eq!(result.frame_times_ms.len(), 125);
+        assert_eq!(result.samples_dropped_unfocused, 0);
+        assert_eq!(result.game_hint.as_deref(), Some("test-game"));
+        assert!(result.capture_path.is_none());
+    }
+
+    #[test]
+    fn capture_loop_uses_batch_source_tail_metadata() {
+        let mut backend = FakeBackend::new(
+            Vec::new(),
+            CaptureTail {
+                frame_times_ms: vec![8.0; 200],
+                application: Some("game.exe".to_string()),
+                duration_secs: Some(42.0),
+                telemetry: FrameTelemetry::default(),
+            },
+            false,
+        );
+
+        let result = run_capture_loop(&mut backend, &unmonitored_options()).expect("fake capture");
+
+        assert_eq!(result.frame_count, 200);
+        assert_eq!(result.game_hint.as_deref(), Some("game.exe"));
+        assert!(result.duration_secs >= 42.0);
+    }
+
+    #[test]
+    fn capture_loop_rejects_too_few_samples() {
+        let mut backend = FakeBackend::new(vec![vec![10.0; 10]], CaptureTail::default(), true);
+
+        assert!(run_capture_loop(&mut backend, &unmonitored_options()).is_err());
+    }
+
+    #[test]
+    fn replay_backend_streams_recorded_log_through_capture_loop() {
+        let mut file = NamedTempFile::new().expect("temp file");
+        writeln!(file, "fps,frametime,cpu_load").expect("write header");
+        for _ in 0..150 {
+            writeln!(file, "200,5.0,40").expect("write row");
+        }
+
+        let mut options = unmonitored_options();
+        options.source = CaptureSource::Replay;
+        options.file = Some(file.path().to_path_buf());
+        options.replay_speed = 50.0;
+
+        let mut backend = ReplayBackend::new(&options).expect("replay backend");
+        let result = run_capture_loop(&mut backend, &options).expect("replay capture");
+
+        assert_eq!(result.source, "Replay (MangoHud)");
+        assert!((result.duration_secs - 0.75).abs() < 1e-9);
+        assert_eq!(result.frame_count, 150);
+        assert!((result.avg_fps - 200.0).abs() < 0.01);
+        assert_eq!(result.capture_path.as_deref(), Some(file.path()));
+    }
+
+    #[test]
+    fn replay_backend_paces_frames_by_wall_clock() {
+        let mut options = unmonitored_options();
+        options.replay_speed = 1.0;
+        let mut backend = ReplayBackend {
+            label: "Replay (test)".to_string(),
+            capture_path: PathBuf::from("replay.csv"),
+            frame_times_ms: vec![1_000.0; 3],
+            telemetry: crate::import::FrameTelemetry::default(),
+            next_frame: 0,
+            replayed_ms: 0.0,
+            speed: options.replay_speed,
+            started: None,
+        };
+
+        backend.start().expect("start");
+        assert!(backend.poll().expect("poll").is_empty());
+        assert!(!backend.is_finished().expect("finished"));
+    }
+
+    #[test]
+    fn replay_requires_file() {
+        let mut options = unmonitored_options();
+        options.source = CaptureSource::Replay;
+        assert!(ReplayBackend::new(&options).is_err());
+    }
 }
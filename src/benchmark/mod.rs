@@ -2,9 +2,15 @@
 //!
 //! Handles benchmark data structures, session tracking, and submission logic.
 
+pub mod compare;
 pub mod focus;
 pub mod live;
+pub mod passes;
+pub mod refresh;
+pub mod regressions;
+pub mod report;
 mod session;
 pub mod submit;
+pub mod wait;
 
 pub use submit::{BenchmarkSubmission, SubmissionResponse};
@@ -0,0 +1,473 @@
+//! Multi-pass live capture
+//!
+//! Runs the same live capture several times with a cooldown in between and
+//! reports run-to-run variance, so a single unlucky pass does not end up as
+//! the submitted result.
+
+use anyhow::Result;
+use std::fmt;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+use crate::benchmark::live::{run_live_capture, LiveCaptureOptions, LiveCaptureResult};
+use crate::import;
+use crate::import::bottleneck::BottleneckReport;
+
+pub const MAX_PASSES: u32 = 10;
+pub const MAX_COOLDOWN_SECS: u64 = 600;
+
+/// Passes further than this fraction from the median avg FPS are flagged,
+/// unless the spread of the run is already wider than that.
+const AVG_FPS_OUTLIER_FLOOR: f64 = 0.05;
+/// 1% lows are naturally noisier than averages, so allow a wider band.
+const FPS_1_LOW_OUTLIER_FLOOR: f64 = 0.10;
+/// Modified z-score threshold (Iglewicz & Hoaglin).
+const MODIFIED_Z_THRESHOLD: f64 = 3.5;
+
+#[derive(Debug, Clone, Copy)]
+pub struct MultiPassOptions {
+    pub passes: u32,
+    pub cooldown_secs: u64,
+}
+
+/// Mean, sample standard deviation and coefficient of variation of one metric.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricSpread {
+    pub mean: f64,
+    pub stddev: f64,
+    pub cv: f64,
+}
+
+impl MetricSpread {
+    fn from_values(values: &[f64]) -> Self {
+        if values.is_empty() {
+            return Self {
+                mean: 0.0,
+                stddev: 0.0,
+                cv: 0.0,
+            };
+        }
+
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let stddev = if values.len() > 1 {
+            let variance =
+                values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+        let cv = if mean > 0.0 { stddev / mean } else { 0.0 };
+
+        Self { mean, stddev, cv }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MultiPassSummary {
+    pub passes: Vec<LiveCaptureResult>,
+    /// Zero-based indices of passes flagged as outliers.
+    pub outliers: Vec<usize>,
+    /// Spread across all passes, outliers included.
+    pub avg_fps: MetricSpread,
+    pub fps_1_low: MetricSpread,
+    /// Combined result over the passes that were not excluded as outliers.
+    pub aggregate: LiveCaptureResult,
+}
+
+impl MultiPassSummary {
+    pub fn included_pass_count(&self) -> usize {
+        self.passes.len() - self.outliers.len()
+    }
+
+    /// Short description of the run used as submission notes.
+    pub fn submission_note(&self) -> String {
+        let mut note = format!(
+            "{} passes aggregated (avg FPS CV {:.1}%, 1% low CV {:.1}%)",
+            self.included_pass_count(),
+            self.avg_fps.cv * 100.0,
+            self.fps_1_low.cv * 100.0
+        );
+        if !self.outliers.is_empty() {
+            let excluded = self
+                .outliers
+                .iter()
+                .map(|idx| (idx + 1).to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            note.push_str(&format!("; excluded outlier passes: {}", excluded));
+        }
+        note
+    }
+}
+
+impl fmt::Display for MultiPassSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Multi-Pass Summary ({} passes)", self.passes.len())?;
+        for (idx, pass) in self.passes.iter().enumerate() {
+            let flag = if self.outliers.contains(&idx) {
+                "  [outlier, excluded]"
+            } else {
+                ""
+            };
+            writeln!(
+                f,
+                "  Pass {}: avg {:.1} FPS | 1% low {:.1} | {} samples | quality {}{}",
+                idx + 1,
+                pass.avg_fps,
+                pass.fps_1_low,
+                pass.frame_count,
+                pass.capture_quality_score,
+                flag
+            )?;
+        }
+        writeln!(
+            f,
+            "  Avg FPS: mean {:.1} | stddev {:.2} | CV {:.2}%",
+            self.avg_fps.mean,
+            self.avg_fps.stddev,
+            self.avg_fps.cv * 100.0
+        )?;
+        writeln!(
+            f,
+            "  1% Low: mean {:.1} | stddev {:.2} | CV {:.2}%",
+            self.fps_1_low.mean,
+            self.fps_1_low.stddev,
+            self.fps_1_low.cv * 100.0
+        )?;
+        writeln!(
+            f,
+            "  Aggregate ({} of {} passes): avg {:.1} FPS | 1% low {:.1}",
+            self.included_pass_count(),
+            self.passes.len(),
+            self.aggregate.avg_fps,
+            self.aggregate.fps_1_low
+        )?;
+        Ok(())
+    }
+}
+
+pub fn validate_multi_pass_options(options: &MultiPassOptions) -> Result<()> {
+    if !(1..=MAX_PASSES).contains(&options.passes) {
+        anyhow::bail!("passes must be between 1 and {}", MAX_PASSES);
+    }
+    if options.cooldown_secs > MAX_COOLDOWN_SECS {
+        anyhow::bail!("pass-cooldown must be <= {} seconds", MAX_COOLDOWN_SECS);
+    }
+    Ok(())
+}
+
+/// Run `pass_options.passes` live captures back to back and summarize them.
+///
+/// A failing pass aborts the whole run; its error is returned unchanged so
+/// callers can show the usual retry guidance.
+pub fn run_multi_pass_capture(
+    options: &LiveCaptureOptions,
+    pass_options: &MultiPassOptions,
+) -> Result<MultiPassSummary> {
+    validate_multi_pass_options(pass_options)?;
+
+    let total = pass_options.passes;
+    let mut results = Vec::with_capacity(total as usize);
+    for pass in 1..=total {
+        if pass > 1 && pass_options.cooldown_secs > 0 {
+            wait_for_cooldown(pass_options.cooldown_secs, pass, total);
+        }
+
+        println!("\nPass {}/{}", pass, total);
+        let result = run_live_capture(options)?;
+        println!(
+            "Pass {}/{} done: avg {:.1} FPS | 1% low {:.1}",
+            pass, total, result.avg_fps, result.fps_1_low
+        );
+        results.push(result);
+    }
+
+    summarize_passes(results)
+}
+
+fn wait_for_cooldown(cooldown_secs: u64, next_pass: u32, total: u32) {
+    for remaining in (1..=cooldown_secs).rev() {
+        print!(
+            "\rCooldown before pass {}/{}: {:>3}s remaining",
+            next_pass, total, remaining
+        );
+        let _ = std::io::stdout().flush();
+        thread::sleep(Duration::from_secs(1));
+    }
+    println!();
+}
+
+pub fn summarize_passes(passes: Vec<LiveCaptureResult>) -> Result<MultiPassSummary> {
+    if passes.is_empty() {
+        anyhow::bail!("No capture passes to summarize");
+    }
+
+    let avg_values: Vec<f64> = passes.iter().map(|p| p.avg_fps).collect();
+    let low_values: Vec<f64> = passes.iter().map(|p| p.fps_1_low).collect();
+
+    let avg_outliers = find_outliers(&avg_values, AVG_FPS_OUTLIER_FLOOR);
+    let low_outliers = find_outliers(&low_values, FPS_1_LOW_OUTLIER_FLOOR);
+    let mut outliers: Vec<usize> = (0..passes.len())
+        .filter(|idx| avg_outliers.contains(idx) || low_outliers.contains(idx))
+        .collect();
+
+    // Excluding half the run or more means there is no clear consensus; keep
+    // everything and let the spread speak for itself.
+    if outliers.len() * 2 >= passes.len() {
+        outliers.clear();
+    }
+
+    let included: Vec<&LiveCaptureResult> = passes
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !outliers.contains(idx))
+        .map(|(_, pass)| pass)
+        .collect();
+    let aggregate = aggregate_passes(&included);
+
+    Ok(MultiPassSummary {
+        avg_fps: MetricSpread::from_values(&avg_values),
+        fps_1_low: MetricSpread::from_values(&low_values),
+        outliers,
+        aggregate,
+        passes,
+    })
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Flag values whose distance from the median exceeds both a modified
+/// z-score threshold and a relative floor. Needs at least three passes.
+fn find_outliers(values: &[f64], relative_floor: f64) -> Vec<usize> {
+    if values.len() < 3 {
+        return Vec::new();
+    }
+
+    let center = median(values);
+    let deviations: Vec<f64> = values.iter().map(|v| (v - center).abs()).collect();
+    let mad = median(&deviations);
+    let threshold = (MODIFIED_Z_THRESHOLD * 1.4826 * mad).max(center.abs() * relative_floor);
+
+    deviations
+        .iter()
+        .enumerate()
+        .filter(|(_, deviation)| **deviation > threshold)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn aggregate_passes(passes: &[&LiveCaptureResult]) -> LiveCaptureResult {
+    let first = passes[0];
+    let last = passes[passes.len() - 1];
+    let count = passes.len() as f64;
+
+    let frame_count: usize = passes.iter().map(|p| p.frame_count).sum();
+    let samples_dropped_unfocused: usize = passes.iter().map(|p| p.samples_dropped_unfocused).sum();
+    let stutter_spike_count: usize = passes.iter().map(|p| p.stutter_spike_count).sum();
+    let fps_01_low = if passes.iter().all(|p| p.fps_01_low.is_some()) {
+        Some(passes.iter().filter_map(|p| p.fps_01_low).sum::<f64>() / count)
+    } else {
+        None
+    };
+    let observed = frame_count + samples_dropped_unfocused;
+    let frame_times_ms: Vec<f64> = passes
+        .iter()
+        .flat_map(|p| p.frame_times_ms.iter().copied())
+        .collect();
+    let segments: Vec<&[f64]> = passes.iter().map(|p| p.frame_times_ms.as_slice()).collect();
+    let frame_time_stats =
+        import::FrameTimeStats::from_segments(&segments, import::DEFAULT_LOW_FPS_THRESHOLD)
+            .unwrap_or_default();
+
+    LiveCaptureResult {
+        source: first.source.clone(),
+        capture_path: last.capture_path.clone(),
+        game_hint: passes.iter().find_map(|p| p.game_hint.clone()),
+        started_at: first.started_at,
+        ended_at: last.ended_at,
+        duration_secs: passes.iter().map(|p| p.duration_secs).sum(),
+        frame_count,
+        avg_fps: passes.iter().map(|p| p.avg_fps).sum::<f64>() / count,
+        fps_1_low: passes.iter().map(|p| p.fps_1_low).sum::<f64>() / count,
+        fps_01_low,
+        min_fps: passes
+            .iter()
+            .map(|p| p.min_fps)
+            .fold(f64::INFINITY, f64::min),
+        max_fps: passes.iter().map(|p| p.max_fps).fold(0.0, f64::max),
+        frame_time_stats,
+        telemetry: import::TelemetrySummary::merge(
+            passes.iter().filter_map(|p| p.telemetry.as_ref()),
+        ),
+        bottleneck: BottleneckReport::merge(passes.iter().filter_map(|p| p.bottleneck.as_ref())),
+        // The worst pass decides whether the run is flagged.
+        power: passes
+            .iter()
+            .filter_map(|p| p.power.as_ref())
+            .max_by_key(|power| power.quality_penalty())
+            .cloned(),
+        target_process: passes.iter().find_map(|p| p.target_process.clone()),
+        focus_pauses: passes.iter().map(|p| p.focus_pauses).sum(),
+        samples_dropped_unfocused,
+        capture_quality_score: passes
+            .iter()
+            .map(|p| p.capture_quality_score)
+            .min()
+            .unwrap_or(0),
+        longest_unfocused_ms: passes
+            .iter()
+            .map(|p| p.longest_unfocused_ms)
+            .max()
+            .unwrap_or(0),
+        total_unfocused_ms: passes.iter().map(|p| p.total_unfocused_ms).sum(),
+        dropped_sample_ratio: if observed > 0 {
+            samples_dropped_unfocused as f64 / observed as f64
+        } else {
+            0.0
+        },
+        stutter_spike_count,
+        stutter_spike_ratio: if frame_count > 0 {
+            stutter_spike_count as f64 / frame_count as f64
+        } else {
+            0.0
+        },
+        unstable_capture: passes.iter().any(|p| p.unstable_capture),
+        frame_times_ms,
+        frame_telemetry: import::FrameTelemetry::concat(passes.iter().map(|p| &p.frame_telemetry)),
+        trim: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_outliers, summarize_passes, validate_multi_pass_options, MultiPassOptions};
+    use crate::benchmark::live::LiveCaptureResult;
+    use crate::import::{FrameTelemetry, FrameTimeStats};
+    use chrono::{Duration, Utc};
+
+    fn pass(avg_fps: f64, fps_1_low: f64, offset_secs: i64) -> LiveCaptureResult {
+        let started_at = Utc::now() + Duration::seconds(offset_secs);
+        LiveCaptureResult {
+            source: "MangoHud Live".to_string(),
+            capture_path: None,
+            game_hint: Some("Cyberpunk 2077".to_string()),
+            started_at,
+            ended_at: started_at + Duration::seconds(60),
+            duration_secs: 60.0,
+            frame_count: 6000,
+            avg_fps,
+            fps_1_low,
+            fps_01_low: Some(fps_1_low * 0.8),
+            min_fps: fps_1_low * 0.7,
+            max_fps: avg_fps * 1.3,
+            frame_time_stats: FrameTimeStats::default(),
+            telemetry: None,
+            bottleneck: None,
+            power: None,
+            target_process: Some("cyberpunk2077.exe".to_string()),
+            focus_pauses: 0,
+            samples_dropped_unfocused: 0,
+            capture_quality_score: 95,
+            longest_unfocused_ms: 0,
+            total_unfocused_ms: 0,
+            dropped_sample_ratio: 0.0,
+            stutter_spike_count: 6,
+            stutter_spike_ratio: 0.001,
+            unstable_capture: false,
+            frame_times_ms: vec![1000.0 / avg_fps; 6000],
+            frame_telemetry: FrameTelemetry::default(),
+            trim: None,
+        }
+    }
+
+    #[test]
+    fn spread_uses_sample_standard_deviation() {
+        let summary = summarize_passes(vec![
+            pass(100.0, 70.0, 0),
+            pass(102.0, 72.0, 100),
+            pass(104.0, 74.0, 200),
+        ])
+        .unwrap();
+
+        assert!((summary.avg_fps.mean - 102.0).abs() < 1e-9);
+        assert!((summary.avg_fps.stddev - 2.0).abs() < 1e-9);
+        assert!((summary.avg_fps.cv - 2.0 / 102.0).abs() < 1e-9);
+        assert!((summary.fps_1_low.mean - 72.0).abs() < 1e-9);
+        assert!(summary.outliers.is_empty());
+    }
+
+    #[test]
+    fn outlier_pass_is_flagged_and_excluded_from_aggregate() {
+        let summary = summarize_passes(vec![
+            pass(120.0, 90.0, 0),
+            pass(121.0, 91.0, 100),
+            pass(80.0, 40.0, 200),
+            pass(119.0, 89.0, 300),
+        ])
+        .unwrap();
+
+        assert_eq!(summary.outliers, vec![2]);
+        assert_eq!(summary.included_pass_count(), 3);
+        assert!((summary.aggregate.avg_fps - 120.0).abs() < 1e-9);
+        assert!((summary.aggregate.fps_1_low - 90.0).abs() < 1e-9);
+        assert_eq!(summary.aggregate.frame_count, 18000);
+        assert!((summary.aggregate.duration_secs - 180.0).abs() < 1e-9);
+        assert!(summary
+            .submission_note()
+            .contains("excluded outlier passes: 3"));
+    }
+
+    #[test]
+    fn two_passes_never_flag_outliers() {
+        assert!(find_outliers(&[60.0, 140.0], 0.05).is_empty());
+        let summary = summarize_passes(vec![pass(60.0, 40.0, 0), pass(140.0, 90.0, 100)]).unwrap();
+        assert!(summary.outliers.is_empty());
+        assert!((summary.aggregate.avg_fps - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn aggregate_jitter_ignores_the_gap_between_passes() {
+        let summary = summarize_passes(vec![pass(60.0, 40.0, 0), pass(140.0, 90.0, 100)]).unwrap();
+        assert_eq!(summary.aggregate.frame_time_stats.jitter_max_ms, 0.0);
+        assert_eq!(summary.aggregate.frame_time_stats.jitter_avg_ms, 0.0);
+    }
+
+    #[test]
+    fn small_run_to_run_noise_is_not_an_outlier() {
+        assert!(find_outliers(&[100.0, 100.0, 100.0, 103.0], 0.05).is_empty());
+    }
+
+    #[test]
+    fn validates_pass_options() {
+        assert!(validate_multi_pass_options(&MultiPassOptions {
+            passes: 0,
+            cooldown_secs: 10,
+        })
+        .is_err());
+        assert!(validate_multi_pass_options(&MultiPassOptions {
+            passes: 11,
+            cooldown_secs: 10,
+        })
+        .is_err());
+        assert!(validate_multi_pass_options(&MultiPassOptions {
+            passes: 3,
+            cooldown_secs: 601,
+        })
+        .is_err());
+        assert!(validate_multi_pass_options(&MultiPassOptions {
+            passes: 3,
+            cooldown_secs: 30,
+        })
+        .is_ok());
+    }
+}
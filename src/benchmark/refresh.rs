@@ -0,0 +1,122 @@
+//! Refresh-rate aware frame pacing metrics
+//!
+//! An average of 140 FPS means different things on a 60 Hz, 144 Hz or 240 Hz
+//! monitor. These metrics put the frame times next to the display's refresh
+//! interval: how many frames missed a refresh, and whether the run sat at a
+//! V-Sync or frame cap.
+
+use serde::{Deserialize, Serialize};
+
+use crate::hardware::display::DisplayInfo;
+
+/// Frame times within this fraction above the refresh interval still count as
+/// on time, so scheduling jitter at a cap is not reported as a miss.
+const INTERVAL_TOLERANCE: f64 = 0.02;
+
+/// Median FPS within this fraction below the refresh rate suggests a cap.
+const CAP_BAND: f64 = 0.03;
+
+/// Frame pacing relative to the display refresh rate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RefreshPacing {
+    pub refresh_hz: f64,
+    /// Whether the display supports VRR, if known
+    #[serde(default)]
+    pub vrr: Option<bool>,
+    /// Share of frames that took longer than one refresh interval
+    pub frames_slower_than_refresh_pct: f64,
+    /// Average FPS as a percentage of the refresh rate
+    pub avg_fps_of_refresh_pct: f64,
+    /// Median FPS sits just under the refresh rate (V-Sync / frame cap likely)
+    pub likely_capped: bool,
+}
+
+impl RefreshPacing {
+    pub fn from_frame_times(
+        frame_times_ms: &[f64],
+        refresh_hz: f64,
+        vrr: Option<bool>,
+    ) -> Option<Self> {
+        let frame_times: Vec<f64> = frame_times_ms
+            .iter()
+            .copied()
+            .filter(|ms| ms.is_finite() && *ms > 0.0)
+            .collect();
+        if frame_times.is_empty() || !refresh_hz.is_finite() || refresh_hz <= 0.0 {
+            return None;
+        }
+
+        let interval_ms = 1000.0 / refresh_hz;
+        let slow = frame_times
+            .iter()
+            .filter(|ms| **ms > interval_ms * (1.0 + INTERVAL_TOLERANCE))
+            .count();
+        let frames_slower_than_refresh_pct = slow as f64 / frame_times.len() as f64 * 100.0;
+
+        let total_ms: f64 = frame_times.iter().sum();
+        let avg_fps = frame_times.len() as f64 * 1000.0 / total_ms;
+
+        let mut sorted = frame_times;
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let median_fps = 1000.0 / sorted[sorted.len() / 2];
+        let likely_capped = median_fps <= refresh_hz * (1.0 + INTERVAL_TOLERANCE)
+            && median_fps >= refresh_hz * (1.0 - CAP_BAND);
+
+        Some(Self {
+            refresh_hz,
+            vrr,
+            frames_slower_than_refresh_pct,
+            avg_fps_of_refresh_pct: avg_fps / refresh_hz * 100.0,
+            likely_capped,
+        })
+    }
+
+    /// Pacing against a detected display, when its refresh rate is known
+    pub fn for_display(frame_times_ms: &[f64], display: Option<&DisplayInfo>) -> Option<Self> {
+        let display = display?;
+        Self::from_frame_times(frame_times_ms, display.refresh_hz?, display.vrr_capable)
+    }
+}
+
+impl std::fmt::Display for RefreshPacing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.1}% of frames slower than {:.0} Hz refresh, avg FPS at {:.0}% of refresh",
+            self.frames_slower_than_refresh_pct, self.refresh_hz, self.avg_fps_of_refresh_pct
+        )?;
+        if self.likely_capped {
+            write!(f, " (likely capped at refresh)")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_frames_missing_the_refresh_interval() {
+        // 144 Hz → 6.94 ms interval; 2 of 10 frames are slower.
+        let mut frame_times = vec![5.0; 8];
+        frame_times.extend([8.0, 12.0]);
+        let pacing = RefreshPacing::from_frame_times(&frame_times, 144.0, Some(true)).unwrap();
+        assert!((pacing.frames_slower_than_refresh_pct - 20.0).abs() < 1e-9);
+        assert!(!pacing.likely_capped);
+        assert!(pacing.avg_fps_of_refresh_pct > 100.0);
+    }
+
+    #[test]
+    fn test_flags_runs_pinned_at_refresh() {
+        // V-Sync at 60 Hz with jitter around 16.67 ms.
+        let frame_times = [16.6, 16.7, 16.68, 16.65, 16.9, 16.5, 16.7];
+        let pacing = RefreshPacing::from_frame_times(&frame_times, 60.0, None).unwrap();
+        assert!(pacing.likely_capped);
+        assert_eq!(pacing.frames_slower_than_refresh_pct, 0.0);
+        assert!(pacing.to_string().contains("likely capped"));
+
+        assert!(RefreshPacing::from_frame_times(&frame_times, 0.0, None).is_none());
+        assert!(RefreshPacing::for_display(&frame_times, None).is_none());
+    }
+}
@@ -0,0 +1,473 @@
+//! Performance regression detection across software updates
+//!
+//! History entries are grouped by game, resolution, preset, upscaling and
+//! hardware fingerprint. Local-only runs count, so a driver can be checked
+//! before it is rolled out; runs the backend rejected and entries with
+//! placeholder settings are left out. Within a group, consecutive runs that
+//! share the same GPU driver, kernel and OS version form an era. Each era is
+//! compared with the one before it. A shift in average FPS or 1% lows larger
+//! than the noise band is reported, together with the version change that
+//! marks the boundary as the suspected cause.
+//!
+//! The noise band is twice the standard error of the difference of the era
+//! means. It never drops below a floor, because a single run per era has no
+//! spread of its own.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::hardware::fingerprint::HardwareIdentity;
+use crate::storage::{HistoryEntry, HistoryStatus};
+
+/// Smallest shift (percent) ever reported, covering ordinary run-to-run variance.
+pub const DEFAULT_MIN_CHANGE_PCT: f64 = 3.0;
+/// Standard errors a shift must exceed to count as beyond noise.
+const NOISE_SIGMAS: f64 = 2.0;
+
+/// Extracts one metric from a history entry, if it was recorded.
+type MetricFn = fn(&HistoryEntry) -> Option<f64>;
+
+/// Software component whose version changed between two eras.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionComponent {
+    GpuDriver,
+    Kernel,
+    Os,
+}
+
+impl std::fmt::Display for VersionComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionComponent::GpuDriver => write!(f, "GPU driver"),
+            VersionComponent::Kernel => write!(f, "kernel"),
+            VersionComponent::Os => write!(f, "OS"),
+        }
+    }
+}
+
+/// A version change at an era boundary.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VersionChange {
+    pub component: VersionComponent,
+    pub from: String,
+    pub to: String,
+}
+
+impl std::fmt::Display for VersionChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} → {}", self.component, self.from, self.to)
+    }
+}
+
+/// Configuration runs are grouped by; only like-for-like results are compared.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct RegressionGroup {
+    pub game: String,
+    pub resolution: String,
+    pub preset: String,
+    pub ray_tracing: bool,
+    pub upscaling: Option<String>,
+    /// Anonymised hardware fingerprint; normalised names below are for display.
+    pub hardware_fingerprint: String,
+    pub cpu: String,
+    pub gpu: String,
+}
+
+impl RegressionGroup {
+    fn of(entry: &HistoryEntry) -> Self {
+        let submission = &entry.submission;
+        let identity = HardwareIdentity::from_system_info(&submission.system_info);
+        Self {
+            game: submission.game.trim().to_string(),
+            resolution: submission.resolution.trim().to_string(),
+            preset: submission.preset.trim().to_string(),
+            ray_tracing: submission.ray_tracing,
+            upscaling: submission.upscaling.clone(),
+            hardware_fingerprint: identity.fingerprint(),
+            cpu: identity.cpu_model,
+            gpu: identity.gpu_model,
+        }
+    }
+}
+
+impl std::fmt::Display for RegressionGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} @ {} {}", self.game, self.resolution, self.preset)?;
+        if self.ray_tracing {
+            write!(f, " RT")?;
+        }
+        if let Some(upscaling) = self.upscaling.as_deref() {
+            write!(f, " {}", upscaling)?;
+        }
+        write!(f, " ({} + {})", self.gpu, self.cpu)
+    }
+}
+
+/// A metric shift beyond noise between two consecutive eras.
+#[derive(Debug, Clone, Serialize)]
+pub struct RegressionFinding {
+    pub group: RegressionGroup,
+    /// "Average FPS" or "1% low FPS"
+    pub metric: &'static str,
+    pub before_mean: f64,
+    pub after_mean: f64,
+    pub before_runs: usize,
+    pub after_runs: usize,
+    /// `(after - before) / before` in percent
+    pub delta_pct: f64,
+    /// Shift needed to count as beyond noise, in percent
+    pub noise_pct: f64,
+    /// First run recorded after the change
+    pub changed_at: DateTime<Utc>,
+    /// Version changes at the era boundary (the suspected cause)
+    pub suspected_causes: Vec<VersionChange>,
+}
+
+impl RegressionFinding {
+    pub fn is_regression(&self) -> bool {
+        self.delta_pct < 0.0
+    }
+}
+
+/// Result of scanning the history.
+#[derive(Debug, Clone, Serialize)]
+pub struct RegressionReport {
+    /// Groups with at least one version change to compare across
+    pub groups_compared: usize,
+    /// Regressions first, largest shift first within each kind
+    pub findings: Vec<RegressionFinding>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct SoftwareVersions {
+    gpu_driver: Option<String>,
+    kernel: Option<String>,
+    os: Option<String>,
+}
+
+impl SoftwareVersions {
+    fn of(entry: &HistoryEntry) -> Self {
+        let system = &entry.submission.system_info;
+        let os = match system.os_version.as_deref() {
+            Some(version) => format!("{} {}", system.os, version),
+            None => system.os.clone(),
+        };
+        Self {
+            gpu_driver: system.gpu.driver_version.clone(),
+            kernel: system.kernel_version.clone(),
+            os: Some(os),
+        }
+    }
+
+    /// Components known on both sides whose versions differ.
+    fn changes_to(&self, next: &Self) -> Vec<VersionChange> {
+        [
+            (
+                VersionComponent::GpuDriver,
+                &self.gpu_driver,
+                &next.gpu_driver,
+            ),
+            (VersionComponent::Kernel, &self.kernel, &next.kernel),
+            (VersionComponent::Os, &self.os, &next.os),
+        ]
+        .into_iter()
+        .filter_map(|(component, from, to)| match (from, to) {
+            (Some(from), Some(to)) if from.trim() != to.trim() => Some(VersionChange {
+                component,
+                from: from.trim().to_string(),
+                to: to.trim().to_string(),
+            }),
+            _ => None,
+        })
+        .collect()
+    }
+}
+
+/// Consecutive runs on the same software versions.
+struct Era<'a> {
+    versions: SoftwareVersions,
+    entries: Vec<&'a HistoryEntry>,
+}
+
+/// Scan history entries (any order) for shifts that follow a version change.
+pub fn detect_regressions(entries: &[HistoryEntry], min_change_pct: f64) -> RegressionReport {
+    let mut groups: std::collections::BTreeMap<RegressionGroup, Vec<&HistoryEntry>> =
+        std::collections::BTreeMap::new();
+    for entry in entries {
+        if is_comparable(entry) {
+            groups
+                .entry(RegressionGroup::of(entry))
+                .or_default()
+                .push(entry);
+        }
+    }
+
+    let mut groups_compared = 0;
+    let mut findings = Vec::new();
+    for (group, mut runs) in groups {
+        runs.sort_by_key(|entry| entry.recorded_at);
+        let eras = split_eras(&runs);
+        if eras.len() < 2 {
+            continue;
+        }
+        groups_compared += 1;
+
+        for pair in eras.windows(2) {
+            let (before, after) = (&pair[0], &pair[1]);
+            let causes = before.versions.changes_to(&after.versions);
+            let changed_at = after.entries[0].recorded_at;
+
+            let metrics: [(&'static str, MetricFn); 2] = [
+                ("Average FPS", |entry| Some(entry.submission.avg_fps)),
+                ("1% low FPS", |entry| entry.submission.fps_1_low),
+            ];
+            for (metric, value) in metrics {
+                let before_values = metric_values(&before.entries, value);
+                let after_values = metric_values(&after.entries, value);
+                findings.extend(
+                    compare_eras(&before_values, &after_values, min_change_pct).map(|shift| {
+                        RegressionFinding {
+                            group: group.clone(),
+                            metric,
+                            before_mean: shift.before_mean,
+                            after_mean: shift.after_mean,
+                            before_runs: before_values.len(),
+                            after_runs: after_values.len(),
+                            delta_pct: shift.delta_pct,
+                            noise_pct: shift.noise_pct,
+                            changed_at,
+                            suspected_causes: causes.clone(),
+                        }
+                    }),
+                );
+            }
+        }
+    }
+
+    findings.sort_by(|a, b| {
+        b.is_regression()
+            .cmp(&a.is_regression())
+            .then(b.delta_pct.abs().total_cmp(&a.delta_pct.abs()))
+    });
+
+    RegressionReport {
+        groups_compared,
+        findings,
+    }
+}
+
+/// Whether an entry is a real run worth comparing: not rejected, with a
+/// measured average and actual settings.
+fn is_comparable(entry: &HistoryEntry) -> bool {
+    let submission = &entry.submission;
+    let is_placeholder = |value: &str| {
+        let value = value.trim();
+        value.is_empty() || value.eq_ignore_ascii_case("unknown")
+    };
+    entry.status != HistoryStatus::Rejected
+        && submission.avg_fps.is_finite()
+        && submission.avg_fps > 0.0
+        && !is_placeholder(&submission.game)
+        && !is_placeholder(&submission.resolution)
+        && !is_placeholder(&submission.preset)
+}
+
+fn split_eras<'a>(runs: &[&'a HistoryEntry]) -> Vec<Era<'a>> {
+    let mut eras: Vec<Era<'a>> = Vec::new();
+    for entry in runs {
+        let versions = SoftwareVersions::of(entry);
+        match eras.last_mut() {
+            Some(era) if era.versions.changes_to(&versions).is_empty() => {
+                // Fill in versions the earlier runs could not detect.
+                if era.versions.gpu_driver.is_none() {
+                    era.versions.gpu_driver = versions.gpu_driver;
+                }
+                if era.versions.kernel.is_none() {
+                    era.versions.kernel = versions.kernel;
+                }
+                era.entries.push(entry);
+            }
+            _ => eras.push(Era {
+                versions,
+                entries: vec![entry],
+            }),
+        }
+    }
+    eras
+}
+
+fn metric_values(entries: &[&HistoryEntry], value: MetricFn) -> Vec<f64> {
+    entries
+        .iter()
+        .filter_map(|entry| value(entry))
+        .filter(|value| value.is_finite() && *value > 0.0)
+        .collect()
+}
+
+struct EraShift {
+    before_mean: f64,
+    after_mean: f64,
+    delta_pct: f64,
+    noise_pct: f64,
+}
+
+fn compare_eras(before: &[f64], after: &[f64], min_change_pct: f64) -> Option<EraShift> {
+    if before.is_empty() || after.is_empty() {
+        return None;
+    }
+    let (before_mean, before_var) = mean_and_variance(before);
+    let (after_mean, after_var) = mean_and_variance(after);
+    let standard_error = (before_var / before.len() as f64 + after_var / after.len() as f64).sqrt();
+
+    let delta_pct = (after_mean - before_mean) / before_mean * 100.0;
+    let noise_pct = (NOISE_SIGMAS * standard_error / before_mean * 100.0).max(min_change_pct);
+    if delta_pct.abs() <= noise_pct {
+        return None;
+    }
+
+    Some(EraShift {
+        before_mean,
+        after_mean,
+        delta_pct,
+        noise_pct,
+    })
+}
+
+/// Mean and sample variance (zero for a single value).
+fn mean_and_variance(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    if values.len() < 2 {
+        return (mean, 0.0);
+    }
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmark::BenchmarkSubmission;
+    use crate::hardware::gpu::GpuInfo;
+    use crate::hardware::SystemInfo;
+    use chrono::Duration;
+
+    fn run(day: i64, driver: &str, kernel: &str, avg_fps: f64, fps_1_low: f64) -> HistoryEntry {
+        let fixture = SystemInfo::test_fixture();
+        let system_info = SystemInfo {
+            gpu: GpuInfo {
+                driver_version: Some(driver.to_string()),
+                ..fixture.gpu
+            },
+            os_version: Some("24.04".to_string()),
+            kernel_version: Some(kernel.to_string()),
+            ..fixture
+        };
+        let submission = BenchmarkSubmission::new(
+            system_info,
+            "Cyberpunk 2077".to_string(),
+            "1440p".to_string(),
+            "High".to_string(),
+            avg_fps,
+            Some(fps_1_low),
+            false,
+            None,
+        );
+        let mut entry = HistoryEntry::new(&submission, HistoryStatus::Submitted);
+        entry.recorded_at = Utc::now() - Duration::days(30 - day);
+        entry
+    }
+
+    #[test]
+    fn driver_update_that_drops_fps_is_reported_with_its_cause() {
+        let entries = vec![
+            run(0, "24.1", "6.8.0", 100.0, 70.0),
+            run(1, "24.1", "6.8.0", 101.0, 71.0),
+            run(2, "24.1", "6.8.0", 99.0, 69.0),
+            run(3, "24.2", "6.8.0", 90.0, 70.0),
+            run(4, "24.2", "6.8.0", 91.0, 70.5),
+        ];
+
+        let report = detect_regressions(&entries, DEFAULT_MIN_CHANGE_PCT);
+        assert_eq!(report.groups_compared, 1);
+        assert_eq!(report.findings.len(), 1, "1% lows stayed within noise");
+
+        let finding = &report.findings[0];
+        assert!(finding.is_regression());
+        assert_eq!(finding.metric, "Average FPS");
+        assert_eq!((finding.before_runs, finding.after_runs), (3, 2));
+        assert!((finding.delta_pct + 9.5).abs() < 0.01);
+        assert_eq!(
+            finding.suspected_causes,
+            vec![VersionChange {
+                component: VersionComponent::GpuDriver,
+                from: "24.1".to_string(),
+                to: "24.2".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn shifts_within_noise_or_without_version_change_are_ignored() {
+        let steady = vec![
+            run(0, "24.1", "6.8.0", 100.0, 70.0),
+            run(1, "24.1", "6.8.0", 80.0, 55.0),
+            run(2, "24.1", "6.8.0", 100.0, 70.0),
+        ];
+        let report = detect_regressions(&steady, DEFAULT_MIN_CHANGE_PCT);
+        assert_eq!(report.groups_compared, 0);
+        assert!(report.findings.is_empty());
+
+        let noisy = vec![
+            run(0, "24.1", "6.8.0", 100.0, 70.0),
+            run(1, "24.1", "6.8.0", 80.0, 56.0),
+            run(2, "24.1", "6.11.0", 85.0, 60.0),
+            run(3, "24.1", "6.11.0", 105.0, 72.0),
+        ];
+        let report = detect_regressions(&noisy, DEFAULT_MIN_CHANGE_PCT);
+        assert_eq!(report.groups_compared, 1);
+        assert!(report.findings.is_empty());
+    }
+
+    #[test]
+    fn different_settings_are_never_compared() {
+        let mut entries = vec![
+            run(0, "24.1", "6.8.0", 100.0, 70.0),
+            run(1, "24.2", "6.8.0", 60.0, 40.0),
+        ];
+        entries[1].submission.preset = "Ultra".to_string();
+
+        let report = detect_regressions(&entries, DEFAULT_MIN_CHANGE_PCT);
+        assert_eq!(report.groups_compared, 0);
+        assert!(report.findings.is_empty());
+    }
+
+    #[test]
+    fn rejected_and_placeholder_runs_are_skipped() {
+        let mut entries = vec![
+            run(0, "24.1", "6.8.0", 100.0, 70.0),
+            run(1, "24.2", "6.8.0", 60.0, 40.0),
+            run(2, "24.2", "6.8.0", 60.0, 40.0),
+        ];
+        entries[1].status = HistoryStatus::Rejected;
+        entries[2].submission.game = "Unknown".to_string();
+
+        let report = detect_regressions(&entries, DEFAULT_MIN_CHANGE_PCT);
+        assert_eq!(report.groups_compared, 0);
+        assert!(report.findings.is_empty());
+    }
+
+    #[test]
+    fn local_only_runs_are_compared() {
+        let mut entries = vec![
+            run(0, "24.1", "6.8.0", 100.0, 70.0),
+            run(1, "24.2", "6.8.0", 60.0, 70.0),
+        ];
+        entries[1].status = HistoryStatus::NotSubmitted;
+
+        let report = detect_regressions(&entries, DEFAULT_MIN_CHANGE_PCT);
+        assert_eq!(report.groups_compared, 1);
+        assert_eq!(report.findings.len(), 1);
+    }
+}
@@ -0,0 +1,659 @@
+//! Self-contained HTML report for a capture
+//!
+//! Produces a single offline HTML file with inline CSS and SVG charts. It has
+//! no scripts, fonts or remote assets, so it can be attached to a bug thread
+//! and opened anywhere.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::benchmark::live::LiveCaptureResult;
+use crate::hardware::SystemInfo;
+use crate::import::{BenchmarkResult, FrameData, FrameTimeStats};
+
+/// Upper bound on plotted points; longer captures keep the slowest frame per bucket.
+const MAX_PLOT_POINTS: usize = 1500;
+const HISTOGRAM_BINS: usize = 40;
+const CHART_WIDTH: f64 = 820.0;
+const CHART_HEIGHT: f64 = 240.0;
+const MARGIN_LEFT: f64 = 52.0;
+const MARGIN_BOTTOM: f64 = 30.0;
+const MARGIN_TOP: f64 = 12.0;
+const MARGIN_RIGHT: f64 = 14.0;
+
+/// Capture quality diagnostics; live-only fields are `None` for imports.
+#[derive(Debug, Clone, Default)]
+pub struct ReportDiagnostics {
+    pub capture_quality_score: Option<u8>,
+    pub focus_pauses: Option<u32>,
+    pub samples_dropped_unfocused: Option<usize>,
+    pub dropped_sample_ratio: Option<f64>,
+    pub stutter_spike_count: usize,
+    pub stutter_spike_ratio: f64,
+    pub unstable_capture: Option<bool>,
+}
+
+/// Everything that goes into one HTML report.
+#[derive(Debug, Clone)]
+pub struct CaptureReport {
+    pub title: String,
+    pub source: String,
+    pub game: Option<String>,
+    pub generated_at: DateTime<Utc>,
+    /// Frame times (ms) in capture order
+    pub frame_times_ms: Vec<f64>,
+    /// Metric label and formatted value
+    pub metrics: Vec<(String, String)>,
+    pub diagnostics: ReportDiagnostics,
+    /// Free-form lines such as trim summaries and bottleneck verdicts
+    pub notes: Vec<String>,
+    pub system: Option<SystemInfo>,
+}
+
+impl CaptureReport {
+    /// Report for an imported capture.
+    pub fn from_frame_data(data: &FrameData, result: &BenchmarkResult) -> Self {
+        let mut notes = Vec::new();
+        if let Some(bottleneck) = &result.bottleneck {
+            notes.push(format!("Bottleneck: {}", bottleneck.overall));
+        }
+        if let Some(telemetry) = &result.telemetry {
+            notes.extend(telemetry.to_string().lines().map(str::to_string));
+        }
+
+        Self {
+            title: "Imported capture".to_string(),
+            source: result.source.clone(),
+            game: result.application.clone(),
+            generated_at: Utc::now(),
+            frame_times_ms: data.sanitized_frame_times(1000.0),
+            metrics: metric_rows(
+                result.avg_fps,
+                result.fps_1_low,
+                result.fps_01_low,
+                result.min_fps,
+                result.max_fps,
+                &result.frame_time_stats,
+                result.frame_count,
+                result.duration_secs,
+            ),
+            diagnostics: ReportDiagnostics {
+                stutter_spike_count: data.stutter_spike_count(1000.0),
+                stutter_spike_ratio: data.stutter_spike_ratio(1000.0),
+                ..ReportDiagnostics::default()
+            },
+            notes,
+            system: None,
+        }
+    }
+
+    /// Report for a live capture (single pass or multi-pass aggregate).
+    pub fn from_live_result(result: &LiveCaptureResult) -> Self {
+        let mut notes = Vec::new();
+        if let Some(trim) = result.trim.as_ref().filter(|trim| !trim.is_empty()) {
+            notes.push(trim.summary());
+        }
+        if let Some(bottleneck) = &result.bottleneck {
+            notes.push(format!("Bottleneck: {}", bottleneck.overall));
+        }
+        if let Some(telemetry) = &result.telemetry {
+            notes.extend(telemetry.to_string().lines().map(str::to_string));
+        }
+        if let Some(power) = &result.power {
+            notes.extend(power.to_string().lines().map(str::to_string));
+        }
+
+        Self {
+            title: "Live capture".to_string(),
+            source: result.source.clone(),
+            game: result.game_hint.clone(),
+            generated_at: Utc::now(),
+            frame_times_ms: result.frame_times_ms.clone(),
+            metrics: metric_rows(
+                result.avg_fps,
+                Some(result.fps_1_low),
+                result.fps_01_low,
+                result.min_fps,
+                result.max_fps,
+                &result.frame_time_stats,
+                result.frame_count,
+                result.duration_secs,
+            ),
+            diagnostics: ReportDiagnostics {
+                capture_quality_score: Some(result.capture_quality_score),
+                focus_pauses: Some(result.focus_pauses),
+                samples_dropped_unfocused: Some(result.samples_dropped_unfocused),
+                dropped_sample_ratio: Some(result.dropped_sample_ratio),
+                stutter_spike_count: result.stutter_spike_count,
+                stutter_spike_ratio: result.stutter_spike_ratio,
+                unstable_capture: Some(result.unstable_capture),
+            },
+            notes,
+            system: None,
+        }
+    }
+
+    /// Write the rendered report to `path`.
+    pub fn write_html(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.render_html())
+            .with_context(|| format!("Failed to write report to {}", path.display()))
+    }
+
+    /// Render the full HTML document.
+    pub fn render_html(&self) -> String {
+        let heading = match &self.game {
+            Some(game) => format!("{} — {}", self.title, game),
+            None => self.title.clone(),
+        };
+
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        let _ = writeln!(html, "<title>{}</title>", escape_html(&heading));
+        html.push_str(STYLE);
+        html.push_str("</head>\n<body>\n");
+        let _ = writeln!(html, "<h1>{}</h1>", escape_html(&heading));
+        let _ = writeln!(
+            html,
+            "<p class=\"meta\">Source: {} · Generated {} by fps-tracker {}</p>",
+            escape_html(&self.source),
+            self.generated_at.format("%Y-%m-%d %H:%M UTC"),
+            env!("CARGO_PKG_VERSION")
+        );
+
+        html.push_str("<div class=\"grid\">\n");
+        html.push_str(&table_section("Frame metrics", &self.metrics));
+        html.push_str(&table_section("Capture quality", &self.diagnostic_rows()));
+        if let Some(system) = &self.system {
+            html.push_str(&table_section("Hardware", &hardware_rows(system)));
+        }
+        html.push_str("</div>\n");
+
+        if !self.notes.is_empty() {
+            html.push_str("<section><h2>Notes</h2><ul>\n");
+            for note in &self.notes {
+                let _ = writeln!(html, "<li>{}</li>", escape_html(note));
+            }
+            html.push_str("</ul></section>\n");
+        }
+
+        let charts = [
+            (
+                "Frame time over capture",
+                frametime_plot(&self.frame_times_ms),
+            ),
+            ("Frame time histogram", histogram_plot(&self.frame_times_ms)),
+            (
+                "Frame time percentiles",
+                percentile_plot(&self.frame_times_ms),
+            ),
+        ];
+        for (title, svg) in charts {
+            let _ = writeln!(html, "<section><h2>{}</h2>\n{}</section>", title, svg);
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    fn diagnostic_rows(&self) -> Vec<(String, String)> {
+        let d = &self.diagnostics;
+        let live_only = "n/a (imported)".to_string();
+        vec![
+            (
+                "Quality score".to_string(),
+                d.capture_quality_score
+                    .map(|score| format!("{}/100", score))
+                    .unwrap_or_else(|| live_only.clone()),
+            ),
+            (
+                "Focus pauses".to_string(),
+                d.focus_pauses
+                    .map(|pauses| pauses.to_string())
+                    .unwrap_or_else(|| live_only.clone()),
+            ),
+            (
+                "Dropped samples".to_string(),
+                match (d.samples_dropped_unfocused, d.dropped_sample_ratio) {
+                    (Some(count), Some(ratio)) => format!("{} ({:.2}%)", count, ratio * 100.0),
+                    _ => live_only.clone(),
+                },
+            ),
+            (
+                "Stutter spikes".to_string(),
+                format!(
+                    "{} ({:.2}%)",
+                    d.stutter_spike_count,
+                    d.stutter_spike_ratio * 100.0
+                ),
+            ),
+            (
+                "Unstable capture".to_string(),
+                d.unstable_capture
+                    .map(|unstable| if unstable { "yes" } else { "no" }.to_string())
+                    .unwrap_or(live_only),
+            ),
+        ]
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn metric_rows(
+    avg_fps: f64,
+    fps_1_low: Option<f64>,
+    fps_01_low: Option<f64>,
+    min_fps: f64,
+    max_fps: f64,
+    pacing: &FrameTimeStats,
+    frame_count: usize,
+    duration_secs: f64,
+) -> Vec<(String, String)> {
+    let mut rows = vec![("Average FPS".to_string(), format!("{:.1}", avg_fps))];
+    if let Some(fps_1) = fps_1_low {
+        rows.push(("1% Low FPS".to_string(), format!("{:.1}", fps_1)));
+    }
+    if let Some(fps_01) = fps_01_low {
+        rows.push(("0.1% Low FPS".to_string(), format!("{:.1}", fps_01)));
+    }
+    if let Some(fps_1_avg) = pacing.fps_1_low_avg {
+        rows.push(("1% Low (avg)".to_string(), format!("{:.1}", fps_1_avg)));
+    }
+    rows.extend([
+        (
+            "Min / Max FPS".to_string(),
+            format!("{:.1} / {:.1}", min_fps, max_fps),
+        ),
+        (
+            "Median / P95 / P99".to_string(),
+            format!(
+                "{:.2} / {:.2} / {:.2} ms",
+                pacing.median_ms, pacing.p95_ms, pacing.p99_ms
+            ),
+        ),
+        ("Std dev".to_string(), format!("{:.2} ms", pacing.stddev_ms)),
+        (
+            "Jitter (avg / p99 / max)".to_string(),
+            format!(
+                "{:.2} / {:.2} / {:.2} ms",
+                pacing.jitter_avg_ms, pacing.jitter_p99_ms, pacing.jitter_max_ms
+            ),
+        ),
+        (
+            format!("Time below {:.0} FPS", pacing.threshold_fps),
+            format!("{:.1}%", pacing.time_below_threshold_pct),
+        ),
+        ("Frames".to_string(), frame_count.to_string()),
+        ("Duration".to_string(), format!("{:.1}s", duration_secs)),
+    ]);
+    rows
+}
+
+fn hardware_rows(system: &SystemInfo) -> Vec<(String, String)> {
+    let mut gpu = system.gpu.name.clone();
+    if let Some(vram) = system.gpu.vram_mb {
+        let _ = write!(gpu, " ({} MB VRAM)", vram);
+    }
+    let mut rows = vec![("GPU".to_string(), gpu)];
+    if let Some(driver) = &system.gpu.driver_version {
+        rows.push(("GPU driver".to_string(), driver.clone()));
+    }
+    rows.push((
+        "CPU".to_string(),
+        format!(
+            "{} ({} cores / {} threads)",
+            system.cpu.name, system.cpu.cores, system.cpu.threads
+        ),
+    ));
+    let mut ram = format!("{} MB usable", system.ram.usable_mb);
+    if let Some(ram_type) = &system.ram.ram_type {
+        let _ = write!(ram, ", {}", ram_type);
+    }
+    if let Some(speed) = system.ram.speed_mhz {
+        let _ = write!(ram, " @ {} MHz", speed);
+    }
+    rows.push(("RAM".to_string(), ram));
+    rows.push((
+        "OS".to_string(),
+        match &system.os_version {
+            Some(version) => format!("{} {}", system.os, version),
+            None => system.os.clone(),
+        },
+    ));
+    rows
+}
+
+fn table_section(title: &str, rows: &[(String, String)]) -> String {
+    let mut html = format!("<section><h2>{}</h2><table>\n", escape_html(title));
+    for (label, value) in rows {
+        let _ = writeln!(
+            html,
+            "<tr><th>{}</th><td>{}</td></tr>",
+            escape_html(label),
+            escape_html(value)
+        );
+    }
+    html.push_str("</table></section>\n");
+    html
+}
+
+/// Maps data coordinates into the plot area of a chart.
+struct ChartFrame {
+    x_max: f64,
+    y_max: f64,
+}
+
+impl ChartFrame {
+    fn x(&self, value: f64) -> f64 {
+        MARGIN_LEFT + value / self.x_max * (CHART_WIDTH - MARGIN_LEFT - MARGIN_RIGHT)
+    }
+
+    fn y(&self, value: f64) -> f64 {
+        let plot_height = CHART_HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+        MARGIN_TOP + plot_height - value.min(self.y_max) / self.y_max * plot_height
+    }
+
+    /// Open an `<svg>` and draw gridlines plus axis labels.
+    fn begin(&self, x_label: &str, y_label: &str) -> String {
+        let mut svg = format!(
+            "<svg viewBox=\"0 0 {w} {h}\" width=\"{w}\" height=\"{h}\" role=\"img\">\n",
+            w = CHART_WIDTH,
+            h = CHART_HEIGHT
+        );
+        let y_step = nice_step(self.y_max);
+        let mut tick = 0.0;
+        while tick <= self.y_max + 1e-9 {
+            let y = self.y(tick);
+            let _ = writeln!(
+                svg,
+                "<line class=\"gridline\" x1=\"{:.1}\" y1=\"{y:.1}\" x2=\"{:.1}\" y2=\"{y:.1}\"/><text class=\"tick\" x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+                MARGIN_LEFT,
+                CHART_WIDTH - MARGIN_RIGHT,
+                MARGIN_LEFT - 6.0,
+                y + 4.0,
+                format_tick(tick)
+            );
+            tick += y_step;
+        }
+        let x_step = nice_step(self.x_max);
+        let mut tick = 0.0;
+        while tick <= self.x_max + 1e-9 {
+            let _ = writeln!(
+                svg,
+                "<text class=\"tick\" x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                self.x(tick),
+                CHART_HEIGHT - MARGIN_BOTTOM + 16.0,
+                format_tick(tick)
+            );
+            tick += x_step;
+        }
+        let _ = writeln!(
+            svg,
+            "<text class=\"axis\" x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text><text class=\"axis\" x=\"4\" y=\"{:.1}\">{}</text>",
+            CHART_WIDTH - MARGIN_RIGHT,
+            CHART_HEIGHT - 2.0,
+            x_label,
+            MARGIN_TOP - 2.0,
+            y_label
+        );
+        svg
+    }
+}
+
+fn empty_chart() -> String {
+    "<p class=\"meta\">No frame data.</p>".to_string()
+}
+
+fn frametime_plot(frame_times_ms: &[f64]) -> String {
+    if frame_times_ms.is_empty() {
+        return empty_chart();
+    }
+    let mut points: Vec<(f64, f64)> = Vec::with_capacity(frame_times_ms.len());
+    let mut elapsed_ms = 0.0;
+    for ft in frame_times_ms {
+        elapsed_ms += ft;
+        points.push((elapsed_ms / 1000.0, *ft));
+    }
+    // Keep the slowest frame of each bucket so spikes survive downsampling.
+    let bucket = frame_times_ms.len().div_ceil(MAX_PLOT_POINTS);
+    let points: Vec<(f64, f64)> = points
+        .chunks(bucket)
+        .filter_map(|chunk| {
+            chunk
+                .iter()
+                .copied()
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        })
+        .collect();
+
+    let frame = ChartFrame {
+        x_max: (elapsed_ms / 1000.0).max(f64::EPSILON),
+        y_max: axis_max(frame_times_ms.iter().copied().fold(0.0, f64::max)),
+    };
+    let mut svg = frame.begin("seconds", "ms");
+    svg.push_str(&polyline(
+        points.iter().map(|(x, y)| (frame.x(*x), frame.y(*y))),
+    ));
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn histogram_plot(frame_times_ms: &[f64]) -> String {
+    if frame_times_ms.is_empty() {
+        return empty_chart();
+    }
+    let mut sorted = frame_times_ms.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    // Clip the long tail so a few spikes don't flatten the main distribution.
+    let upper = axis_max(sorted[((sorted.len() as f64 * 0.999) as usize).min(sorted.len() - 1)]);
+    let bin_width = upper / HISTOGRAM_BINS as f64;
+    let mut counts = [0usize; HISTOGRAM_BINS];
+    for ft in &sorted {
+        let bin = ((ft / bin_width) as usize).min(HISTOGRAM_BINS - 1);
+        counts[bin] += 1;
+    }
+    let max_share = counts.iter().copied().max().unwrap_or(0) as f64 / sorted.len() as f64 * 100.0;
+
+    let frame = ChartFrame {
+        x_max: upper,
+        y_max: axis_max(max_share),
+    };
+    let mut svg = frame.begin("ms", "% frames");
+    for (bin, count) in counts.iter().enumerate() {
+        if *count == 0 {
+            continue;
+        }
+        let share = *count as f64 / sorted.len() as f64 * 100.0;
+        let x0 = frame.x(bin as f64 * bin_width);
+        let x1 = frame.x((bin + 1) as f64 * bin_width);
+        let y = frame.y(share);
+        let _ = writeln!(
+            svg,
+            "<rect class=\"bar\" x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\"/>",
+            x0 + 0.5,
+            y,
+            (x1 - x0 - 1.0).max(0.5),
+            frame.y(0.0) - y
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn percentile_plot(frame_times_ms: &[f64]) -> String {
+    if frame_times_ms.is_empty() {
+        return empty_chart();
+    }
+    let mut sorted = frame_times_ms.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let at =
+        |pct: f64| sorted[((sorted.len() as f64 * pct / 100.0) as usize).min(sorted.len() - 1)];
+
+    let frame = ChartFrame {
+        x_max: 100.0,
+        y_max: axis_max(sorted[sorted.len() - 1]),
+    };
+    let mut svg = frame.begin("percentile", "ms");
+    svg.push_str(&polyline((0..=1000).map(|step| {
+        let pct = step as f64 / 10.0;
+        (frame.x(pct), frame.y(at(pct)))
+    })));
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn polyline(points: impl Iterator<Item = (f64, f64)>) -> String {
+    let mut coords = String::new();
+    for (x, y) in points {
+        let _ = write!(coords, "{:.1},{:.1} ", x, y);
+    }
+    format!(
+        "<polyline class=\"line\" points=\"{}\"/>\n",
+        coords.trim_end()
+    )
+}
+
+/// Round an axis maximum up to a whole number of gridline steps.
+fn axis_max(value: f64) -> f64 {
+    if !value.is_finite() || value <= 0.0 {
+        return 1.0;
+    }
+    let step = nice_step(value * 1.05);
+    (value * 1.05 / step).ceil() * step
+}
+
+/// A 1/2/5 x 10^n step giving roughly five gridlines over `range`.
+fn nice_step(range: f64) -> f64 {
+    if !range.is_finite() || range <= 0.0 {
+        return 1.0;
+    }
+    let raw = range / 5.0;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+fn format_tick(value: f64) -> String {
+    if (value - value.round()).abs() < 1e-9 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const STYLE: &str = r#"<style>
+body { font-family: system-ui, -apple-system, "Segoe UI", sans-serif; margin: 24px; color: #1d232a; background: #f7f8fa; }
+h1 { font-size: 22px; margin-bottom: 4px; }
+h2 { font-size: 15px; margin: 0 0 8px; }
+.meta { color: #5b6570; font-size: 13px; }
+.grid { display: flex; flex-wrap: wrap; gap: 16px; }
+section { background: #fff; border: 1px solid #dde1e6; border-radius: 8px; padding: 12px 16px; margin-bottom: 16px; }
+table { border-collapse: collapse; font-size: 13px; }
+th { text-align: left; font-weight: 500; color: #5b6570; padding: 2px 16px 2px 0; }
+td { font-variant-numeric: tabular-nums; padding: 2px 0; }
+svg { max-width: 100%; height: auto; }
+.gridline { stroke: #e6e9ed; stroke-width: 1; }
+.tick { font-size: 10px; fill: #5b6570; }
+.axis { font-size: 11px; fill: #1d232a; }
+.line { fill: none; stroke: #2563eb; stroke-width: 1.2; }
+.bar { fill: #2563eb; }
+</style>
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::FrameTelemetry;
+
+    fn sample_data() -> FrameData {
+        let mut frame_times_ms: Vec<f64> =
+            (0..3_000).map(|i| 16.0 + (i % 7) as f64 * 0.2).collect();
+        frame_times_ms[1_500] = 48.0;
+        FrameData {
+            duration_secs: frame_times_ms.iter().sum::<f64>() / 1000.0,
+            frame_times_ms,
+            application: Some("Tom & Jerry's <Game>".to_string()),
+            source: "MangoHud".to_string(),
+            telemetry: FrameTelemetry::default(),
+            sampled: false,
+        }
+    }
+
+    #[test]
+    fn report_is_self_contained_with_three_charts() {
+        let data = sample_data();
+        let result = data.calculate_stats().unwrap();
+        let html = CaptureReport::from_frame_data(&data, &result).render_html();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_eq!(html.matches("<svg ").count(), 3);
+        assert!(html.contains("<polyline"));
+        assert!(html.contains("<rect class=\"bar\""));
+        assert!(html.contains("Stutter spikes"));
+        assert!(html.contains("n/a (imported)"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("src=\"http"));
+        assert!(!html.contains("href=\"http"));
+    }
+
+    #[test]
+    fn report_escapes_user_supplied_text() {
+        let data = sample_data();
+        let result = data.calculate_stats().unwrap();
+        let html = CaptureReport::from_frame_data(&data, &result).render_html();
+
+        assert!(html.contains("Tom &amp; Jerry&#39;s &lt;Game&gt;"));
+        assert!(!html.contains("<Game>"));
+    }
+
+    #[test]
+    fn frametime_plot_keeps_spikes_when_downsampling() {
+        let data = sample_data();
+        let svg = frametime_plot(&data.frame_times_ms);
+        let points = svg
+            .split("points=\"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        assert!(points.split(' ').count() <= MAX_PLOT_POINTS);
+
+        let frame = ChartFrame {
+            x_max: 1.0,
+            y_max: axis_max(48.0),
+        };
+        let spike_y = format!(",{:.1}", frame.y(48.0));
+        assert!(points.contains(&spike_y));
+    }
+
+    #[test]
+    fn nice_steps_follow_one_two_five() {
+        assert!((nice_step(50.0) - 10.0).abs() < 1e-9);
+        assert!((nice_step(7.0) - 2.0).abs() < 1e-9);
+        assert!((nice_step(0.9) - 0.2).abs() < 1e-9);
+        assert!((axis_max(48.0) - 60.0).abs() < 1e-9);
+    }
+}
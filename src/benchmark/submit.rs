@@ -7,7 +7,12 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
+use super::refresh::RefreshPacing;
+use crate::hardware::display::DisplayModeSource;
+use crate::hardware::power::PowerSummary;
 use crate::hardware::SystemInfo;
+use crate::import::bottleneck::BottleneckBreakdown;
+use crate::import::TelemetrySummary;
 
 /// A benchmark submission to the backend API
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,6 +105,22 @@ pub struct BenchmarkSubmission {
     /// Optional extended synthetic metrics for audit/debug (tool-specific raw readings).
     #[serde(default)]
     pub synthetic_extended: Option<Value>,
+    /// Optional hardware telemetry summary (loads, temps, clocks, power, memory) from the
+    /// capture log. Only included when the user opts in.
+    #[serde(default)]
+    pub telemetry: Option<TelemetrySummary>,
+    /// CPU- vs GPU-bound verdict for the capture, when GPU utilisation was logged.
+    #[serde(default)]
+    pub bottleneck: Option<BottleneckBreakdown>,
+    /// Anonymised hardware fingerprint, so runs from the same hardware can be grouped.
+    #[serde(default)]
+    pub hardware_fingerprint: Option<String>,
+    /// Frame pacing against the primary display's refresh rate.
+    #[serde(default)]
+    pub refresh_pacing: Option<RefreshPacing>,
+    /// Battery, thermal and throttling state sampled during a live capture.
+    #[serde(default)]
+    pub power: Option<PowerSummary>,
     /// User notes (optional)
     pub notes: Option<String>,
 }
@@ -119,6 +140,7 @@ impl BenchmarkSubmission {
     ) -> Self {
         let normalized_resolution =
             normalize_resolution(&resolution).unwrap_or_else(|| resolution.trim().to_string());
+        let hardware_fingerprint = Some(system_info.hardware_fingerprint());
 
         BenchmarkSubmission {
             id: Uuid::new_v4(),
@@ -152,6 +174,11 @@ impl BenchmarkSubmission {
             synthetic_profile: None,
             synthetic_suite_version: None,
             synthetic_extended: None,
+            telemetry: None,
+            bottleneck: None,
+            hardware_fingerprint,
+            refresh_pacing: None,
+            power: None,
             notes: None,
         }
     }
@@ -165,6 +192,7 @@ impl BenchmarkSubmission {
         let avg_fps = session.average_fps()?;
         let normalized_resolution = normalize_resolution(&session.resolution)
             .unwrap_or_else(|| session.resolution.trim().to_string());
+        let hardware_fingerprint = Some(system_info.hardware_fingerprint());
 
         Some(BenchmarkSubmission {
             id: Uuid::new_v4(),
@@ -198,6 +226,11 @@ impl BenchmarkSubmission {
             synthetic_profile: None,
             synthetic_suite_version: None,
             synthetic_extended: None,
+            telemetry: None,
+            bottleneck: None,
+            hardware_fingerprint,
+            refresh_pacing: None,
+            power: None,
             notes: None,
         })
     }
@@ -300,6 +333,31 @@ impl BenchmarkSubmission {
             output.push_str(&format!("  Synthetic Profile: {}\n", profile));
         }
 
+        if let Some(bottleneck) = &self.bottleneck {
+            output.push_str(&format!("  Bottleneck: {}\n", bottleneck));
+        }
+
+        if let Some(pacing) = &self.refresh_pacing {
+            output.push_str(&format!("  Refresh Pacing: {}\n", pacing));
+        }
+
+        if let Some(power) = &self.power {
+            let warnings = power.warnings();
+            let state = if warnings.is_empty() {
+                "AC power, no throttling detected".to_string()
+            } else {
+                warnings.join("; ")
+            };
+            output.push_str(&format!("  Power / Thermals: {}\n", state));
+        }
+
+        if let Some(telemetry) = &self.telemetry {
+            output.push_str("  Telemetry:\n");
+            for line in telemetry.to_string().lines() {
+                output.push_str(&format!("    {}\n", line));
+            }
+        }
+
         if self.ray_tracing {
             output.push_str("  Ray Tracing: ON\n");
         }
@@ -308,9 +366,55 @@ impl BenchmarkSubmission {
             output.push_str(&format!("  Upscaling: {}\n", upscaling));
         }
 
+        if let Some(ref fingerprint) = self.hardware_fingerprint {
+            output.push_str(&format!("  Hardware Fingerprint: {}\n", fingerprint));
+        }
+
         output
     }
 
+    /// Compute refresh pacing from frame times against the primary display
+    pub fn set_refresh_pacing(&mut self, frame_times_ms: &[f64]) {
+        self.refresh_pacing =
+            RefreshPacing::for_display(frame_times_ms, self.system_info.primary_display());
+    }
+
+    /// Warn when the submitted resolution does not match the primary display.
+    ///
+    /// Not a validation error: games can run below the desktop resolution, and
+    /// DSR/VSR can render above it, but either is worth a second look. When
+    /// only the EDID was readable, the display's mode is its native one.
+    pub fn resolution_warning(&self) -> Option<String> {
+        let display = self.system_info.primary_display()?;
+        if display.width == 0 || display.height == 0 {
+            return None;
+        }
+        let (width, height) = resolution_dimensions(&self.resolution)?;
+        let reference = match display.mode_source {
+            DisplayModeSource::Active => "desktop",
+            DisplayModeSource::Preferred => "native",
+        };
+        let detected = display.resolution();
+        if (width, height) == (display.width, display.height) {
+            None
+        } else if width >= display.width && height >= display.height {
+            Some(format!(
+                "Resolution {} is higher than the detected {} resolution ({}). Check the in-game setting (DSR/VSR?).",
+                self.resolution, reference, detected
+            ))
+        } else if width <= display.width && height <= display.height {
+            Some(format!(
+                "Resolution {} is lower than the detected {} resolution ({}). Make sure it matches the in-game setting.",
+                self.resolution, reference, detected
+            ))
+        } else {
+            Some(format!(
+                "Resolution {} has a different aspect ratio than the detected {} resolution ({}). Make sure it matches the in-game setting.",
+                self.resolution, reference, detected
+            ))
+        }
+    }
+
     /// Validate the submission
     pub fn validate(&self) -> Result<(), Vec<String>> {
         let mut errors = Vec::new();
@@ -589,6 +693,30 @@ fn is_valid_synthetic_source(source: &str) -> bool {
         .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/'))
 }
 
+/// Vertical pixels for a normalized resolution ("1440p", "4K", "3440x1440")
+/// Width and height of a resolution. "1440p"-style names are the 16:9 (or,
+/// for 1200p/1600p, 16:10) modes `normalize_resolution` folds into them.
+fn resolution_dimensions(resolution: &str) -> Option<(u32, u32)> {
+    let normalized = normalize_resolution(resolution)?;
+    match normalized.as_str() {
+        "4K" => Some((3840, 2160)),
+        "5K" => Some((5120, 2880)),
+        "8K" => Some((7680, 4320)),
+        other => match other.split_once('x') {
+            Some((width, height)) => Some((width.parse().ok()?, height.parse().ok()?)),
+            None => {
+                let height: u32 = other.trim_end_matches('p').parse().ok()?;
+                let width = match height {
+                    1200 => 1920,
+                    1600 => 2560,
+                    _ => height * 16 / 9,
+                };
+                Some((width, height))
+            }
+        },
+    }
+}
+
 fn normalize_resolution(resolution: &str) -> Option<String> {
     let normalized = resolution.trim().to_ascii_lowercase().replace(' ', "");
     if normalized.is_empty() {
@@ -725,6 +853,7 @@ mod tests {
                 memory_clock_mhz: None,
                 temperature_c: None,
                 utilization_percent: None,
+                power_w: None,
             },
             cpu: CpuInfo {
                 name: "AMD Ryzen 7 7800X3D".to_string(),
@@ -734,6 +863,7 @@ mod tests {
                 vendor: "AMD".to_string(),
                 architecture: Some("x86_64".to_string()),
                 max_frequency_mhz: Some(5000),
+                topology: None,
             },
             ram: RamInfo {
                 installed_mb: Some(32768),
@@ -745,6 +875,13 @@ mod tests {
             },
             os: "Linux".to_string(),
             os_version: Some("6.8".to_string()),
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
         }
     }
 
@@ -794,6 +931,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resolution_warning_compares_with_primary_display() {
+        use crate::hardware::display::{DisplayInfo, DisplayModeSource};
+
+        let mut info = mock_system_info();
+        info.displays = vec![DisplayInfo {
+            connector: "DP-1".to_string(),
+            name: None,
+            width: 2560,
+            height: 1440,
+            refresh_hz: Some(165.0),
+            mode_source: DisplayModeSource::Active,
+            primary: true,
+            vrr_capable: Some(true),
+            vrr_range_hz: None,
+            hdr_capable: None,
+        }];
+
+        let submission = |resolution: &str| {
+            BenchmarkSubmission::new(
+                info.clone(),
+                "Cyberpunk 2077".to_string(),
+                resolution.to_string(),
+                "High".to_string(),
+                120.0,
+                Some(90.0),
+                false,
+                None,
+            )
+        };
+        assert!(submission("1440p").resolution_warning().is_none());
+        assert!(submission("4K")
+            .resolution_warning()
+            .is_some_and(|w| w.contains("higher")));
+        assert!(submission("1080p")
+            .resolution_warning()
+            .is_some_and(|w| w.contains("lower")));
+        assert!(
+            submission("3440x1440")
+                .resolution_warning()
+                .is_some_and(|w| w.contains("higher")),
+            "same height, wider"
+        );
+
+        let mut native = info.clone();
+        native.displays[0].mode_source = DisplayModeSource::Preferred;
+        native.displays[0].width = 3440;
+        let warning = BenchmarkSubmission::new(
+            native,
+            "Cyberpunk 2077".to_string(),
+            "1440p".to_string(),
+            "High".to_string(),
+            120.0,
+            Some(90.0),
+            false,
+            None,
+        )
+        .resolution_warning()
+        .unwrap();
+        assert!(warning.contains("lower than the detected native resolution (3440x1440)"));
+
+        let mut paced = submission("1440p");
+        paced.set_refresh_pacing(&[5.0, 5.0, 8.0, 5.0]);
+        let pacing = paced.refresh_pacing.unwrap();
+        assert_eq!(pacing.refresh_hz, 165.0);
+        assert!((pacing.frames_slower_than_refresh_pct - 25.0).abs() < 1e-9);
+    }
+
     #[test]
     fn rejects_placeholder_hardware_values() {
         let mut info = mock_system_info();
@@ -0,0 +1,273 @@
+//! Wait for the target game before a live capture starts
+//!
+//! Polls the process list (sysinfo) and the foreground window until one of the
+//! game's known process names is running and focused, so the timed capture
+//! does not begin on launcher or menu frames.
+
+use anyhow::Result;
+use std::ffi::OsStr;
+use std::io::Write;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+use crate::benchmark::focus;
+use crate::games::GameInfo;
+
+pub const MIN_WAIT_TIMEOUT_SECS: u64 = 10;
+pub const MAX_WAIT_TIMEOUT_SECS: u64 = 3600;
+pub const MAX_WARMUP_SECS: u64 = 300;
+
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Linux truncates `/proc/<pid>/comm` to 15 bytes.
+const LINUX_COMM_MAX_LEN: usize = 15;
+
+/// What `--wait-for-process` resolved to.
+#[derive(Debug, Clone)]
+pub struct WaitTarget {
+    /// Canonical game name when the argument matched the games database.
+    pub game_name: Option<&'static str>,
+    /// Process names that count as the game running.
+    pub candidates: Vec<String>,
+}
+
+impl WaitTarget {
+    /// Resolve a game name (via the games database) or a literal process name.
+    pub fn resolve(name: &str, explicit_process: Option<&str>) -> Result<Self> {
+        let trimmed = name.trim();
+        if trimmed.is_empty() {
+            anyhow::bail!("--wait-for-process requires a game or process name");
+        }
+
+        let mut candidates: Vec<String> = Vec::new();
+        if let Some(process) = explicit_process {
+            candidates.push(process.to_string());
+        }
+
+        let game = GameInfo::find(trimmed);
+        match game {
+            Some(info) => candidates.extend(
+                info.process_name_suggestions()
+                    .iter()
+                    .map(|name| name.to_string()),
+            ),
+            None => candidates.push(trimmed.to_string()),
+        }
+
+        let mut seen = Vec::new();
+        candidates.retain(|candidate| {
+            let key = focus::normalize_process_name(candidate);
+            if seen.contains(&key) {
+                false
+            } else {
+                seen.push(key);
+                true
+            }
+        });
+
+        if candidates.is_empty() {
+            anyhow::bail!(
+                "No known process names for '{}'. Pass --process-name explicitly.",
+                trimmed
+            );
+        }
+
+        Ok(Self {
+            game_name: game.map(|info| info.name.as_str()),
+            candidates,
+        })
+    }
+}
+
+pub fn validate_wait_options(timeout_secs: u64, warmup_secs: u64) -> Result<()> {
+    if !(MIN_WAIT_TIMEOUT_SECS..=MAX_WAIT_TIMEOUT_SECS).contains(&timeout_secs) {
+        anyhow::bail!(
+            "wait-timeout must be between {} and {} seconds",
+            MIN_WAIT_TIMEOUT_SECS,
+            MAX_WAIT_TIMEOUT_SECS
+        );
+    }
+    if warmup_secs > MAX_WARMUP_SECS {
+        anyhow::bail!("warmup must be <= {} seconds", MAX_WARMUP_SECS);
+    }
+    Ok(())
+}
+
+/// Block until a candidate process is running and focused. Returns the
+/// candidate that matched.
+///
+/// When the platform cannot report the foreground process, a running match is
+/// accepted on its own.
+pub fn wait_for_target_process(target: &WaitTarget, timeout_secs: u64) -> Result<String> {
+    let started = Instant::now();
+    let timeout = Duration::from_secs(timeout_secs);
+    let mut system = System::new();
+
+    println!(
+        "Waiting for {} to start and take focus (timeout {}s)...",
+        target.candidates.join(" / "),
+        timeout_secs
+    );
+
+    loop {
+        // Only names, exe paths and command lines (Proton titles) are matched;
+        // none of them change while a process runs.
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing()
+                .with_exe(UpdateKind::OnlyIfNotSet)
+                .with_cmd(UpdateKind::OnlyIfNotSet),
+        );
+        let running = system.processes().values().find_map(|process| {
+            let cmd_name = process
+                .cmd()
+                .first()
+                .map(|arg| executable_file_name(arg).to_string());
+            let exe_name = process
+                .exe()
+                .and_then(Path::file_name)
+                .map(|name| name.to_string_lossy().into_owned());
+            let names = [
+                Some(process.name().to_string_lossy().into_owned()),
+                exe_name,
+                cmd_name,
+            ];
+            target.candidates.iter().find(|candidate| {
+                names
+                    .iter()
+                    .flatten()
+                    .any(|name| process_name_matches_candidate(name, candidate))
+            })
+        });
+
+        let status = match running {
+            Some(candidate) => match focus::foreground_process_name() {
+                Some(active) if process_name_matches_candidate(&active, candidate) => {
+                    println!();
+                    println!("Detected {} in the foreground.", candidate);
+                    return Ok(candidate.clone());
+                }
+                Some(_) => "running, waiting for focus",
+                None => {
+                    println!();
+                    println!(
+                        "Detected {} running (foreground detection unavailable).",
+                        candidate
+                    );
+                    return Ok(candidate.clone());
+                }
+            },
+            None => "not running yet",
+        };
+
+        let elapsed = started.elapsed();
+        if elapsed >= timeout {
+            println!();
+            anyhow::bail!(
+                "Timed out after {}s waiting for {} ({}).",
+                timeout_secs,
+                target.candidates.join(" / "),
+                status
+            );
+        }
+
+        print!(
+            "\rWaiting... {:>4}s | game {:<28}",
+            elapsed.as_secs(),
+            status
+        );
+        let _ = std::io::stdout().flush();
+        thread::sleep(WAIT_POLL_INTERVAL);
+    }
+}
+
+/// Sleep through the warmup window. Live sources only read samples produced
+/// after capture starts, so frames rendered here are discarded.
+pub fn run_warmup(warmup_secs: u64) {
+    if warmup_secs == 0 {
+        return;
+    }
+    for remaining in (1..=warmup_secs).rev() {
+        print!("\rWarmup: discarding frames, {:>3}s remaining", remaining);
+        let _ = std::io::stdout().flush();
+        thread::sleep(Duration::from_secs(1));
+    }
+    println!();
+}
+
+/// File name of a command-line executable argument, handling both `/` and
+/// Windows-style `\` separators (Proton/Wine paths).
+fn executable_file_name(arg: &OsStr) -> &str {
+    let value = arg.to_str().unwrap_or_default();
+    value.rsplit(['/', '\\']).next().unwrap_or(value)
+}
+
+/// Match a reported process name against a candidate, tolerating Linux's
+/// 15-byte `comm` truncation.
+fn process_name_matches_candidate(reported: &str, candidate: &str) -> bool {
+    if focus::process_name_matches(reported, candidate) {
+        return true;
+    }
+
+    let reported = reported.trim();
+    reported.len() == LINUX_COMM_MAX_LEN
+        && candidate
+            .trim()
+            .to_ascii_lowercase()
+            .starts_with(&reported.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        executable_file_name, process_name_matches_candidate, validate_wait_options, WaitTarget,
+    };
+    use std::ffi::OsStr;
+
+    #[test]
+    fn resolves_game_name_to_known_process_names() {
+        let target = WaitTarget::resolve("Cyberpunk 2077", None).unwrap();
+        assert_eq!(target.game_name, Some("Cyberpunk 2077"));
+        assert!(target
+            .candidates
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case("Cyberpunk2077.exe")));
+    }
+
+    #[test]
+    fn unknown_name_is_used_as_process_name() {
+        let target = WaitTarget::resolve("mygame.exe", Some("MyGame.exe")).unwrap();
+        assert!(target.game_name.is_none());
+        assert_eq!(target.candidates, vec!["MyGame.exe".to_string()]);
+    }
+
+    #[test]
+    fn matches_truncated_linux_comm_names() {
+        assert!(process_name_matches_candidate(
+            "Cyberpunk2077.e",
+            "Cyberpunk2077.exe"
+        ));
+        assert!(process_name_matches_candidate("cs2", "cs2.exe"));
+        assert!(!process_name_matches_candidate("steam", "cs2.exe"));
+    }
+
+    #[test]
+    fn extracts_executable_from_wine_style_paths() {
+        assert_eq!(
+            executable_file_name(OsStr::new(
+                "Z:\\games\\Cyberpunk 2077\\bin\\Cyberpunk2077.exe"
+            )),
+            "Cyberpunk2077.exe"
+        );
+        assert_eq!(executable_file_name(OsStr::new("/usr/bin/cs2")), "cs2");
+    }
+
+    #[test]
+    fn validates_wait_bounds() {
+        assert!(validate_wait_options(5, 0).is_err());
+        assert!(validate_wait_options(600, 301).is_err());
+        assert!(validate_wait_options(600, 15).is_ok());
+    }
+}
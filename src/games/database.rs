@@ -2,11 +2,24 @@
 //!
 //! This database helps users understand which games are good for benchmarking
 //! and provides consistent benchmark settings recommendations.
+//!
+//! The catalogue ships as `catalogue.toml` and can be extended or overridden
+//! per game through a `games.toml` file next to the user config.
 
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::config::Config;
+
+const BUNDLED_CATALOGUE: &str = include_str!("catalogue.toml");
+const OVERRIDES_FILE: &str = "games.toml";
 
 /// How demanding is the game on GPU
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum GameDifficulty {
     /// Lightweight games (e.g., CS2, Valorant, LoL)
     Light,
@@ -29,652 +42,251 @@ impl std::fmt::Display for GameDifficulty {
     }
 }
 
+/// Upscaling technology a game ships with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Upscaler {
+    Dlss,
+    Fsr,
+    Xess,
+}
+
+impl std::fmt::Display for Upscaler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Upscaler::Dlss => write!(f, "DLSS"),
+            Upscaler::Fsr => write!(f, "FSR"),
+            Upscaler::Xess => write!(f, "XeSS"),
+        }
+    }
+}
+
+/// How likely a game's anti-cheat is to object to third-party capture tools
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AntiCheatRisk {
+    Low,
+    Medium,
+    High,
+}
+
+impl std::fmt::Display for AntiCheatRisk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AntiCheatRisk::Low => write!(f, "Low"),
+            AntiCheatRisk::Medium => write!(f, "Medium"),
+            AntiCheatRisk::High => write!(f, "High"),
+        }
+    }
+}
+
 /// Information about a known game
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct GameInfo {
     /// Game name (canonical form)
-    pub name: &'static str,
+    pub name: String,
     /// Alternative names/abbreviations
-    pub aliases: &'static [&'static str],
+    #[serde(default)]
+    pub aliases: Vec<String>,
     /// GPU difficulty rating
     pub difficulty: GameDifficulty,
     /// Has built-in benchmark
+    #[serde(default)]
     pub has_benchmark: bool,
     /// Supports ray tracing
+    #[serde(default)]
     pub supports_rt: bool,
-    /// Supports DLSS
-    pub supports_dlss: bool,
-    /// Supports FSR
-    pub supports_fsr: bool,
+    /// Supported upscalers
+    #[serde(default)]
+    pub upscalers: Vec<Upscaler>,
+    /// Upscalers whose frame generation the game also exposes
+    #[serde(default)]
+    pub frame_generation: Vec<Upscaler>,
+    /// Anti-cheat risk for external capture (required: there is no safe default)
+    pub anti_cheat: AntiCheatRisk,
+    /// Executable/process names, the first one is the default capture hint
+    #[serde(default)]
+    pub process_names: Vec<String>,
     /// Recommended benchmark location/method
-    pub benchmark_notes: &'static str,
+    #[serde(default)]
+    pub benchmark_notes: String,
+}
+
+/// Games catalogue: the bundled entries plus any user overrides
+#[derive(Debug, Clone, Default)]
+pub struct GameCatalogue {
+    games: Vec<GameInfo>,
+    /// Problems worth telling the user about that did not stop loading
+    warnings: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CatalogueFile {
+    #[serde(default, rename = "game")]
+    games: Vec<GameInfo>,
+}
+
+impl GameCatalogue {
+    /// Catalogue compiled into the binary
+    pub fn bundled() -> Result<Self> {
+        Self::parse(BUNDLED_CATALOGUE).context("Bundled games catalogue is invalid")
+    }
+
+    /// Parse and validate a catalogue in TOML form
+    pub fn parse(contents: &str) -> Result<Self> {
+        let file: CatalogueFile = toml::from_str(contents)?;
+        let catalogue = Self {
+            games: file.games,
+            warnings: Vec::new(),
+        };
+        catalogue.validate()?;
+        Ok(catalogue)
+    }
+
+    /// Apply user overrides: an entry whose name or an alias matches a known
+    /// game's name or alias replaces it, anything else is appended. Lowering a
+    /// game's anti-cheat risk is allowed but recorded in
+    /// [`GameCatalogue::warnings`].
+    pub fn with_overrides(mut self, overrides: GameCatalogue) -> Result<Self> {
+        for game in overrides.games {
+            let keys: Vec<String> = game.match_keys().collect();
+            match self
+                .games
+                .iter_mut()
+                .find(|g| g.match_keys().any(|key| keys.contains(&key)))
+            {
+                Some(existing) => {
+                    if game.anti_cheat < existing.anti_cheat {
+                        self.warnings.push(format!(
+                            "games.toml lowers the anti-cheat risk of {} from {} to {}",
+                            existing.name, existing.anti_cheat, game.anti_cheat
+                        ));
+                    }
+                    *existing = game;
+                }
+                None => self.games.push(game),
+            }
+        }
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Location of the user overrides file
+    pub fn overrides_path() -> Result<PathBuf> {
+        Ok(Config::config_path()?.with_file_name(OVERRIDES_FILE))
+    }
+
+    /// Bundled catalogue merged with the user overrides file, if present
+    pub fn load() -> Result<Self> {
+        let bundled = Self::bundled()?;
+        let path = Self::overrides_path()?;
+        if !path.exists() {
+            return Ok(bundled);
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read games overrides at {}", path.display()))?;
+        let overrides = Self::parse(&contents)
+            .with_context(|| format!("Invalid games overrides at {}", path.display()))?;
+        bundled.with_overrides(overrides)
+    }
+
+    pub fn games(&self) -> &[GameInfo] {
+        &self.games
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    fn validate(&self) -> Result<()> {
+        let mut keys: HashMap<String, &str> = HashMap::new();
+        for game in &self.games {
+            if game.name.trim().is_empty() {
+                bail!("Game entry is missing a name");
+            }
+            if game.aliases.iter().any(|a| a.trim().is_empty()) {
+                bail!("{}: aliases must not be empty", game.name);
+            }
+            if game.process_names.is_empty() {
+                bail!("{}: at least one process name is required", game.name);
+            }
+            for process in &game.process_names {
+                if process.trim().is_empty() || process.contains(['/', '\\']) {
+                    bail!(
+                        "{}: process name '{}' must be a bare executable name",
+                        game.name,
+                        process
+                    );
+                }
+            }
+            if let Some(fg) = game
+                .frame_generation
+                .iter()
+                .find(|fg| !game.upscalers.contains(fg))
+            {
+                bail!(
+                    "{}: {} frame generation requires {} in upscalers",
+                    game.name,
+                    fg,
+                    fg
+                );
+            }
+
+            for key in std::iter::once(&game.name).chain(&game.aliases) {
+                let normalized = normalize_match_key(key);
+                if normalized.is_empty() {
+                    bail!("{}: '{}' has no letters or digits", game.name, key);
+                }
+                match keys.get(&normalized) {
+                    Some(owner) if *owner != game.name => {
+                        bail!("'{}' is used by both {} and {}", key, owner, game.name);
+                    }
+                    Some(_) => {}
+                    None => {
+                        keys.insert(normalized, &game.name);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
-/// Database of known games with benchmark difficulty ratings
-pub static KNOWN_GAMES: &[GameInfo] = &[
-    // ============ EXTREME (90+ percentile GPU load) ============
-    GameInfo {
-        name: "Cyberpunk 2077",
-        aliases: &["CP2077", "Cyberpunk"],
-        difficulty: GameDifficulty::Extreme,
-        has_benchmark: true,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use built-in benchmark. RT Overdrive mode for extreme testing.",
-    },
-    GameInfo {
-        name: "Avatar: Frontiers of Pandora",
-        aliases: &["Avatar", "AFOP"],
-        difficulty: GameDifficulty::Extreme,
-        has_benchmark: true,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use built-in benchmark.",
-    },
-    GameInfo {
-        name: "Alan Wake 2",
-        aliases: &["AW2"],
-        difficulty: GameDifficulty::Extreme,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Test in Bright Falls town area for consistent load.",
-    },
-    GameInfo {
-        name: "Hogwarts Legacy",
-        aliases: &["Hogwarts"],
-        difficulty: GameDifficulty::Extreme,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes:
-            "No built-in benchmark. Use a repeatable route in Hogsmeade or Hogwarts. Keep time of day and weather consistent.",
-    },
-    GameInfo {
-        name: "Black Myth: Wukong",
-        aliases: &["Wukong", "BMW"],
-        difficulty: GameDifficulty::Extreme,
-        has_benchmark: true,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use built-in benchmark.",
-    },
-
-    // ============ HEAVY (75-90 percentile GPU load) ============
-    GameInfo {
-        name: "Red Dead Redemption 2",
-        aliases: &["RDR2", "Red Dead 2"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: true,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use built-in benchmark (all 5 scenes).",
-    },
-    GameInfo {
-        name: "The Witcher 3",
-        aliases: &["Witcher 3", "TW3"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Test in Novigrad city for high NPC density.",
-    },
-    GameInfo {
-        name: "Microsoft Flight Simulator 2024",
-        aliases: &["MSFS", "Flight Sim", "MSFS2024"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Test landing at major airports (JFK, LHR).",
-    },
-    GameInfo {
-        name: "Starfield",
-        aliases: &["SF"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Test in New Atlantis city for consistent load.",
-    },
-    GameInfo {
-        name: "Dying Light 2",
-        aliases: &["DL2"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: true,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use built-in benchmark.",
-    },
-    GameInfo {
-        name: "Horizon Zero Dawn",
-        aliases: &["HZD", "Horizon"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: true,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use built-in benchmark.",
-    },
-    GameInfo {
-        name: "Horizon Forbidden West",
-        aliases: &["HFW"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: true,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use built-in benchmark.",
-    },
-    GameInfo {
-        name: "HELLDIVERS 2",
-        aliases: &["Helldivers 2", "HD2"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use the same mission and land in the same biome. Avoid major patch days.",
-    },
-    GameInfo {
-        name: "ARC Raiders",
-        aliases: &["ARC"],
-        difficulty: GameDifficulty::Heavy,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use the same map/route for 2-3 minutes and record a repeatable combat segment.",
-    },
-
-    // ============ MEDIUM (50-75 percentile GPU load) ============
-    GameInfo {
-        name: "Fortnite",
-        aliases: &["FN"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Land at Tilted and fight for 2 minutes.",
-    },
-    GameInfo {
-        name: "Apex Legends",
-        aliases: &["Apex"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Drop hot and test during first fight.",
-    },
-    GameInfo {
-        name: "Call of Duty: Warzone",
-        aliases: &["Warzone", "WZ", "COD Warzone"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes:
-            "No built-in benchmark. Use the same mode/map and record a repeatable 60-120s segment. Note: RT support may be limited to specific modes/areas (often lobby).",
-    },
-    GameInfo {
-        name: "Elden Ring",
-        aliases: &["ER"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Test in Limgrave open world.",
-    },
-    GameInfo {
-        name: "Monster Hunter Wilds",
-        aliases: &["MHW", "MH Wilds"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes:
-            "No built-in benchmark. Test a repeatable 60-120s combat segment (same locale, similar camera distance).",
-    },
-    GameInfo {
-        name: "Baldur's Gate 3",
-        aliases: &["BG3"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Test in Act 3 city areas.",
-    },
-    GameInfo {
-        name: "PUBG: BATTLEGROUNDS",
-        aliases: &["PUBG"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use training mode or a repeatable drop + short route to reduce variance.",
-    },
-    GameInfo {
-        name: "Rust",
-        aliases: &["RUST"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a consistent server/location. Avoid busy hubs when measuring.",
-    },
-    GameInfo {
-        name: "Tom Clancy's Rainbow Six Siege",
-        aliases: &[
-            "Rainbow Six Siege",
-            "Siege X",
-            "Tom Clancy's Rainbow Six Siege X",
-            "R6 Siege",
-            "R6",
-            "Siege",
-        ],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: true,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes:
-            "Use the built-in benchmark for consistent results. For DLSS, use the Vulkan renderer if available.",
-    },
-    GameInfo {
-        name: "Grand Theft Auto V",
-        aliases: &["GTA V", "GTA5", "Grand Theft Auto V Legacy"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: true,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use the built-in benchmark (all scenes).",
-    },
-    GameInfo {
-        name: "Grand Theft Auto V Enhanced",
-        aliases: &["GTA V Enhanced", "GTA5 Enhanced", "Grand Theft Auto V Enhanced Edition"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: true,
-        supports_rt: true,
-        supports_dlss: false,
-        supports_fsr: true,
-        benchmark_notes:
-            "Use the built-in benchmark (all scenes). If using RT presets, note them explicitly (RT can materially change GPU load).",
-    },
-    GameInfo {
-        name: "Warframe",
-        aliases: &["WF"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes:
-            "No built-in benchmark. Use the same hub/mission type and measure a repeatable 60-120s segment.",
-    },
-    GameInfo {
-        name: "War Thunder",
-        aliases: &["WT"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a custom battle/training scenario for repeatable load.",
-    },
-    GameInfo {
-        name: "DayZ",
-        aliases: &["Day Z"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use the same town/route. Avoid player-heavy hotspots when measuring.",
-    },
-    GameInfo {
-        name: "Palworld",
-        aliases: &["PW"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a fixed route around your base and a short combat segment.",
-    },
-    GameInfo {
-        name: "Destiny 2",
-        aliases: &["D2"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a consistent destination/activity. Measure a repeatable 60-120s segment.",
-    },
-    GameInfo {
-        name: "Marvel Rivals",
-        aliases: &["Rivals"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use the practice range or a repeatable match segment for 60-120s.",
-    },
-    GameInfo {
-        name: "Delta Force",
-        aliases: &["Delta Force: Hawk Ops"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a repeatable training range or match segment for 60-120s.",
-    },
-    GameInfo {
-        name: "Project Zomboid",
-        aliases: &["PZ"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a dense urban area with similar zoom level and population settings.",
-    },
-    GameInfo {
-        name: "Dead by Daylight",
-        aliases: &["DBD"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a private match and a consistent chase/route for 60-120s.",
-    },
-    GameInfo {
-        name: "VRChat",
-        aliases: &["VRC"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use the same world instance and stay in a similar crowd density area.",
-    },
-    GameInfo {
-        name: "The Finals",
-        aliases: &["THE FINALS", "Finals"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use the practice range or a consistent match segment for 60-120s.",
-    },
-    GameInfo {
-        name: "Sid Meier's Civilization VI",
-        aliases: &["Civ VI", "Civ 6", "Civilization VI"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: true,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use the built-in benchmark (AI / graphics).",
-    },
-    GameInfo {
-        name: "EA SPORTS FC 26",
-        aliases: &["FC 26", "FIFA", "EA FC 26"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a consistent stadium/camera view. Record a 60-120s match segment.",
-    },
-    GameInfo {
-        name: "Valheim",
-        aliases: &["VH"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a consistent base location with similar weather/time of day.",
-    },
-    GameInfo {
-        name: "No Man's Sky",
-        aliases: &["NMS"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: true,
-        supports_fsr: true,
-        benchmark_notes: "Use the same planet/settlement area. Record a repeatable 60-120s route.",
-    },
-    GameInfo {
-        name: "Satisfactory",
-        aliases: &["SATISFACTORY"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a fixed route through your factory and record 60-120s.",
-    },
-    GameInfo {
-        name: "7 Days to Die",
-        aliases: &["7DTD"],
-        difficulty: GameDifficulty::Medium,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a dense POI and a repeatable 60-120s route.",
-    },
-
-    // ============ LIGHT (< 50 percentile GPU load) ============
-    GameInfo {
-        name: "Counter-Strike 2",
-        aliases: &["CS2", "CS", "Counter-Strike"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: true,
-        benchmark_notes: "Test on de_dust2 with bots.",
-    },
-    GameInfo {
-        name: "Valorant",
-        aliases: &["Val"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Test in deathmatch mode.",
-    },
-    GameInfo {
-        name: "League of Legends",
-        aliases: &["LoL", "League"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Test during 5v5 teamfight.",
-    },
-    GameInfo {
-        name: "Dota 2",
-        aliases: &["Dota"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes:
-            "Use a repeatable replay/teamfight segment (same camera path). Avoid live matches to reduce variance.",
-    },
-    GameInfo {
-        name: "Minecraft Java",
-        aliases: &["MC Java", "Minecraft"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Java Edition: Test in a world with many chunks loaded. Use shader packs like SEUS PTGI for heavy GPU load.",
-    },
-    GameInfo {
-        name: "Minecraft Bedrock (RTX)",
-        aliases: &["MC Bedrock", "Minecraft RTX"],
-        difficulty: GameDifficulty::Extreme,
-        has_benchmark: false,
-        supports_rt: true,
-        supports_dlss: true,
-        supports_fsr: false,
-        benchmark_notes:
-            "Bedrock RTX: Use a repeatable RTX world/scene (fixed camera path). Requires RT-capable GPU and RTX resource packs/worlds.",
-    },
-    GameInfo {
-        name: "Overwatch 2",
-        aliases: &["OW2", "Overwatch"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: true,
-        benchmark_notes: "Test in quick play match.",
-    },
-    GameInfo {
-        name: "Team Fortress 2",
-        aliases: &["TF2"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Test on a consistent map with bots or a 60-120s repeatable route.",
-    },
-    GameInfo {
-        name: "Terraria",
-        aliases: &["TERRARIA"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a busy base area or repeatable boss fight segment.",
-    },
-    GameInfo {
-        name: "Stardew Valley",
-        aliases: &["SDV", "Stardew"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a consistent in-game day segment (same area and time of day).",
-    },
-    GameInfo {
-        name: "Geometry Dash",
-        aliases: &["GD"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use the same level segment for 60-120s capture.",
-    },
-    GameInfo {
-        name: "Rocket League",
-        aliases: &["RL"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use training/free play for a repeatable 60-120s segment.",
-    },
-    GameInfo {
-        name: "Left 4 Dead 2",
-        aliases: &["L4D2"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Run a consistent campaign segment with the same settings and bots.",
-    },
-    GameInfo {
-        name: "Hearts of Iron IV",
-        aliases: &["HOI4", "HOI IV"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a late-game save or a busy scenario. Record 60-120s at the same zoom.",
-    },
-    GameInfo {
-        name: "Garry's Mod",
-        aliases: &["GMod", "Garrys Mod"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a consistent map and spawn setup. Record a repeatable 60-120s segment.",
-    },
-    GameInfo {
-        name: "RimWorld",
-        aliases: &["RW"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a late-game colony and consistent zoom. Record 60-120s.",
-    },
-    GameInfo {
-        name: "Factorio",
-        aliases: &["FACT"],
-        difficulty: GameDifficulty::Light,
-        has_benchmark: false,
-        supports_rt: false,
-        supports_dlss: false,
-        supports_fsr: false,
-        benchmark_notes: "Use a large save and a consistent camera position. Record 60-120s.",
-    },
-];
+static CATALOGUE: OnceLock<GameCatalogue> = OnceLock::new();
+
+/// Install the catalogue [`known_games`] serves. `main` loads it once at
+/// startup and reports problems there; later calls are ignored.
+pub fn set_catalogue(catalogue: GameCatalogue) {
+    let _ = CATALOGUE.set(catalogue);
+}
+
+/// Known games: the catalogue installed with [`set_catalogue`], or the
+/// bundled one if none was installed
+pub fn known_games() -> &'static [GameInfo] {
+    CATALOGUE
+        .get_or_init(|| GameCatalogue::bundled().unwrap_or_default())
+        .games()
+}
 
 impl GameInfo {
+    /// Normalised name and aliases
+    fn match_keys(&self) -> impl Iterator<Item = String> + '_ {
+        std::iter::once(&self.name)
+            .chain(&self.aliases)
+            .map(|key| normalize_match_key(key))
+    }
+
     /// Find a game by name or alias (case-insensitive)
     pub fn find(query: &str) -> Option<&'static GameInfo> {
         let query_lower = query.to_lowercase();
         let query_normalized = normalize_match_key(query);
-        KNOWN_GAMES.iter().find(|g| {
+        known_games().iter().find(|g| {
             g.name.to_lowercase() == query_lower
                 || g.aliases.iter().any(|a| a.to_lowercase() == query_lower)
-                || normalize_match_key(g.name) == query_normalized
+                || normalize_match_key(&g.name) == query_normalized
                 || g.aliases
                     .iter()
                     .any(|a| normalize_match_key(a) == query_normalized)
@@ -684,7 +296,7 @@ impl GameInfo {
     /// Get games by difficulty
     #[allow(dead_code)]
     pub fn by_difficulty(difficulty: GameDifficulty) -> Vec<&'static GameInfo> {
-        KNOWN_GAMES
+        known_games()
             .iter()
             .filter(|g| g.difficulty == difficulty)
             .collect()
@@ -692,89 +304,12 @@ impl GameInfo {
 
     /// Suggested executable/process names for safer external capture targeting.
     /// The first entry is the default hint used for auto-selection.
-    pub fn process_name_suggestions(&self) -> &'static [&'static str] {
-        match self.name {
-            "Cyberpunk 2077" => &["Cyberpunk2077.exe", "Cyberpunk2077", "Cyberpunk2077GOG.exe"],
-            "Avatar: Frontiers of Pandora" => &["AFOP.exe", "AvatarFrontiersOfPandora.exe", "AFOP"],
-            "Alan Wake 2" => &["AlanWake2.exe", "AlanWake2"],
-            "Hogwarts Legacy" => &["HogwartsLegacy.exe", "HogwartsLegacy"],
-            "Black Myth: Wukong" => &["BlackMythWukong.exe", "b1.exe", "BlackMythWukong"],
-            "Red Dead Redemption 2" => &["RDR2.exe", "RDR2"],
-            "The Witcher 3" => &["witcher3.exe", "witcher3", "witcher3_x64.exe"],
-            "Microsoft Flight Simulator 2024" => &[
-                "FlightSimulator.exe",
-                "FlightSimulator",
-                "Microsoft Flight Simulator",
-            ],
-            "Starfield" => &["Starfield.exe", "Starfield"],
-            "Dying Light 2" => &[
-                "DyingLightGame_x64_rwdi.exe",
-                "DyingLightGame_x64_rwdi",
-                "DyingLight2",
-            ],
-            "Horizon Zero Dawn" => &["HorizonZeroDawn.exe", "HorizonZeroDawn"],
-            "Horizon Forbidden West" => &["HorizonForbiddenWest.exe", "HorizonForbiddenWest"],
-            "Fortnite" => &[
-                "FortniteClient-Win64-Shipping.exe",
-                "FortniteClient-Win64-Shipping",
-                "FortniteLauncher.exe",
-            ],
-            "Apex Legends" => &["r5apex.exe", "r5apex"],
-            "Call of Duty: Warzone" => &["cod.exe", "ModernWarfare.exe", "cod", "iw8"],
-            "Elden Ring" => &["eldenring.exe", "eldenring"],
-            "Monster Hunter Wilds" => &["MonsterHunterWilds.exe", "MonsterHunterWilds"],
-            "Baldur's Gate 3" => &["bg3.exe", "bg3_dx11.exe", "bg3", "bg3_dx11"],
-            "Counter-Strike 2" => &["cs2.exe", "cs2", "hl2_linux"],
-            "Valorant" => &[
-                "VALORANT-Win64-Shipping.exe",
-                "VALORANT-Win64-Shipping",
-                "VALORANT",
-            ],
-            "League of Legends" => &[
-                "League of Legends.exe",
-                "LeagueClient.exe",
-                "LeagueofLegends",
-            ],
-            "Dota 2" => &["dota2.exe", "dota2", "dota2_linux"],
-            "Minecraft Java" => &["javaw.exe", "java.exe", "javaw", "java"],
-            "Minecraft Bedrock (RTX)" => &["Minecraft.Windows.exe", "Minecraft.Windows"],
-            "Overwatch 2" => &["Overwatch.exe", "Overwatch", "Overwatch2"],
-            "HELLDIVERS 2" => &["helldivers2.exe", "helldivers2"],
-            "ARC Raiders" => &["ARCRaiders.exe", "ARCRaiders", "arcraiders.exe"],
-            "PUBG: BATTLEGROUNDS" => &["TslGame.exe", "TslGame"],
-            "Rust" => &["RustClient.exe", "RustClient", "RustClient.exe"],
-            "Tom Clancy's Rainbow Six Siege" => &["RainbowSix.exe", "RainbowSix"],
-            "Grand Theft Auto V" => &["GTA5.exe", "GTA5"],
-            "Grand Theft Auto V Enhanced" => &["GTA5.exe", "GTA5"],
-            "Warframe" => &["Warframe.x64.exe", "Warframe.exe", "Warframe"],
-            "War Thunder" => &["aces.exe", "aces"],
-            "DayZ" => &["DayZ_x64.exe", "DayZ"],
-            "Palworld" => &["Palworld-Win64-Shipping.exe", "Palworld"],
-            "Destiny 2" => &["destiny2.exe", "destiny2"],
-            "Marvel Rivals" => &["MarvelRivals.exe", "MarvelRivals"],
-            "Delta Force" => &["DeltaForce.exe", "DeltaForce"],
-            "Project Zomboid" => &["ProjectZomboid64.exe", "ProjectZomboid", "ProjectZomboid64"],
-            "Dead by Daylight" => &["DeadByDaylight-Win64-Shipping.exe", "DeadByDaylight"],
-            "VRChat" => &["VRChat.exe", "VRChat"],
-            "The Finals" => &["Discovery.exe", "Discovery"],
-            "Sid Meier's Civilization VI" => &["CivilizationVI.exe", "CivilizationVI"],
-            "EA SPORTS FC 26" => &["FC26.exe", "EASPORTSFC26.exe", "FC26"],
-            "Valheim" => &["valheim.exe", "valheim"],
-            "No Man's Sky" => &["NMS.exe", "NMS"],
-            "Satisfactory" => &["FactoryGame-Win64-Shipping.exe", "FactoryGame"],
-            "7 Days to Die" => &["7DaysToDie.exe", "7DaysToDie"],
-            "Team Fortress 2" => &["hl2.exe", "hl2_linux"],
-            "Terraria" => &["Terraria.exe", "Terraria"],
-            "Stardew Valley" => &["Stardew Valley.exe", "StardewValley.exe", "StardewValley"],
-            "Geometry Dash" => &["GeometryDash.exe", "GeometryDash"],
-            "Rocket League" => &["RocketLeague.exe", "RocketLeague"],
-            "Left 4 Dead 2" => &["left4dead2.exe", "left4dead2", "hl2_linux"],
-            "Hearts of Iron IV" => &["hoi4.exe", "hoi4"],
-            "Garry's Mod" => &["hl2.exe", "hl2_linux"],
-            "RimWorld" => &["RimWorldWin64.exe", "RimWorldWin.exe", "RimWorld"],
-            "Factorio" => &["factorio.exe", "factorio"],
-            _ => &[],
-        }
+    pub fn process_name_suggestions(&self) -> &[String] {
+        &self.process_names
+    }
+
+    pub fn supports(&self, upscaler: Upscaler) -> bool {
+        self.upscalers.contains(&upscaler)
     }
 }
 
@@ -788,7 +323,7 @@ fn normalize_match_key(value: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::GameInfo;
+    use super::{AntiCheatRisk, GameCatalogue, GameInfo, Upscaler};
 
     #[test]
     fn find_supports_normalized_queries() {
@@ -800,7 +335,171 @@ mod tests {
     fn process_suggestions_include_platform_variants() {
         let game = GameInfo::find("Dota 2").expect("Expected known game");
         let suggestions = game.process_name_suggestions();
-        assert!(suggestions.contains(&"dota2.exe"));
-        assert!(suggestions.contains(&"dota2_linux"));
+        assert!(suggestions.iter().any(|s| s == "dota2.exe"));
+        assert!(suggestions.iter().any(|s| s == "dota2_linux"));
+    }
+
+    #[test]
+    fn bundled_catalogue_is_valid() {
+        let catalogue = GameCatalogue::bundled().expect("bundled catalogue should parse");
+        assert!(catalogue.games().len() >= 50);
+        let valorant = catalogue
+            .games()
+            .iter()
+            .find(|g| g.name == "Valorant")
+            .expect("Valorant should be bundled");
+        assert_eq!(valorant.anti_cheat, AntiCheatRisk::High);
+
+        let risk = |name: &str| {
+            catalogue
+                .games()
+                .iter()
+                .find(|g| g.name == name)
+                .map(|g| g.anti_cheat)
+        };
+        assert_eq!(risk("Counter-Strike 2"), Some(AntiCheatRisk::Medium));
+        assert_eq!(risk("PUBG: BATTLEGROUNDS"), Some(AntiCheatRisk::Low));
+    }
+
+    #[test]
+    fn overrides_replace_matching_games_and_append_new_ones() {
+        let overrides = GameCatalogue::parse(
+            r#"
+[[game]]
+name = "cyberpunk 2077"
+difficulty = "heavy"
+anti_cheat = "low"
+upscalers = ["fsr"]
+frame_generation = ["fsr"]
+process_names = ["Cyberpunk2077.exe"]
+
+[[game]]
+name = "Homebrew Racer"
+aliases = ["HBR"]
+difficulty = "light"
+anti_cheat = "medium"
+process_names = ["hbr.exe"]
+"#,
+        )
+        .expect("overrides should parse");
+        let bundled = GameCatalogue::bundled().unwrap();
+        let bundled_len = bundled.games().len();
+        let merged = bundled.with_overrides(overrides).unwrap();
+
+        assert_eq!(merged.games().len(), bundled_len + 1);
+        let cyberpunk = merged
+            .games()
+            .iter()
+            .find(|g| g.name == "cyberpunk 2077")
+            .unwrap();
+        assert!(!cyberpunk.supports(Upscaler::Dlss));
+        assert!(cyberpunk.aliases.is_empty());
+        let homebrew = merged.games().last().unwrap();
+        assert_eq!(homebrew.name, "Homebrew Racer");
+        assert_eq!(homebrew.anti_cheat, AntiCheatRisk::Medium);
+        assert!(merged.warnings().is_empty());
+    }
+
+    #[test]
+    fn overrides_match_bundled_games_by_alias() {
+        let overrides = GameCatalogue::parse(
+            r#"
+[[game]]
+name = "CP2077"
+difficulty = "heavy"
+anti_cheat = "low"
+process_names = ["Cyberpunk2077.exe"]
+"#,
+        )
+        .unwrap();
+        let bundled = GameCatalogue::bundled().unwrap();
+        let bundled_len = bundled.games().len();
+        let merged = bundled.with_overrides(overrides).unwrap();
+
+        assert_eq!(merged.games().len(), bundled_len);
+        assert!(merged.games().iter().any(|g| g.name == "CP2077"));
+        assert!(!merged.games().iter().any(|g| g.name == "Cyberpunk 2077"));
+    }
+
+    #[test]
+    fn overrides_that_lower_anti_cheat_risk_are_flagged() {
+        let overrides = GameCatalogue::parse(
+            r#"
+[[game]]
+name = "Valorant"
+difficulty = "light"
+anti_cheat = "low"
+process_names = ["VALORANT-Win64-Shipping.exe"]
+"#,
+        )
+        .unwrap();
+        let merged = GameCatalogue::bundled()
+            .unwrap()
+            .with_overrides(overrides)
+            .unwrap();
+        assert_eq!(
+            merged.warnings(),
+            ["games.toml lowers the anti-cheat risk of Valorant from High to Low"]
+        );
+    }
+
+    #[test]
+    fn validation_rejects_inconsistent_entries() {
+        let duplicate_alias = r#"
+[[game]]
+name = "First"
+aliases = ["Shared"]
+difficulty = "light"
+anti_cheat = "low"
+process_names = ["first.exe"]
+
+[[game]]
+name = "Second"
+aliases = ["shared"]
+difficulty = "light"
+anti_cheat = "low"
+process_names = ["second.exe"]
+"#;
+        let err = GameCatalogue::parse(duplicate_alias).unwrap_err();
+        assert!(err.to_string().contains("used by both"));
+
+        let orphan_frame_gen = r#"
+[[game]]
+name = "First"
+difficulty = "light"
+anti_cheat = "low"
+upscalers = ["fsr"]
+frame_generation = ["dlss"]
+process_names = ["first.exe"]
+"#;
+        assert!(GameCatalogue::parse(orphan_frame_gen).is_err());
+
+        let path_process = r#"
+[[game]]
+name = "First"
+difficulty = "light"
+anti_cheat = "low"
+process_names = ["C:\\Games\\first.exe"]
+"#;
+        assert!(GameCatalogue::parse(path_process).is_err());
+
+        let unknown_key = r#"
+[[game]]
+name = "First"
+difficulty = "light"
+anti_cheat = "low"
+process_names = ["first.exe"]
+supports_dlss = true
+"#;
+        assert!(GameCatalogue::parse(unknown_key).is_err());
+
+        let missing_anti_cheat = r#"
+[[game]]
+name = "First"
+difficulty = "light"
+process_names = ["first.exe"]
+"#;
+        let err = GameCatalogue::parse(missing_anti_cheat).unwrap_err();
+        assert!(err.to_string().contains("anti_cheat"));
     }
 }
@@ -1,8 +1,11 @@
 //! Games database module
 //!
 //! Contains known games with their GPU difficulty ratings and recommended
-//! benchmark settings for consistent data collection.
+//! benchmark settings for consistent data collection. The list is loaded from
+//! a bundled TOML catalogue plus optional user overrides.
 
 mod database;
 
-pub use database::{GameDifficulty, GameInfo, KNOWN_GAMES};
+pub use database::{
+    known_games, set_catalogue, AntiCheatRisk, GameCatalogue, GameDifficulty, GameInfo, Upscaler,
+};
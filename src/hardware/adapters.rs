@@ -0,0 +1,720 @@
+//! GPU adapter inventory and render-GPU selection
+//!
+//! Hybrid laptops and desktops with an iGPU expose more than one adapter, and
+//! the first one a detection method finds is often not the one rendering the
+//! game. This module lists every adapter with its vendor, VRAM, PCI id and
+//! driver. It then makes a best-effort guess at the render GPU.
+//!
+//! The guess uses these signals, in order:
+//! - Explicit PRIME offload (`DRI_PRIME`, `__NV_PRIME_RENDER_OFFLOAD`).
+//! - The `boot_vga` sysfs attribute when it points at a discrete card.
+//! - Any discrete card.
+//! - The boot VGA device.
+//!
+//! On Linux the inventory comes from `/sys/class/drm`, with marketing names
+//! taken from `lspci` and `nvidia-smi`. On Windows it comes from WMI.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use std::fs;
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
+use super::gpu::{GpuInfo, GpuVendor};
+use super::probe::Probe;
+
+/// AMD APUs report their stolen-memory carve-out as VRAM; real cards have more.
+const APU_CARVE_OUT_MAX_MB: u64 = 2048;
+
+/// One display adapter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GpuAdapter {
+    pub name: String,
+    pub vendor: GpuVendor,
+    pub vram_mb: Option<u64>,
+    /// PCI vendor:device id (e.g. "10de:2786")
+    pub pci_id: Option<String>,
+    /// PCI slot (e.g. "0000:01:00.0")
+    pub pci_slot: Option<String>,
+    /// Kernel driver module (Linux), e.g. "amdgpu", "i915", "nvidia"
+    pub driver: Option<String>,
+    pub driver_version: Option<String>,
+    /// Integrated GPU (iGPU / APU) rather than a discrete card
+    pub integrated: bool,
+    /// Firmware used this adapter for the boot console
+    pub boot_vga: bool,
+}
+
+impl GpuAdapter {
+    pub fn to_gpu_info(&self) -> GpuInfo {
+        GpuInfo {
+            name: self.name.clone(),
+            vendor: self.vendor.clone(),
+            vram_mb: self.vram_mb,
+            driver_version: self.driver_version.clone(),
+            pci_id: self.pci_id.clone(),
+            gpu_clock_mhz: None,
+            memory_clock_mhz: None,
+            temperature_c: None,
+            utilization_percent: None,
+            power_w: None,
+        }
+    }
+}
+
+/// Why an adapter was picked as the render GPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GpuSelectionReason {
+    /// `DRI_PRIME` selected it
+    DriPrime,
+    /// `__NV_PRIME_RENDER_OFFLOAD=1` selected the NVIDIA card
+    NvidiaPrimeOffload,
+    /// Discrete card that is also the boot VGA device
+    BootVgaDiscrete,
+    /// Discrete card next to an iGPU (largest VRAM wins)
+    Discrete,
+    /// Boot VGA device when nothing better is known
+    BootVga,
+    /// The only adapter found
+    OnlyAdapter,
+    /// First adapter, no signal available
+    Fallback,
+}
+
+impl std::fmt::Display for GpuSelectionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GpuSelectionReason::DriPrime => write!(f, "DRI_PRIME"),
+            GpuSelectionReason::NvidiaPrimeOffload => write!(f, "NVIDIA PRIME render offload"),
+            GpuSelectionReason::BootVgaDiscrete => write!(f, "discrete boot VGA device"),
+            GpuSelectionReason::Discrete => write!(f, "discrete GPU"),
+            GpuSelectionReason::BootVga => write!(f, "boot VGA device"),
+            GpuSelectionReason::OnlyAdapter => write!(f, "only adapter"),
+            GpuSelectionReason::Fallback => write!(f, "first adapter"),
+        }
+    }
+}
+
+/// The adapter believed to render the game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GpuSelection {
+    /// Index into `SystemInfo::gpus`
+    pub adapter_index: usize,
+    pub reason: GpuSelectionReason,
+}
+
+/// Environment signals that force a render GPU.
+#[derive(Debug, Clone, Default)]
+pub struct GpuSelectionHints {
+    pub dri_prime: Option<String>,
+    pub nvidia_prime_offload: bool,
+}
+
+impl GpuSelectionHints {
+    pub fn from_env(probe: &Probe) -> Self {
+        Self {
+            dri_prime: probe
+                .var("DRI_PRIME")
+                .filter(|value| !value.trim().is_empty()),
+            nvidia_prime_offload: probe
+                .var("__NV_PRIME_RENDER_OFFLOAD")
+                .is_some_and(|value| value.trim() == "1"),
+        }
+    }
+}
+
+/// Guess which adapter renders the game.
+pub fn select_render_adapter(
+    adapters: &[GpuAdapter],
+    hints: &GpuSelectionHints,
+) -> Option<GpuSelection> {
+    let pick = |adapter_index: usize, reason| {
+        Some(GpuSelection {
+            adapter_index,
+            reason,
+        })
+    };
+
+    match adapters.len() {
+        0 => return None,
+        1 => return pick(0, GpuSelectionReason::OnlyAdapter),
+        _ => {}
+    }
+
+    if let Some(index) = hints
+        .dri_prime
+        .as_deref()
+        .and_then(|value| dri_prime_target(adapters, value))
+    {
+        return pick(index, GpuSelectionReason::DriPrime);
+    }
+    if hints.nvidia_prime_offload {
+        if let Some(index) = adapters
+            .iter()
+            .position(|adapter| adapter.vendor == GpuVendor::Nvidia)
+        {
+            return pick(index, GpuSelectionReason::NvidiaPrimeOffload);
+        }
+    }
+
+    let boot_vga = adapters.iter().position(|adapter| adapter.boot_vga);
+    if let Some(index) = boot_vga.filter(|index| !adapters[*index].integrated) {
+        return pick(index, GpuSelectionReason::BootVgaDiscrete);
+    }
+    if let Some((index, _)) = adapters
+        .iter()
+        .enumerate()
+        .filter(|(_, adapter)| !adapter.integrated)
+        .max_by_key(|(_, adapter)| adapter.vram_mb.unwrap_or(0))
+    {
+        return pick(index, GpuSelectionReason::Discrete);
+    }
+    if let Some(index) = boot_vga {
+        return pick(index, GpuSelectionReason::BootVga);
+    }
+    pick(0, GpuSelectionReason::Fallback)
+}
+
+/// Resolve a `DRI_PRIME` value: "0" is the default (boot) GPU, "1" the other
+/// one, and "pci-0000_01_00_0" or "vvvv:dddd" name a device directly.
+fn dri_prime_target(adapters: &[GpuAdapter], value: &str) -> Option<usize> {
+    let value = value.trim().to_lowercase();
+    if let Some(slot) = value.strip_prefix("pci-") {
+        // "pci-0000_01_00_0" → "0000:01:00.0"
+        let mut parts: Vec<&str> = slot.split('_').collect();
+        if parts.len() != 4 {
+            return None;
+        }
+        let function = parts.pop()?;
+        let slot = format!("{}.{}", parts.join(":"), function);
+        return adapters
+            .iter()
+            .position(|adapter| adapter.pci_slot.as_deref() == Some(slot.as_str()));
+    }
+    if value.contains(':') {
+        return adapters
+            .iter()
+            .position(|adapter| adapter.pci_id.as_deref() == Some(value.as_str()));
+    }
+    let default = adapters
+        .iter()
+        .position(|adapter| adapter.boot_vga)
+        .unwrap_or(0);
+    match value.parse::<usize>().ok()? {
+        0 => Some(default),
+        _ => (0..adapters.len()).find(|index| *index != default),
+    }
+}
+
+/// List all display adapters (best effort; empty when nothing can be read).
+pub fn detect_adapters(probe: &Probe) -> Vec<GpuAdapter> {
+    #[cfg(target_os = "linux")]
+    {
+        let mut adapters = read_drm_adapters(&probe.path("/sys"));
+        if let Some(names) = lspci_names(probe) {
+            apply_lspci_names(&mut adapters, &names);
+        }
+        if let Some(output) = nvidia_smi_adapters(probe) {
+            apply_nvidia_smi(&mut adapters, &output);
+        }
+        adapters
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        wmi_adapters(probe).unwrap_or_default()
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        let _ = probe;
+        Vec::new()
+    }
+}
+
+/// Read adapters from `<sys_root>/class/drm/card*` (render nodes and connectors are skipped).
+#[cfg(target_os = "linux")]
+pub fn read_drm_adapters(sys_root: &Path) -> Vec<GpuAdapter> {
+    let drm_path = sys_root.join("class/drm");
+    let Ok(entries) = fs::read_dir(&drm_path) else {
+        return Vec::new();
+    };
+
+    let mut cards: Vec<(u32, GpuAdapter)> = Vec::new();
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        let Some(card_index) = file_name
+            .strip_prefix("card")
+            .and_then(|index| index.parse::<u32>().ok())
+        else {
+            continue;
+        };
+
+        let device = entry.path().join("device");
+        let read = |name: &str| {
+            fs::read_to_string(device.join(name))
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let Some(vendor_id) = read("vendor") else {
+            continue;
+        };
+        let vendor_id = vendor_id.trim_start_matches("0x").to_lowercase();
+        let vendor = match vendor_id.as_str() {
+            "10de" => GpuVendor::Nvidia,
+            "1002" => GpuVendor::Amd,
+            "8086" => GpuVendor::Intel,
+            _ => GpuVendor::Unknown,
+        };
+        let device_id = read("device").map(|id| id.trim_start_matches("0x").to_lowercase());
+        let pci_id = device_id.map(|device_id| format!("{vendor_id}:{device_id}"));
+
+        let uevent = read("uevent").unwrap_or_default();
+        let uevent_value = |key: &str| {
+            uevent
+                .lines()
+                .find_map(|line| line.strip_prefix(key))
+                .map(|value| value.trim().to_string())
+        };
+        let pci_slot = uevent_value("PCI_SLOT_NAME=").map(|slot| slot.to_lowercase());
+        let driver = uevent_value("DRIVER=");
+        let driver_version = driver.as_deref().and_then(|driver| {
+            fs::read_to_string(sys_root.join("module").join(driver).join("version"))
+                .ok()
+                .map(|version| version.trim().to_string())
+                .filter(|version| !version.is_empty())
+        });
+
+        let vram_mb = read("mem_info_vram_total")
+            .and_then(|bytes| bytes.parse::<u64>().ok())
+            .map(|bytes| bytes / 1024 / 1024)
+            .filter(|mb| *mb > 0);
+        let boot_vga = read("boot_vga").as_deref() == Some("1");
+
+        let name = format!(
+            "{} GPU{}",
+            vendor,
+            pci_id
+                .as_deref()
+                .map(|id| format!(" [{id}]"))
+                .unwrap_or_default()
+        );
+
+        let mut adapter = GpuAdapter {
+            name,
+            vendor,
+            vram_mb,
+            pci_id,
+            pci_slot,
+            driver,
+            driver_version,
+            integrated: false,
+            boot_vga,
+        };
+        adapter.integrated = looks_integrated(&adapter);
+
+        // Several card nodes can point at one device (e.g. simpledrm); keep the first.
+        if adapter.pci_slot.is_some()
+            && cards
+                .iter()
+                .any(|(_, existing)| existing.pci_slot == adapter.pci_slot)
+        {
+            continue;
+        }
+        cards.push((card_index, adapter));
+    }
+
+    cards.sort_by_key(|(index, _)| *index);
+    cards.into_iter().map(|(_, adapter)| adapter).collect()
+}
+
+/// Integrated GPUs sit on the root PCI bus (Intel iGPU at 00:02.0) or are AMD
+/// APUs whose "VRAM" is a small carve-out. Without a PCI slot (WMI), Intel
+/// parts other than Arc count as integrated.
+fn looks_integrated(adapter: &GpuAdapter) -> bool {
+    let bus = adapter
+        .pci_slot
+        .as_deref()
+        .and_then(|slot| slot.split(':').nth(1));
+    let on_root_bus = bus == Some("00");
+    match adapter.vendor {
+        GpuVendor::Intel if bus.is_some() => on_root_bus,
+        GpuVendor::Intel => !adapter
+            .name
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|word| word == "arc"),
+        GpuVendor::Amd => {
+            on_root_bus
+                || adapter
+                    .vram_mb
+                    .is_some_and(|vram| vram <= APU_CARVE_OUT_MAX_MB)
+        }
+        _ => on_root_bus,
+    }
+}
+
+/// Map of PCI slot → marketing name from `lspci -D`.
+#[cfg(target_os = "linux")]
+fn lspci_names(probe: &Probe) -> Option<Vec<(String, String)>> {
+    let stdout = probe.run("lspci", &["-D"])?;
+    Some(parse_lspci_names(&stdout))
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn parse_lspci_names(stdout: &str) -> Vec<(String, String)> {
+    stdout
+        .lines()
+        .filter(|line| {
+            line.contains("VGA compatible controller")
+                || line.contains("3D controller")
+                || line.contains("Display controller")
+        })
+        .filter_map(|line| {
+            let (slot, rest) = line.split_once(' ')?;
+            let (_, name) = rest.split_once(": ")?;
+            let name = match name.rfind(" (rev") {
+                Some(idx) => &name[..idx],
+                None => name,
+            };
+            Some((slot.to_lowercase(), name.trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn apply_lspci_names(adapters: &mut [GpuAdapter], names: &[(String, String)]) {
+    for adapter in adapters.iter_mut() {
+        let Some(slot) = adapter.pci_slot.as_deref() else {
+            continue;
+        };
+        if let Some((_, name)) = names.iter().find(|(candidate, _)| candidate == slot) {
+            adapter.name = name.clone();
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn nvidia_smi_adapters(probe: &Probe) -> Option<String> {
+    probe.run(
+        "nvidia-smi",
+        &[
+            "--query-gpu=pci.bus_id,name,memory.total,driver_version",
+            "--format=csv,noheader,nounits",
+        ],
+    )
+}
+
+/// Fill NVIDIA names, VRAM and driver versions from `nvidia-smi` rows.
+#[cfg(any(target_os = "linux", test))]
+fn apply_nvidia_smi(adapters: &mut [GpuAdapter], stdout: &str) {
+    for line in stdout.lines() {
+        let parts: Vec<&str> = line.split(',').map(str::trim).collect();
+        if parts.len() < 4 {
+            continue;
+        }
+        // nvidia-smi prints an 8-digit domain: "00000000:01:00.0".
+        let Some((domain, rest)) = parts[0].split_once(':') else {
+            continue;
+        };
+        let Ok(domain) = u32::from_str_radix(domain, 16) else {
+            continue;
+        };
+        let slot = format!("{:04x}:{}", domain, rest.to_lowercase());
+
+        if let Some(adapter) = adapters
+            .iter_mut()
+            .find(|adapter| adapter.pci_slot.as_deref() == Some(slot.as_str()))
+        {
+            adapter.name = if parts[1].starts_with("NVIDIA") {
+                parts[1].to_string()
+            } else {
+                format!("NVIDIA {}", parts[1])
+            };
+            adapter.vram_mb = parts[2].parse().ok().or(adapter.vram_mb);
+            adapter.driver_version = Some(parts[3].to_string()).filter(|v| !v.is_empty());
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn wmi_adapters(probe: &Probe) -> Option<Vec<GpuAdapter>> {
+    let stdout = probe.run(
+        "wmic",
+        &[
+            "path",
+            "win32_VideoController",
+            "get",
+            "Name,AdapterRAM,DriverVersion,PNPDeviceID",
+            "/format:csv",
+        ],
+    )?;
+    Some(parse_wmi_adapters(&stdout))
+}
+
+/// Parse `wmic ... get Name,AdapterRAM,DriverVersion,PNPDeviceID /format:csv`
+/// (columns come back alphabetically: Node,AdapterRAM,DriverVersion,Name,PNPDeviceID).
+#[cfg(any(target_os = "windows", test))]
+fn parse_wmi_adapters(stdout: &str) -> Vec<GpuAdapter> {
+    let mut adapters = Vec::new();
+    for line in stdout.lines() {
+        let parts: Vec<&str> = line.split(',').map(str::trim).collect();
+        if parts.len() < 5 || parts[3].is_empty() || parts[3] == "Name" {
+            continue;
+        }
+        let name = parts[3].to_string();
+        let pnp = parts[4].to_uppercase();
+        let hex_after = |key: &str| {
+            pnp.find(key)
+                .map(|idx| pnp[idx + key.len()..].chars().take(4).collect::<String>())
+                .filter(|id| id.len() == 4)
+                .map(|id| id.to_lowercase())
+        };
+        let pci_id = match (hex_after("VEN_"), hex_after("DEV_")) {
+            (Some(vendor), Some(device)) => Some(format!("{vendor}:{device}")),
+            _ => None,
+        };
+        let vendor = match pci_id.as_deref().map(|id| &id[..4]) {
+            Some("10de") => GpuVendor::Nvidia,
+            Some("1002") => GpuVendor::Amd,
+            Some("8086") => GpuVendor::Intel,
+            _ => GpuVendor::Unknown,
+        };
+        // Skip virtual adapters (Microsoft Basic Display, remote desktop).
+        if vendor == GpuVendor::Unknown && !pnp.starts_with("PCI\\") {
+            continue;
+        }
+
+        let mut adapter = GpuAdapter {
+            name,
+            vendor,
+            // AdapterRAM is a 32-bit field that saturates near 4 GB (typically
+            // 0xFFF00000); only trust values below that.
+            vram_mb: parts[1]
+                .parse::<u64>()
+                .ok()
+                .filter(|bytes| *bytes > 0 && *bytes < 0xFFF0_0000)
+                .map(|bytes| bytes / 1024 / 1024),
+            pci_id,
+            pci_slot: None,
+            driver: None,
+            driver_version: Some(parts[2].to_string()).filter(|v| !v.is_empty()),
+            integrated: false,
+            boot_vga: false,
+        };
+        adapter.integrated = looks_integrated(&adapter);
+        adapters.push(adapter);
+    }
+    adapters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter(name: &str, vendor: GpuVendor, slot: &str, boot_vga: bool) -> GpuAdapter {
+        let mut adapter = GpuAdapter {
+            name: name.to_string(),
+            vendor,
+            vram_mb: None,
+            pci_id: None,
+            pci_slot: Some(slot.to_string()),
+            driver: None,
+            driver_version: None,
+            integrated: false,
+            boot_vga,
+        };
+        adapter.integrated = looks_integrated(&adapter);
+        adapter
+    }
+
+    fn hybrid_laptop() -> Vec<GpuAdapter> {
+        vec![
+            adapter(
+                "Intel Corporation Raptor Lake-P [Iris Xe Graphics]",
+                GpuVendor::Intel,
+                "0000:00:02.0",
+                true,
+            ),
+            adapter(
+                "NVIDIA Corporation AD107M [GeForce RTX 4060 Max-Q / Mobile]",
+                GpuVendor::Nvidia,
+                "0000:01:00.0",
+                false,
+            ),
+        ]
+    }
+
+    #[test]
+    fn hybrid_laptop_prefers_discrete_unless_prime_says_otherwise() {
+        let adapters = hybrid_laptop();
+        assert!(adapters[0].integrated);
+        assert!(!adapters[1].integrated);
+
+        let selection = select_render_adapter(&adapters, &GpuSelectionHints::default()).unwrap();
+        assert_eq!(selection.adapter_index, 1);
+        assert_eq!(selection.reason, GpuSelectionReason::Discrete);
+
+        for (value, expected) in [("0", 0), ("1", 1), ("pci-0000_00_02_0", 0)] {
+            let hints = GpuSelectionHints {
+                dri_prime: Some(value.to_string()),
+                nvidia_prime_offload: false,
+            };
+            let selection = select_render_adapter(&adapters, &hints).unwrap();
+            assert_eq!(selection.adapter_index, expected, "DRI_PRIME={value}");
+            assert_eq!(selection.reason, GpuSelectionReason::DriPrime);
+        }
+
+        let hints = GpuSelectionHints {
+            dri_prime: None,
+            nvidia_prime_offload: true,
+        };
+        let selection = select_render_adapter(&adapters, &hints).unwrap();
+        assert_eq!(selection.adapter_index, 1);
+        assert_eq!(selection.reason, GpuSelectionReason::NvidiaPrimeOffload);
+    }
+
+    #[test]
+    fn desktop_boot_vga_discrete_card_wins_and_single_adapter_is_trivial() {
+        let mut amd = adapter(
+            "Advanced Micro Devices, Inc. [AMD/ATI] Navi 32 [Radeon RX 7800 XT]",
+            GpuVendor::Amd,
+            "0000:03:00.0",
+            true,
+        );
+        amd.vram_mb = Some(16368);
+        let adapters = vec![
+            adapter(
+                "Intel UHD Graphics 770",
+                GpuVendor::Intel,
+                "0000:00:02.0",
+                false,
+            ),
+            amd.clone(),
+        ];
+        let selection = select_render_adapter(&adapters, &GpuSelectionHints::default()).unwrap();
+        assert_eq!(selection.adapter_index, 1);
+        assert_eq!(selection.reason, GpuSelectionReason::BootVgaDiscrete);
+
+        let selection = select_render_adapter(&[amd], &GpuSelectionHints::default()).unwrap();
+        assert_eq!(selection.reason, GpuSelectionReason::OnlyAdapter);
+        assert!(select_render_adapter(&[], &GpuSelectionHints::default()).is_none());
+    }
+
+    #[test]
+    fn intel_arc_card_is_discrete_before_it_is_named() {
+        // sysfs only knows the PCI id until lspci supplies the marketing name.
+        let arc = adapter(
+            "Intel GPU [8086:56a0]",
+            GpuVendor::Intel,
+            "0000:03:00.0",
+            false,
+        );
+        assert!(!arc.integrated);
+        let igpu = adapter(
+            "Intel GPU [8086:a780]",
+            GpuVendor::Intel,
+            "0000:00:02.0",
+            true,
+        );
+        assert!(igpu.integrated);
+
+        let selection = select_render_adapter(&[igpu, arc], &GpuSelectionHints::default()).unwrap();
+        assert_eq!(selection.adapter_index, 1);
+        assert_eq!(selection.reason, GpuSelectionReason::Discrete);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn drm_sysfs_fixture_lists_cards_and_skips_connectors() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+
+        write("class/drm/card1/device/vendor", "0x10de\n");
+        write("class/drm/card1/device/device", "0x28e0\n");
+        write("class/drm/card1/device/boot_vga", "0\n");
+        write(
+            "class/drm/card1/device/uevent",
+            "DRIVER=nvidia\nPCI_ID=10DE:28E0\nPCI_SLOT_NAME=0000:01:00.0\n",
+        );
+        write("module/nvidia/version", "550.78\n");
+        write("class/drm/card0/device/vendor", "0x1002\n");
+        write("class/drm/card0/device/device", "0x15bf\n");
+        write("class/drm/card0/device/boot_vga", "1\n");
+        write("class/drm/card0/device/mem_info_vram_total", "536870912\n");
+        write(
+            "class/drm/card0/device/uevent",
+            "DRIVER=amdgpu\nPCI_ID=1002:15BF\nPCI_SLOT_NAME=0000:c4:00.0\n",
+        );
+        write("class/drm/card0-eDP-1/status", "connected\n");
+        write("class/drm/renderD128/dev", "226:128\n");
+
+        let mut adapters = read_drm_adapters(root);
+        assert_eq!(adapters.len(), 2);
+
+        let apu = &adapters[0];
+        assert_eq!(apu.vendor, GpuVendor::Amd);
+        assert_eq!(apu.pci_id.as_deref(), Some("1002:15bf"));
+        assert_eq!(apu.pci_slot.as_deref(), Some("0000:c4:00.0"));
+        assert_eq!(apu.driver.as_deref(), Some("amdgpu"));
+        assert_eq!(apu.vram_mb, Some(512));
+        assert!(apu.boot_vga);
+        assert!(apu.integrated, "512 MB carve-out marks an APU");
+
+        let nvidia = &adapters[1];
+        assert_eq!(nvidia.driver_version.as_deref(), Some("550.78"));
+        assert!(!nvidia.integrated);
+
+        apply_lspci_names(
+            &mut adapters,
+            &parse_lspci_names(
+                "0000:01:00.0 VGA compatible controller: NVIDIA Corporation AD107M [GeForce RTX 4060 Max-Q / Mobile] (rev a1)\n\
+                 0000:c4:00.0 Display controller: Advanced Micro Devices, Inc. [AMD/ATI] Phoenix1 (rev c4)\n\
+                 0000:c4:00.1 Audio device: Advanced Micro Devices, Inc. [AMD/ATI] Rembrandt Radeon High Definition Audio Controller\n",
+            ),
+        );
+        apply_nvidia_smi(
+            &mut adapters,
+            "00000000:01:00.0, NVIDIA GeForce RTX 4060 Laptop GPU, 8188, 550.78\n",
+        );
+        assert_eq!(
+            adapters[0].name,
+            "Advanced Micro Devices, Inc. [AMD/ATI] Phoenix1"
+        );
+        assert_eq!(adapters[1].name, "NVIDIA GeForce RTX 4060 Laptop GPU");
+        assert_eq!(adapters[1].vram_mb, Some(8188));
+
+        let selection = select_render_adapter(&adapters, &GpuSelectionHints::default()).unwrap();
+        assert_eq!(selection.adapter_index, 1);
+        assert_eq!(selection.reason, GpuSelectionReason::Discrete);
+    }
+
+    #[test]
+    fn wmi_rows_become_adapters_and_virtual_displays_are_dropped() {
+        let stdout = "\r\nNode,AdapterRAM,DriverVersion,Name,PNPDeviceID\r\n\
+            DESKTOP,1073741824,31.0.101.5382,Intel(R) UHD Graphics 770,PCI\\VEN_8086&DEV_4680&SUBSYS_00000000&REV_0C\\3&11583659&0&10\r\n\
+            DESKTOP,4293918720,32.0.15.6094,NVIDIA GeForce RTX 4070,PCI\\VEN_10DE&DEV_2786&SUBSYS_00000000&REV_A1\\4&2283F625&0&0008\r\n\
+            DESKTOP,0,10.0.22621.1,Microsoft Basic Display Adapter,ROOT\\BASICDISPLAY\\0000\r\n";
+        let adapters = parse_wmi_adapters(stdout);
+        assert_eq!(adapters.len(), 2);
+        assert_eq!(adapters[0].pci_id.as_deref(), Some("8086:4680"));
+        assert!(adapters[0].integrated);
+        assert_eq!(adapters[0].vram_mb, Some(1024));
+        assert_eq!(adapters[1].vendor, GpuVendor::Nvidia);
+        assert_eq!(adapters[1].vram_mb, None, "saturated AdapterRAM is ignored");
+        assert_eq!(adapters[1].driver_version.as_deref(), Some("32.0.15.6094"));
+
+        let selection = select_render_adapter(&adapters, &GpuSelectionHints::default()).unwrap();
+        assert_eq!(selection.adapter_index, 1);
+    }
+}
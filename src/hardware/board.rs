@@ -0,0 +1,165 @@
+//! Motherboard and BIOS detection module
+//!
+//! - Linux: DMI strings in /sys/class/dmi/id (readable without root)
+//! - Windows: WMI (Win32_BaseBoard, Win32_BIOS)
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use std::{fs, path::Path};
+
+use super::probe::Probe;
+
+/// Motherboard and firmware information
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MotherboardInfo {
+    /// Board vendor (e.g. "ASUSTeK COMPUTER INC.")
+    pub vendor: Option<String>,
+    /// Board model (e.g. "ROG STRIX B650E-F GAMING WIFI")
+    pub model: Option<String>,
+    pub bios_vendor: Option<String>,
+    pub bios_version: Option<String>,
+    /// BIOS release date as reported by the firmware (usually MM/DD/YYYY)
+    pub bios_date: Option<String>,
+}
+
+impl MotherboardInfo {
+    pub fn is_empty(&self) -> bool {
+        self.vendor.is_none() && self.model.is_none() && self.bios_version.is_none()
+    }
+
+    /// Vendor and model, e.g. "ASUSTeK COMPUTER INC. ROG STRIX B650E-F GAMING WIFI"
+    pub fn name(&self) -> Option<String> {
+        match (&self.vendor, &self.model) {
+            (Some(vendor), Some(model)) => Some(format!("{} {}", vendor, model)),
+            (None, Some(model)) => Some(model.clone()),
+            (Some(vendor), None) => Some(vendor.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Detect the motherboard, or `None` when nothing useful is exposed
+    pub fn detect(probe: &Probe) -> Option<Self> {
+        #[cfg(target_os = "linux")]
+        let board = Self::read_dmi(&probe.path("/sys"));
+
+        #[cfg(target_os = "windows")]
+        let board = Self::detect_wmi(probe);
+
+        #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+        let board = {
+            let _ = probe;
+            Self::default()
+        };
+
+        Some(board).filter(|board| !board.is_empty())
+    }
+
+    /// Read DMI strings from `<sys_root>/class/dmi/id`
+    #[cfg(target_os = "linux")]
+    pub fn read_dmi(sys_root: &Path) -> Self {
+        let dmi = sys_root.join("class/dmi/id");
+        let read = |name: &str| {
+            fs::read_to_string(dmi.join(name))
+                .ok()
+                .and_then(|value| clean_dmi_string(&value))
+        };
+        Self {
+            vendor: read("board_vendor"),
+            model: read("board_name"),
+            bios_vendor: read("bios_vendor"),
+            bios_version: read("bios_version"),
+            bios_date: read("bios_date"),
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn detect_wmi(probe: &Probe) -> Self {
+        let query = |class: &str, fields: &str| -> Vec<String> {
+            let Some(stdout) = probe.run("wmic", &[class, "get", fields, "/format:csv"]) else {
+                return Vec::new();
+            };
+            // Columns: Node,<fields in alphabetical order>
+            stdout
+                .lines()
+                .map(str::trim)
+                .rev()
+                .find(|line| !line.is_empty() && !line.starts_with("Node,"))
+                .map(|line| line.split(',').skip(1).map(str::to_string).collect())
+                .unwrap_or_default()
+        };
+
+        let board = query("baseboard", "Manufacturer,Product");
+        let bios = query("bios", "Manufacturer,ReleaseDate,SMBIOSBIOSVersion");
+        let field = |values: &[String], index: usize| {
+            values.get(index).and_then(|value| clean_dmi_string(value))
+        };
+        Self {
+            vendor: field(&board, 0),
+            model: field(&board, 1),
+            bios_vendor: field(&bios, 0),
+            // WMI dates look like 20240315000000.000000+000
+            bios_date: field(&bios, 1).map(|date| date.chars().take(8).collect()),
+            bios_version: field(&bios, 2),
+        }
+    }
+}
+
+/// Trim a DMI string and drop the placeholders OEMs leave in unused fields
+fn clean_dmi_string(value: &str) -> Option<String> {
+    const PLACEHOLDERS: &[&str] = &[
+        "to be filled by o.e.m.",
+        "default string",
+        "system product name",
+        "system manufacturer",
+        "not applicable",
+        "not specified",
+        "none",
+        "n/a",
+    ];
+    let value = value.trim();
+    if value.is_empty() || PLACEHOLDERS.contains(&value.to_ascii_lowercase().as_str()) {
+        return None;
+    }
+    Some(value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_dmi_string_drops_oem_placeholders() {
+        assert_eq!(
+            clean_dmi_string("  B650 AORUS ELITE AX \n").as_deref(),
+            Some("B650 AORUS ELITE AX")
+        );
+        assert!(clean_dmi_string("To Be Filled By O.E.M.").is_none());
+        assert!(clean_dmi_string("Default string\n").is_none());
+        assert!(clean_dmi_string("").is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_read_dmi_fixture() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dmi = temp_dir.path().join("class/dmi/id");
+        fs::create_dir_all(&dmi).unwrap();
+        fs::write(dmi.join("board_vendor"), "ASUSTeK COMPUTER INC.\n").unwrap();
+        fs::write(dmi.join("board_name"), "ROG STRIX B650E-F GAMING WIFI\n").unwrap();
+        fs::write(dmi.join("bios_vendor"), "American Megatrends Inc.\n").unwrap();
+        fs::write(dmi.join("bios_version"), "3024\n").unwrap();
+        fs::write(dmi.join("bios_date"), "08/02/2024\n").unwrap();
+
+        let board = MotherboardInfo::read_dmi(temp_dir.path());
+        assert_eq!(
+            board.name().as_deref(),
+            Some("ASUSTeK COMPUTER INC. ROG STRIX B650E-F GAMING WIFI")
+        );
+        assert_eq!(board.bios_version.as_deref(), Some("3024"));
+        assert_eq!(board.bios_date.as_deref(), Some("08/02/2024"));
+
+        let missing = MotherboardInfo::read_dmi(&temp_dir.path().join("nope"));
+        assert!(missing.is_empty());
+    }
+}
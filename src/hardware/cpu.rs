@@ -1,19 +1,21 @@
 //! CPU detection module
 //!
 //! Detects CPU information using:
-//! - Cross-platform: sysinfo crate
-//! - Linux: /proc/cpuinfo, cpufreq sysfs
+//! - Cross-platform: sysinfo crate (via `Probe::host`)
+//! - Linux: /proc/cpuinfo, cpufreq sysfs, cache/topology sysfs
 //! - Windows: WMI, registry
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use sysinfo::System;
 
 #[cfg(target_os = "linux")]
-use std::{fs, path::Path};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
 
-#[cfg(target_os = "windows")]
-use std::process::Command;
+use super::probe::Probe;
 
 /// CPU information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,38 +34,288 @@ pub struct CpuInfo {
     pub architecture: Option<String>,
     /// Max turbo/boost frequency in MHz (if available)
     pub max_frequency_mhz: Option<u64>,
+    /// Cache sizes, L3 domains (CCXs) and hybrid core split (Linux)
+    #[serde(default)]
+    pub topology: Option<CpuTopology>,
 }
 
-impl CpuInfo {
-    /// Detect CPU information (platform-specific)
-    pub fn detect() -> Result<Self> {
-        let mut sys = System::new();
-        sys.refresh_cpu_all();
+/// One L3 cache domain: a CCX on AMD (one per CCD since Zen 3), the whole ring on Intel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct L3Domain {
+    pub size_kb: u64,
+    /// Physical cores sharing this L3
+    pub cores: usize,
+}
+
+/// CPU cache and core topology
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CpuTopology {
+    /// L2 cache per core in KB (of the first core)
+    #[serde(default)]
+    pub l2_cache_kb: Option<u64>,
+    /// L3 domains; more than one means multiple CCXs (or an asymmetric X3D part)
+    #[serde(default)]
+    pub l3_domains: Vec<L3Domain>,
+    /// Performance cores on hybrid CPUs (None when all cores are alike)
+    #[serde(default)]
+    pub performance_cores: Option<usize>,
+    /// Efficiency cores on hybrid CPUs
+    #[serde(default)]
+    pub efficiency_cores: Option<usize>,
+}
+
+impl CpuTopology {
+    /// Total L3 cache across all domains in KB
+    pub fn l3_cache_kb(&self) -> Option<u64> {
+        if self.l3_domains.is_empty() {
+            return None;
+        }
+        Some(self.l3_domains.iter().map(|domain| domain.size_kb).sum())
+    }
+
+    /// One-line summary, e.g. "8P + 16E | L2 2 MB/core | L3 36 MB" or
+    /// "2 CCXs | L2 1 MB/core | L3 128 MB (96 + 32 MB)"
+    pub fn summary(&self) -> String {
+        let mb = |kb: u64| {
+            if kb >= 1024 && kb.is_multiple_of(1024) {
+                format!("{} MB", kb / 1024)
+            } else if kb >= 1024 {
+                format!("{:.1} MB", kb as f64 / 1024.0)
+            } else {
+                format!("{} KB", kb)
+            }
+        };
+
+        let mut parts = Vec::new();
+        if let (Some(p), Some(e)) = (self.performance_cores, self.efficiency_cores) {
+            parts.push(format!("{}P + {}E", p, e));
+        }
+        if self.l3_domains.len() > 1 {
+            parts.push(format!("{} CCXs", self.l3_domains.len()));
+        }
+        if let Some(l2) = self.l2_cache_kb {
+            parts.push(format!("L2 {}/core", mb(l2)));
+        }
+        if let Some(l3) = self.l3_cache_kb() {
+            let asymmetric = self
+                .l3_domains
+                .windows(2)
+                .any(|pair| pair[0].size_kb != pair[1].size_kb);
+            if asymmetric {
+                let split: Vec<String> = self
+                    .l3_domains
+                    .iter()
+                    .map(|d| mb(d.size_kb).trim_end_matches(" MB").to_string())
+                    .collect();
+                parts.push(format!("L3 {} ({} MB)", mb(l3), split.join(" + ")));
+            } else {
+                parts.push(format!("L3 {}", mb(l3)));
+            }
+        }
+        parts.join(" | ")
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl CpuTopology {
+    /// Read caches and core types from `<sys_root>/devices` (normally "/sys")
+    pub fn read_sysfs(sys_root: &Path) -> Option<Self> {
+        let cpu_root = sys_root.join("devices/system/cpu");
+        let read = |path: &Path| {
+            fs::read_to_string(path)
+                .ok()
+                .map(|value| value.trim().to_string())
+        };
 
-        let cpus = sys.cpus();
+        let cpus = match read(&cpu_root.join("online")) {
+            Some(list) => parse_cpu_list(&list),
+            None => fs::read_dir(&cpu_root)
+                .ok()?
+                .flatten()
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .strip_prefix("cpu")?
+                        .parse::<usize>()
+                        .ok()
+                })
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+        };
         if cpus.is_empty() {
+            return None;
+        }
+
+        // Threads of one physical core share (package, core_id).
+        let core_of = |cpu: usize| {
+            let topology = cpu_root.join(format!("cpu{cpu}/topology"));
+            let id = |name: &str| read(&topology.join(name)).and_then(|v| v.parse::<i64>().ok());
+            (
+                id("physical_package_id").unwrap_or(0),
+                id("core_id").unwrap_or(cpu as i64),
+            )
+        };
+        let count_cores = |cpus: &[usize]| {
+            cpus.iter()
+                .map(|cpu| core_of(*cpu))
+                .collect::<BTreeSet<_>>()
+                .len()
+        };
+
+        let mut l2_cache_kb = None;
+        let mut l3_by_first_cpu: BTreeMap<usize, (u64, Vec<usize>)> = BTreeMap::new();
+        for &cpu in &cpus {
+            let Ok(entries) = fs::read_dir(cpu_root.join(format!("cpu{cpu}/cache"))) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                if !entry.file_name().to_string_lossy().starts_with("index") {
+                    continue;
+                }
+                let index = entry.path();
+                if read(&index.join("type")).as_deref() == Some("Instruction") {
+                    continue;
+                }
+                let Some(size_kb) = read(&index.join("size")).and_then(|v| parse_cache_size_kb(&v))
+                else {
+                    continue;
+                };
+                match read(&index.join("level")).as_deref() {
+                    Some("2") if l2_cache_kb.is_none() => l2_cache_kb = Some(size_kb),
+                    Some("3") => {
+                        let shared = read(&index.join("shared_cpu_list"))
+                            .map(|list| parse_cpu_list(&list))
+                            .filter(|list| !list.is_empty())
+                            .unwrap_or_else(|| vec![cpu]);
+                        l3_by_first_cpu
+                            .entry(shared[0])
+                            .or_insert((size_kb, shared));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let l3_domains = l3_by_first_cpu
+            .into_values()
+            .map(|(size_kb, shared)| L3Domain {
+                size_kb,
+                cores: count_cores(&shared),
+            })
+            .collect();
+
+        // Intel hybrid parts register separate "cpu_core" and "cpu_atom" PMUs;
+        // ARM big.LITTLE (and some newer x86 kernels) report per-CPU capacity.
+        let pmu_cpus = |name: &str| {
+            read(&sys_root.join(format!("devices/{name}/cpus"))).map(|list| parse_cpu_list(&list))
+        };
+        let (performance_cores, efficiency_cores) =
+            match (pmu_cpus("cpu_core"), pmu_cpus("cpu_atom")) {
+                (Some(p), Some(e)) if !e.is_empty() => {
+                    (Some(count_cores(&p)), Some(count_cores(&e)))
+                }
+                _ => {
+                    let capacities: Vec<(usize, u64)> = cpus
+                        .iter()
+                        .filter_map(|cpu| {
+                            read(&cpu_root.join(format!("cpu{cpu}/cpu_capacity")))
+                                .and_then(|v| v.parse().ok())
+                                .map(|capacity| (*cpu, capacity))
+                        })
+                        .collect();
+                    let max = capacities.iter().map(|(_, capacity)| *capacity).max();
+                    let split = |performance: bool| {
+                        capacities
+                            .iter()
+                            .filter(|(_, capacity)| (Some(*capacity) == max) == performance)
+                            .map(|(cpu, _)| *cpu)
+                            .collect::<Vec<_>>()
+                    };
+                    let efficiency = split(false);
+                    if capacities.len() == cpus.len() && !efficiency.is_empty() {
+                        (
+                            Some(count_cores(&split(true))),
+                            Some(count_cores(&efficiency)),
+                        )
+                    } else {
+                        (None, None)
+                    }
+                }
+            };
+
+        let topology = CpuTopology {
+            l2_cache_kb,
+            l3_domains,
+            performance_cores,
+            efficiency_cores,
+        };
+        (topology != CpuTopology::default()).then_some(topology)
+    }
+}
+
+/// Parse a sysfs CPU list ("0-7,16-23")
+#[cfg(target_os = "linux")]
+fn parse_cpu_list(list: &str) -> Vec<usize> {
+    let mut cpus = Vec::new();
+    for part in list.trim().split(',').filter(|part| !part.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                if let (Ok(start), Ok(end)) = (start.parse::<usize>(), end.parse::<usize>()) {
+                    cpus.extend(start..=end);
+                }
+            }
+            None => cpus.extend(part.parse::<usize>().ok()),
+        }
+    }
+    cpus
+}
+
+/// Parse a sysfs cache size ("32K", "98304K", "96M") into KB
+#[cfg(target_os = "linux")]
+fn parse_cache_size_kb(size: &str) -> Option<u64> {
+    let size = size.trim();
+    if let Some(kb) = size.strip_suffix('K') {
+        kb.parse().ok()
+    } else if let Some(mb) = size.strip_suffix('M') {
+        mb.parse::<u64>().ok().map(|mb| mb * 1024)
+    } else {
+        size.parse::<u64>().ok().map(|bytes| bytes / 1024)
+    }
+}
+
+impl CpuInfo {
+    /// Detect CPU information (platform-specific)
+    pub fn detect(probe: &Probe) -> Result<Self> {
+        let host = probe.host();
+        if host.cpu_threads == 0 {
             anyhow::bail!("No CPU detected");
         }
 
-        let first_cpu = &cpus[0];
-        let name = first_cpu.brand().to_string();
-        let vendor = first_cpu.vendor_id().to_string();
-        let frequency_mhz = Some(first_cpu.frequency());
+        let name = host.cpu_brand;
+        let vendor = host.cpu_vendor;
+        let frequency_mhz = host.cpu_frequency_mhz;
 
         // Count physical cores
-        let threads = cpus.len();
-        let cores = sys.physical_core_count().unwrap_or(threads / 2);
+        let threads = host.cpu_threads;
+        let cores = host.cpu_cores.unwrap_or(threads / 2);
 
         // Get platform-specific additional info
         #[cfg(target_os = "linux")]
-        let (architecture, max_frequency_mhz) = Self::get_linux_cpu_info();
+        let (architecture, max_frequency_mhz) = Self::get_linux_cpu_info(probe);
 
         #[cfg(target_os = "windows")]
-        let (architecture, max_frequency_mhz) = Self::get_windows_cpu_info();
+        let (architecture, max_frequency_mhz) = Self::get_windows_cpu_info(probe);
 
         #[cfg(not(any(target_os = "linux", target_os = "windows")))]
         let (architecture, max_frequency_mhz) = (None, None);
 
+        #[cfg(target_os = "linux")]
+        let topology = CpuTopology::read_sysfs(&probe.path("/sys"));
+
+        #[cfg(not(target_os = "linux"))]
+        let topology = None;
+
         Ok(CpuInfo {
             name,
             cores,
@@ -72,37 +324,33 @@ impl CpuInfo {
             vendor,
             architecture,
             max_frequency_mhz,
+            topology,
         })
     }
 
     /// Get additional CPU info from Linux /proc/cpuinfo
     #[cfg(target_os = "linux")]
-    fn get_linux_cpu_info() -> (Option<String>, Option<u64>) {
+    fn get_linux_cpu_info(probe: &Probe) -> (Option<String>, Option<u64>) {
         let mut architecture = None;
         let mut max_frequency = None;
 
-        if Path::new("/proc/cpuinfo").exists() {
-            if let Ok(content) = fs::read_to_string("/proc/cpuinfo") {
-                for line in content.lines() {
-                    if line.starts_with("model name") && architecture.is_none() {
-                        // Extract architecture hints from model name
-                        if line.contains("x86-64") || line.contains("Intel") || line.contains("AMD")
-                        {
-                            architecture = Some("x86_64".to_string());
-                        }
+        if let Some(content) = probe.read_to_string("/proc/cpuinfo") {
+            for line in content.lines() {
+                if line.starts_with("model name") && architecture.is_none() {
+                    // Extract architecture hints from model name
+                    if line.contains("x86-64") || line.contains("Intel") || line.contains("AMD") {
+                        architecture = Some("x86_64".to_string());
                     }
                 }
             }
         }
 
         // Try to get max frequency from cpufreq
-        if Path::new("/sys/devices/system/cpu/cpu0/cpufreq/scaling_max_freq").exists() {
-            if let Ok(freq) =
-                fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/scaling_max_freq")
-            {
-                if let Ok(freq_khz) = freq.trim().parse::<u64>() {
-                    max_frequency = Some(freq_khz / 1000); // Convert kHz to MHz
-                }
+        if let Some(freq) =
+            probe.read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/scaling_max_freq")
+        {
+            if let Ok(freq_khz) = freq.trim().parse::<u64>() {
+                max_frequency = Some(freq_khz / 1000); // Convert kHz to MHz
             }
         }
 
@@ -111,69 +359,62 @@ impl CpuInfo {
 
     /// Get additional CPU info from Windows WMI
     #[cfg(target_os = "windows")]
-    fn get_windows_cpu_info() -> (Option<String>, Option<u64>) {
+    fn get_windows_cpu_info(probe: &Probe) -> (Option<String>, Option<u64>) {
         let mut architecture = Some("x86_64".to_string()); // Most Windows PCs are x86_64
         let mut max_frequency = None;
 
         // Use wmic to get CPU info
-        if let Ok(output) = Command::new("wmic")
-            .args(["cpu", "get", "MaxClockSpeed,Architecture", "/format:csv"])
-            .output()
-        {
-            if output.status.success() {
-                let stdout = String::from_utf8_lossy(&output.stdout);
-
-                // Parse CSV output (skip header)
-                for line in stdout.lines().skip(1) {
-                    let parts: Vec<&str> = line.split(',').collect();
-                    if parts.len() >= 3 {
-                        // MaxClockSpeed is in MHz
-                        if let Ok(freq) = parts[1].trim().parse::<u64>() {
-                            max_frequency = Some(freq);
-                        }
+        if let Some(stdout) = probe.run(
+            "wmic",
+            &["cpu", "get", "MaxClockSpeed,Architecture", "/format:csv"],
+        ) {
+            // Parse CSV output (skip header)
+            for line in stdout.lines().skip(1) {
+                let parts: Vec<&str> = line.split(',').collect();
+                if parts.len() >= 3 {
+                    // MaxClockSpeed is in MHz
+                    if let Ok(freq) = parts[1].trim().parse::<u64>() {
+                        max_frequency = Some(freq);
+                    }
 
-                        // Architecture codes: 0=x86, 1=MIPS, 2=Alpha, 3=PowerPC,
-                        // 5=ARM, 6=ia64, 9=x64
-                        if let Ok(arch_code) = parts[2].trim().parse::<u32>() {
-                            architecture = match arch_code {
-                                0 => Some("x86".to_string()),
-                                9 => Some("x86_64".to_string()),
-                                5 => Some("ARM".to_string()),
-                                6 => Some("IA64".to_string()),
-                                12 => Some("ARM64".to_string()),
-                                _ => Some("x86_64".to_string()),
-                            };
-                        }
-                        break;
+                    // Architecture codes: 0=x86, 1=MIPS, 2=Alpha, 3=PowerPC,
+                    // 5=ARM, 6=ia64, 9=x64
+                    if let Ok(arch_code) = parts[2].trim().parse::<u32>() {
+                        architecture = match arch_code {
+                            0 => Some("x86".to_string()),
+                            9 => Some("x86_64".to_string()),
+                            5 => Some("ARM".to_string()),
+                            6 => Some("IA64".to_string()),
+                            12 => Some("ARM64".to_string()),
+                            _ => Some("x86_64".to_string()),
+                        };
                     }
+                    break;
                 }
             }
         }
 
         // Alternative: Try to read from registry
         if max_frequency.is_none() {
-            if let Ok(output) = Command::new("reg")
-                .args([
+            if let Some(stdout) = probe.run(
+                "reg",
+                &[
                     "query",
                     "HKEY_LOCAL_MACHINE\\HARDWARE\\DESCRIPTION\\System\\CentralProcessor\\0",
                     "/v",
                     "~MHz",
-                ])
-                .output()
-            {
-                if output.status.success() {
-                    let stdout = String::from_utf8_lossy(&output.stdout);
-                    // Parse: "    ~MHz    REG_DWORD    0x1e61"
-                    for line in stdout.lines() {
-                        if line.contains("~MHz") {
-                            let parts: Vec<&str> = line.split_whitespace().collect();
-                            if let Some(hex_str) = parts.last() {
-                                if let Ok(freq) =
-                                    u64::from_str_radix(hex_str.trim_start_matches("0x"), 16)
-                                {
-                                    max_frequency = Some(freq);
-                                    break;
-                                }
+                ],
+            ) {
+                // Parse: "    ~MHz    REG_DWORD    0x1e61"
+                for line in stdout.lines() {
+                    if line.contains("~MHz") {
+                        let parts: Vec<&str> = line.split_whitespace().collect();
+                        if let Some(hex_str) = parts.last() {
+                            if let Ok(freq) =
+                                u64::from_str_radix(hex_str.trim_start_matches("0x"), 16)
+                            {
+                                max_frequency = Some(freq);
+                                break;
                             }
                         }
                     }
@@ -184,3 +425,165 @@ impl CpuInfo {
         (architecture, max_frequency)
     }
 }
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    /// `cores` physical cores with SMT siblings at `cpu + cores`, each core
+    /// assigned an L3 domain by `l3_of(core)`.
+    fn smt_cpu(
+        root: &Path,
+        cores: usize,
+        l2: &str,
+        l3_of: impl Fn(usize) -> (&'static str, &'static str),
+    ) {
+        let threads = cores * 2;
+        write(
+            root,
+            "devices/system/cpu/online",
+            &format!("0-{}", threads - 1),
+        );
+        for cpu in 0..threads {
+            let core = cpu % cores;
+            let base = format!("devices/system/cpu/cpu{cpu}");
+            write(root, &format!("{base}/topology/physical_package_id"), "0");
+            write(root, &format!("{base}/topology/core_id"), &core.to_string());
+            for (index, level, kind, size, shared) in [
+                (
+                    "index0",
+                    "1",
+                    "Data",
+                    "32K",
+                    format!("{core},{}", core + cores),
+                ),
+                (
+                    "index1",
+                    "1",
+                    "Instruction",
+                    "32K",
+                    format!("{core},{}", core + cores),
+                ),
+                (
+                    "index2",
+                    "2",
+                    "Unified",
+                    l2,
+                    format!("{core},{}", core + cores),
+                ),
+                (
+                    "index3",
+                    "3",
+                    "Unified",
+                    l3_of(core).0,
+                    l3_of(core).1.to_string(),
+                ),
+            ] {
+                let dir = format!("{base}/cache/{index}");
+                write(root, &format!("{dir}/level"), level);
+                write(root, &format!("{dir}/type"), kind);
+                write(root, &format!("{dir}/size"), size);
+                write(root, &format!("{dir}/shared_cpu_list"), &shared);
+            }
+        }
+    }
+
+    #[test]
+    fn test_topology_asymmetric_x3d_ccxs() {
+        let temp_dir = TempDir::new().unwrap();
+        // 7950X3D: CCD0 has 96 MB of stacked L3, CCD1 has 32 MB.
+        smt_cpu(temp_dir.path(), 16, "1024K", |core| {
+            if core < 8 {
+                ("98304K", "0-7,16-23")
+            } else {
+                ("32768K", "8-15,24-31")
+            }
+        });
+
+        let topology = CpuTopology::read_sysfs(temp_dir.path()).unwrap();
+        assert_eq!(topology.l2_cache_kb, Some(1024));
+        assert_eq!(
+            topology.l3_domains,
+            vec![
+                L3Domain {
+                    size_kb: 98304,
+                    cores: 8
+                },
+                L3Domain {
+                    size_kb: 32768,
+                    cores: 8
+                },
+            ]
+        );
+        assert_eq!(topology.l3_cache_kb(), Some(131072));
+        assert_eq!(topology.efficiency_cores, None);
+        assert_eq!(
+            topology.summary(),
+            "2 CCXs | L2 1 MB/core | L3 128 MB (96 + 32 MB)"
+        );
+    }
+
+    #[test]
+    fn test_topology_intel_hybrid_split() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        // 4 P-cores with HT (cpu0-7) and 8 E-cores (cpu8-15), one shared L3.
+        write(root, "devices/system/cpu/online", "0-15");
+        for cpu in 0..16 {
+            let core = if cpu < 8 { cpu / 2 } else { cpu - 4 };
+            let base = format!("devices/system/cpu/cpu{cpu}");
+            write(root, &format!("{base}/topology/core_id"), &core.to_string());
+            write(root, &format!("{base}/cache/index3/level"), "3");
+            write(root, &format!("{base}/cache/index3/type"), "Unified");
+            write(root, &format!("{base}/cache/index3/size"), "24M");
+            write(
+                root,
+                &format!("{base}/cache/index3/shared_cpu_list"),
+                "0-15",
+            );
+        }
+        write(root, "devices/cpu_core/cpus", "0-7");
+        write(root, "devices/cpu_atom/cpus", "8-15");
+
+        let topology = CpuTopology::read_sysfs(root).unwrap();
+        assert_eq!(topology.performance_cores, Some(4));
+        assert_eq!(topology.efficiency_cores, Some(8));
+        assert_eq!(
+            topology.l3_domains,
+            vec![L3Domain {
+                size_kb: 24576,
+                cores: 12
+            }]
+        );
+        assert_eq!(topology.summary(), "4P + 8E | L3 24 MB");
+
+        // Without the hybrid PMUs, per-CPU capacity gives the same split.
+        fs::remove_dir_all(root.join("devices/cpu_core")).unwrap();
+        fs::remove_dir_all(root.join("devices/cpu_atom")).unwrap();
+        for cpu in 0..16 {
+            let capacity = if cpu < 8 { "1024" } else { "623" };
+            write(
+                root,
+                &format!("devices/system/cpu/cpu{cpu}/cpu_capacity"),
+                capacity,
+            );
+        }
+        let topology = CpuTopology::read_sysfs(root).unwrap();
+        assert_eq!(topology.performance_cores, Some(4));
+        assert_eq!(topology.efficiency_cores, Some(8));
+    }
+
+    #[test]
+    fn test_parse_cpu_list_and_cache_size() {
+        assert_eq!(parse_cpu_list("0-2,8,10-11\n"), vec![0, 1, 2, 8, 10, 11]);
+        assert_eq!(parse_cache_size_kb("98304K"), Some(98304));
+        assert_eq!(parse_cache_size_kb("96M"), Some(98304));
+    }
+}
@@ -0,0 +1,553 @@
+//! Display detection module
+//!
+//! Detects connected monitors and their mode so FPS can be read against the
+//! refresh rate:
+//! - Linux: `xrandr --current --props` (active mode, `vrr_capable`, EDID),
+//!   falling back to DRM connectors in /sys/class/drm (preferred mode, EDID)
+//! - Windows: WMI (current resolution and refresh rate)
+//!
+//! EDID parsing covers the monitor name, preferred timing, the range-limits
+//! descriptor (VRR window), AMD FreeSync and HDR static metadata blocks.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use std::{fs, path::Path};
+
+use super::probe::Probe;
+
+/// Where a display's mode came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayModeSource {
+    /// Active desktop mode (xrandr, WMI)
+    Active,
+    /// Preferred/native mode from EDID; the active mode was not readable
+    Preferred,
+}
+
+/// A connected monitor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayInfo {
+    /// Connector / output name (e.g. "DP-1")
+    pub connector: String,
+    /// Monitor name from EDID
+    pub name: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub refresh_hz: Option<f64>,
+    pub mode_source: DisplayModeSource,
+    pub primary: bool,
+    /// Adaptive sync (VRR / FreeSync / G-Sync Compatible) support, if known
+    pub vrr_capable: Option<bool>,
+    /// Vertical refresh range advertised in EDID (min, max)
+    pub vrr_range_hz: Option<(u32, u32)>,
+    /// HDR10 (PQ) or HLG support advertised in EDID
+    pub hdr_capable: Option<bool>,
+}
+
+impl DisplayInfo {
+    pub fn resolution(&self) -> String {
+        format!("{}x{}", self.width, self.height)
+    }
+
+    /// One-line summary, e.g. "2560x1440 @ 165 Hz | VRR 48–165 Hz | HDR"
+    pub fn summary(&self) -> String {
+        let mut summary = self.resolution();
+        if let Some(hz) = self.refresh_hz {
+            summary.push_str(&format!(" @ {:.0} Hz", hz));
+        }
+        if self.mode_source == DisplayModeSource::Preferred {
+            summary.push_str(" (native)");
+        }
+        match (self.vrr_capable, self.vrr_range_hz) {
+            (Some(false), _) => {}
+            (Some(true), Some((min, max))) => {
+                summary.push_str(&format!(" | VRR {}–{} Hz", min, max))
+            }
+            (Some(true), None) => summary.push_str(" | VRR"),
+            (None, _) => {}
+        }
+        if self.hdr_capable == Some(true) {
+            summary.push_str(" | HDR");
+        }
+        summary
+    }
+}
+
+/// Fields decoded from an EDID blob
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EdidInfo {
+    pub name: Option<String>,
+    /// Preferred timing: width, height, refresh in Hz
+    pub preferred_mode: Option<(u32, u32, f64)>,
+    pub vertical_range_hz: Option<(u32, u32)>,
+    pub freesync: bool,
+    pub hdr: bool,
+}
+
+impl EdidInfo {
+    /// Decode a base EDID block plus any CTA-861 extensions
+    pub fn parse(edid: &[u8]) -> Option<Self> {
+        const HEADER: [u8; 8] = [0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+        if edid.len() < 128 || edid[..8] != HEADER {
+            return None;
+        }
+
+        let mut info = EdidInfo::default();
+        for offset in [54usize, 72, 90, 108] {
+            let d = &edid[offset..offset + 18];
+            if d[0] != 0 || d[1] != 0 {
+                // Detailed timing descriptor; the first one is the preferred mode.
+                if info.preferred_mode.is_none() {
+                    let clock_hz = u16::from_le_bytes([d[0], d[1]]) as f64 * 10_000.0;
+                    let h_active = d[2] as u32 | ((d[4] as u32 >> 4) << 8);
+                    let h_blank = d[3] as u32 | ((d[4] as u32 & 0x0f) << 8);
+                    let v_active = d[5] as u32 | ((d[7] as u32 >> 4) << 8);
+                    let v_blank = d[6] as u32 | ((d[7] as u32 & 0x0f) << 8);
+                    let total = ((h_active + h_blank) * (v_active + v_blank)) as f64;
+                    if total > 0.0 {
+                        info.preferred_mode = Some((h_active, v_active, clock_hz / total));
+                    }
+                }
+                continue;
+            }
+            match d[3] {
+                0xfc => {
+                    let name: String = d[5..18]
+                        .iter()
+                        .take_while(|b| **b != 0x0a)
+                        .map(|b| *b as char)
+                        .collect();
+                    let name = name.trim().to_string();
+                    if !name.is_empty() {
+                        info.name = Some(name);
+                    }
+                }
+                0xfd => {
+                    // Bits 0-1 of byte 4 add 255 to max/min vertical rates (EDID 1.4).
+                    let max_offset = if d[4] & 0x02 != 0 { 255 } else { 0 };
+                    let min_offset = if d[4] & 0x03 == 0x03 { 255 } else { 0 };
+                    let min = d[5] as u32 + min_offset;
+                    let max = d[6] as u32 + max_offset;
+                    if min > 0 && max >= min {
+                        info.vertical_range_hz = Some((min, max));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let extensions = edid[126] as usize;
+        for block in edid.chunks_exact(128).skip(1).take(extensions) {
+            if block[0] == 0x02 {
+                info.parse_cta_block(block);
+            }
+        }
+        Some(info)
+    }
+
+    fn parse_cta_block(&mut self, block: &[u8]) {
+        let dtd_offset = (block[2] as usize).min(127);
+        let mut pos = 4;
+        while pos < dtd_offset {
+            let tag = block[pos] >> 5;
+            let len = (block[pos] & 0x1f) as usize;
+            let payload = &block[(pos + 1).min(127)..(pos + 1 + len).min(127)];
+            match tag {
+                // Vendor-specific data block; AMD's OUI (00-00-1A) marks FreeSync.
+                3 if payload.len() >= 3 && payload[..3] == [0x1a, 0x00, 0x00] => {
+                    self.freesync = true;
+                }
+                // Extended tag 6: HDR static metadata; EOTF bit 2 = PQ, bit 3 = HLG.
+                7 if payload.len() >= 2 && payload[0] == 0x06 => {
+                    self.hdr = payload[1] & 0x0c != 0;
+                }
+                _ => {}
+            }
+            pos += 1 + len;
+        }
+    }
+
+    /// A refresh window wide enough for adaptive sync (e.g. 48–165 Hz rather than 56–76 Hz)
+    pub fn looks_vrr(&self) -> bool {
+        self.freesync
+            || self
+                .vertical_range_hz
+                .is_some_and(|(min, max)| max >= 90 && max as f64 >= min as f64 * 1.8)
+    }
+}
+
+/// Detect connected displays (best effort; empty when nothing can be read)
+pub fn detect_displays(probe: &Probe) -> Vec<DisplayInfo> {
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(displays) = xrandr_displays(probe).filter(|displays| !displays.is_empty()) {
+            return displays;
+        }
+        read_drm_displays(&probe.path("/sys"))
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        wmi_displays(probe).unwrap_or_default()
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        let _ = probe;
+        Vec::new()
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn xrandr_displays(probe: &Probe) -> Option<Vec<DisplayInfo>> {
+    let stdout = probe.run("xrandr", &["--current", "--props"])?;
+    Some(parse_xrandr(&stdout))
+}
+
+/// Parse `xrandr --current --props` into the active outputs
+#[cfg(any(target_os = "linux", test))]
+fn parse_xrandr(stdout: &str) -> Vec<DisplayInfo> {
+    struct Output {
+        display: DisplayInfo,
+        active: bool,
+        edid_hex: String,
+    }
+
+    let mut outputs: Vec<Output> = Vec::new();
+    let mut in_edid = false;
+    for line in stdout.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            in_edid = false;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.get(1) != Some(&"connected") {
+                continue;
+            }
+            let primary = tokens.get(2) == Some(&"primary");
+            // Active outputs carry a geometry token: "2560x1440+0+0"
+            let geometry = tokens
+                .iter()
+                .skip(2)
+                .find_map(|token| parse_mode(token.split('+').next().unwrap_or("")));
+            outputs.push(Output {
+                display: DisplayInfo {
+                    connector: tokens[0].to_string(),
+                    name: None,
+                    width: geometry.map(|g| g.0).unwrap_or(0),
+                    height: geometry.map(|g| g.1).unwrap_or(0),
+                    refresh_hz: None,
+                    mode_source: DisplayModeSource::Active,
+                    primary,
+                    vrr_capable: None,
+                    vrr_range_hz: None,
+                    hdr_capable: None,
+                },
+                active: geometry.is_some(),
+                edid_hex: String::new(),
+            });
+            continue;
+        }
+
+        let Some(output) = outputs.last_mut() else {
+            continue;
+        };
+        let trimmed = line.trim();
+        if in_edid {
+            if !trimmed.is_empty() && trimmed.chars().all(|c| c.is_ascii_hexdigit()) {
+                output.edid_hex.push_str(trimmed);
+                continue;
+            }
+            in_edid = false;
+        }
+        if trimmed == "EDID:" {
+            in_edid = true;
+        } else if let Some(value) = trimmed.strip_prefix("vrr_capable:") {
+            output.display.vrr_capable = Some(value.trim() == "1");
+        } else if line.starts_with("   ") && trimmed.split_whitespace().count() > 1 {
+            // Mode line: "2560x1440    165.00*+ 144.00"; '*' marks the current rate.
+            let mut tokens = trimmed.split_whitespace();
+            let Some((width, height)) = tokens.next().and_then(parse_mode) else {
+                continue;
+            };
+            if let Some(rate) = tokens.find(|token| token.contains('*')) {
+                if width == output.display.width && height == output.display.height {
+                    output.display.refresh_hz =
+                        rate.trim_end_matches(['*', '+']).parse::<f64>().ok();
+                }
+            }
+        }
+    }
+
+    outputs
+        .into_iter()
+        .filter(|output| output.active)
+        .map(|mut output| {
+            if let Some(edid) = decode_hex(&output.edid_hex).and_then(|b| EdidInfo::parse(&b)) {
+                apply_edid(&mut output.display, &edid);
+            }
+            output.display
+        })
+        .collect()
+}
+
+/// Read connected DRM connectors from `<sys_root>/class/drm/card*-*`
+#[cfg(target_os = "linux")]
+pub fn read_drm_displays(sys_root: &Path) -> Vec<DisplayInfo> {
+    let Ok(entries) = fs::read_dir(sys_root.join("class/drm")) else {
+        return Vec::new();
+    };
+
+    let mut displays: Vec<DisplayInfo> = Vec::new();
+    let mut connectors: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+    connectors.sort();
+    for path in connectors {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let Some((_, connector)) = file_name
+            .strip_prefix("card")
+            .and_then(|rest| rest.split_once('-'))
+        else {
+            continue;
+        };
+        let read = |name: &str| {
+            fs::read_to_string(path.join(name))
+                .ok()
+                .map(|value| value.trim().to_string())
+        };
+        if read("status").as_deref() != Some("connected") {
+            continue;
+        }
+        if read("enabled").as_deref() == Some("disabled") {
+            continue;
+        }
+
+        let edid = fs::read(path.join("edid"))
+            .ok()
+            .and_then(|bytes| EdidInfo::parse(&bytes));
+        // `modes` lists the preferred mode first.
+        let first_mode = read("modes").and_then(|modes| modes.lines().next().and_then(parse_mode));
+        let Some((width, height)) = first_mode.or_else(|| {
+            edid.as_ref()
+                .and_then(|edid| edid.preferred_mode)
+                .map(|(w, h, _)| (w, h))
+        }) else {
+            continue;
+        };
+
+        let mut display = DisplayInfo {
+            connector: connector.to_string(),
+            name: None,
+            width,
+            height,
+            refresh_hz: None,
+            mode_source: DisplayModeSource::Preferred,
+            primary: displays.is_empty(),
+            vrr_capable: None,
+            vrr_range_hz: None,
+            hdr_capable: None,
+        };
+        if let Some(edid) = edid {
+            apply_edid(&mut display, &edid);
+        }
+        displays.push(display);
+    }
+    displays
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn apply_edid(display: &mut DisplayInfo, edid: &EdidInfo) {
+    display.name = edid.name.clone();
+    display.hdr_capable = Some(edid.hdr);
+    display.vrr_range_hz = edid.vertical_range_hz;
+    if display.vrr_capable.is_none() && edid.looks_vrr() {
+        display.vrr_capable = Some(true);
+    }
+    if display.refresh_hz.is_none() {
+        if let Some((w, h, hz)) = edid.preferred_mode {
+            if w == display.width && h == display.height {
+                display.refresh_hz = Some((hz * 100.0).round() / 100.0);
+            }
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn parse_mode(token: &str) -> Option<(u32, u32)> {
+    let (width, height) = token.split_once('x')?;
+    // Interlaced modes end in "i" ("1920x1080i").
+    let height = height.trim_end_matches('i');
+    Some((width.parse().ok()?, height.parse().ok()?))
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.is_empty() || !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn wmi_displays(probe: &Probe) -> Option<Vec<DisplayInfo>> {
+    let stdout = probe.run(
+        "wmic",
+        &[
+            "path",
+            "Win32_VideoController",
+            "get",
+            "CurrentHorizontalResolution,CurrentRefreshRate,CurrentVerticalResolution",
+            "/format:csv",
+        ],
+    )?;
+    let mut displays = Vec::new();
+    // Columns: Node,CurrentHorizontalResolution,CurrentRefreshRate,CurrentVerticalResolution
+    for line in stdout.lines() {
+        let parts: Vec<&str> = line.split(',').map(str::trim).collect();
+        if parts.len() < 4 {
+            continue;
+        }
+        let (Ok(width), Ok(height)) = (parts[1].parse::<u32>(), parts[3].parse::<u32>()) else {
+            continue;
+        };
+        displays.push(DisplayInfo {
+            connector: format!("display{}", displays.len()),
+            name: None,
+            width,
+            height,
+            refresh_hz: parts[2].parse::<f64>().ok().filter(|hz| *hz > 1.0),
+            mode_source: DisplayModeSource::Active,
+            primary: displays.is_empty(),
+            vrr_capable: None,
+            vrr_range_hz: None,
+            hdr_capable: None,
+        });
+    }
+    Some(displays)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 256-byte EDID: 2560x1440 preferred timing, name, 48–165 Hz range,
+    /// plus a CTA block with FreeSync and HDR static metadata.
+    fn sample_edid() -> Vec<u8> {
+        let mut edid = vec![0u8; 256];
+        edid[..8].copy_from_slice(&[0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00]);
+
+        // DTD: 645.00 MHz, 2560+160 x 1440+41 → 160.1 Hz
+        let dtd = &mut edid[54..72];
+        dtd[..2].copy_from_slice(&64500u16.to_le_bytes());
+        dtd[2] = (2560 & 0xff) as u8;
+        dtd[3] = 160;
+        dtd[4] = ((2560 >> 8) << 4) as u8;
+        dtd[5] = (1440 & 0xff) as u8;
+        dtd[6] = 41;
+        dtd[7] = ((1440 >> 8) << 4) as u8;
+
+        let name = &mut edid[72..90];
+        name[3] = 0xfc;
+        name[5..18].copy_from_slice(b"DELL S2721DGF");
+
+        let range = &mut edid[90..108];
+        range[3] = 0xfd;
+        range[5] = 48;
+        range[6] = 165;
+
+        edid[126] = 1;
+        let cta = &mut edid[128..256];
+        cta[0] = 0x02;
+        cta[1] = 0x03;
+        cta[2] = 4 + 4 + 4;
+        // AMD VSDB: tag 3, len 3, OUI 00-00-1A
+        cta[4..8].copy_from_slice(&[(3 << 5) | 3, 0x1a, 0x00, 0x00]);
+        // HDR static metadata: tag 7, len 3, ext tag 6, EOTF SDR + PQ
+        cta[8..12].copy_from_slice(&[(7 << 5) | 3, 0x06, 0x05, 0x01]);
+        edid
+    }
+
+    #[test]
+    fn test_edid_parse_name_timing_range_freesync_hdr() {
+        let edid = EdidInfo::parse(&sample_edid()).unwrap();
+        assert_eq!(edid.name.as_deref(), Some("DELL S2721DGF"));
+        let (w, h, hz) = edid.preferred_mode.unwrap();
+        assert_eq!((w, h), (2560, 1440));
+        assert!((hz - 645_000_000.0 / (2720.0 * 1481.0)).abs() < 1e-6);
+        assert_eq!(edid.vertical_range_hz, Some((48, 165)));
+        assert!(edid.freesync);
+        assert!(edid.hdr);
+        assert!(edid.looks_vrr());
+
+        assert!(EdidInfo::parse(&[0u8; 128]).is_none());
+    }
+
+    #[test]
+    fn test_parse_xrandr_active_outputs() {
+        let hex: String = sample_edid()[..128]
+            .chunks(16)
+            .map(|chunk| {
+                let line: String = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+                format!("\t\t{line}\n")
+            })
+            .collect();
+        let stdout = format!(
+            "Screen 0: minimum 320 x 200, current 4480 x 1440, maximum 16384 x 16384\n\
+             DisplayPort-0 connected primary 2560x1440+0+0 (normal left inverted right x axis y axis) 597mm x 336mm\n\
+             \tEDID: \n{hex}\
+             \tvrr_capable: 1 \n\
+             \t\trange: (0, 1)\n\
+             \x20  2560x1440    165.00*+ 143.97   120.00  \n\
+             \x20  1920x1080     60.00  \n\
+             HDMI-A-0 connected 1920x1080+2560+0 (normal left inverted right x axis y axis) 527mm x 296mm\n\
+             \x20  1920x1080     60.00*+  50.00  \n\
+             DisplayPort-1 disconnected (normal left inverted right x axis y axis)\n"
+        );
+
+        let displays = parse_xrandr(&stdout);
+        assert_eq!(displays.len(), 2);
+        let main = &displays[0];
+        assert_eq!(main.connector, "DisplayPort-0");
+        assert!(main.primary);
+        assert_eq!(main.resolution(), "2560x1440");
+        assert_eq!(main.refresh_hz, Some(165.0));
+        assert_eq!(main.vrr_capable, Some(true));
+        assert_eq!(main.name.as_deref(), Some("DELL S2721DGF"));
+        assert_eq!(main.summary(), "2560x1440 @ 165 Hz | VRR 48–165 Hz");
+
+        let side = &displays[1];
+        assert!(!side.primary);
+        assert_eq!(side.refresh_hz, Some(60.0));
+        assert_eq!(side.vrr_capable, None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_drm_connector_fixture_uses_edid_preferred_mode() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let connector = root.join("class/drm/card1-DP-1");
+        fs::create_dir_all(&connector).unwrap();
+        fs::write(connector.join("status"), "connected\n").unwrap();
+        fs::write(connector.join("enabled"), "enabled\n").unwrap();
+        fs::write(connector.join("modes"), "2560x1440\n1920x1080\n").unwrap();
+        fs::write(connector.join("edid"), sample_edid()).unwrap();
+        let off = root.join("class/drm/card1-HDMI-A-1");
+        fs::create_dir_all(&off).unwrap();
+        fs::write(off.join("status"), "disconnected\n").unwrap();
+
+        let displays = read_drm_displays(root);
+        assert_eq!(displays.len(), 1);
+        let display = &displays[0];
+        assert_eq!(display.connector, "DP-1");
+        assert_eq!(display.mode_source, DisplayModeSource::Preferred);
+        assert_eq!(display.refresh_hz, Some(160.12));
+        assert_eq!(display.hdr_capable, Some(true));
+        assert!(display.primary);
+    }
+}
@@ -0,0 +1,271 @@
+//! Storage drive detection module
+//!
+//! - Linux: /sys/block/<dev> (`device/model`, `queue/rotational`, `size`)
+//! - Windows: `Get-PhysicalDisk` (MediaType, BusType)
+//!
+//! Only physical, non-removable drives are listed; loop, zram, device-mapper,
+//! optical and USB-attached devices are skipped. Serial numbers are never read.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use std::{fs, path::Path};
+
+use super::probe::Probe;
+
+/// Drive technology / interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DriveKind {
+    Nvme,
+    SataSsd,
+    Hdd,
+    Unknown,
+}
+
+impl std::fmt::Display for DriveKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DriveKind::Nvme => write!(f, "NVMe SSD"),
+            DriveKind::SataSsd => write!(f, "SATA SSD"),
+            DriveKind::Hdd => write!(f, "HDD"),
+            DriveKind::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// A physical storage drive
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriveInfo {
+    /// Kernel / OS device name (e.g. "nvme0n1", "sda")
+    pub device: String,
+    pub model: Option<String>,
+    pub kind: DriveKind,
+    pub capacity_gb: u64,
+}
+
+impl DriveInfo {
+    /// One-line summary, e.g. "Samsung SSD 990 PRO 2TB (NVMe SSD, 2000 GB)"
+    pub fn summary(&self) -> String {
+        let model = self.model.as_deref().unwrap_or(&self.device);
+        format!("{} ({}, {} GB)", model, self.kind, self.capacity_gb)
+    }
+}
+
+/// Detect physical drives, largest first
+pub fn detect_drives(probe: &Probe) -> Vec<DriveInfo> {
+    #[cfg(target_os = "linux")]
+    {
+        read_block_devices(&probe.path("/sys"))
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        physical_disks(probe).unwrap_or_default()
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        let _ = probe;
+        Vec::new()
+    }
+}
+
+/// Read drives from `<sys_root>/block`
+#[cfg(target_os = "linux")]
+pub fn read_block_devices(sys_root: &Path) -> Vec<DriveInfo> {
+    let Ok(entries) = fs::read_dir(sys_root.join("block")) else {
+        return Vec::new();
+    };
+
+    let mut drives = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let device = entry.file_name().to_string_lossy().into_owned();
+        // Virtual block devices (loop, zram, dm-*, md*) have no `device` link.
+        if !path.join("device").exists() {
+            continue;
+        }
+        let read = |name: &str| {
+            fs::read_to_string(path.join(name))
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        if read("removable").as_deref() == Some("1") || is_on_usb_bus(&path) {
+            continue;
+        }
+        // `size` is always in 512-byte sectors.
+        let sectors = read("size")
+            .and_then(|size| size.parse::<u64>().ok())
+            .unwrap_or(0);
+        if sectors == 0 {
+            continue;
+        }
+
+        let kind = if device.starts_with("nvme") {
+            DriveKind::Nvme
+        } else {
+            match read("queue/rotational").as_deref() {
+                Some("1") => DriveKind::Hdd,
+                // eMMC (mmcblk*) and virtio (vd*) disks are not SATA SSDs.
+                Some("0") if device.starts_with("sd") => DriveKind::SataSsd,
+                _ => DriveKind::Unknown,
+            }
+        };
+
+        drives.push(DriveInfo {
+            device,
+            model: read("device/model"),
+            kind,
+            capacity_gb: sectors * 512 / 1_000_000_000,
+        });
+    }
+
+    drives.sort_by(|a, b| {
+        b.capacity_gb
+            .cmp(&a.capacity_gb)
+            .then_with(|| a.device.cmp(&b.device))
+    });
+    drives
+}
+
+/// Whether a `/sys/block/<dev>` entry resolves to a device behind a USB host
+/// controller (`.../usb2/2-1/...`); USB sticks and enclosures often report
+/// `removable` as 0.
+#[cfg(target_os = "linux")]
+fn is_on_usb_bus(block_path: &Path) -> bool {
+    fs::canonicalize(block_path).is_ok_and(|resolved| {
+        resolved.components().any(|component| {
+            component
+                .as_os_str()
+                .to_str()
+                .and_then(|name| name.strip_prefix("usb"))
+                .is_some_and(|bus| !bus.is_empty() && bus.chars().all(|c| c.is_ascii_digit()))
+        })
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn physical_disks(probe: &Probe) -> Option<Vec<DriveInfo>> {
+    let stdout = probe.run(
+        "powershell",
+        &[
+            "-NoProfile",
+            "-Command",
+            "Get-PhysicalDisk | Select-Object DeviceId,FriendlyName,MediaType,BusType,Size | ConvertTo-Csv -NoTypeInformation",
+        ],
+    )?;
+    Some(parse_physical_disk_csv(&stdout))
+}
+
+/// Parse `Get-PhysicalDisk | ConvertTo-Csv` output
+#[cfg(any(target_os = "windows", test))]
+fn parse_physical_disk_csv(stdout: &str) -> Vec<DriveInfo> {
+    let mut drives = Vec::new();
+    // Columns: "DeviceId","FriendlyName","MediaType","BusType","Size"
+    for line in stdout.lines().skip(1) {
+        let parts: Vec<&str> = line
+            .split("\",\"")
+            .map(|part| part.trim_matches('"'))
+            .collect();
+        if parts.len() < 5 {
+            continue;
+        }
+        let Ok(bytes) = parts[4].trim().parse::<u64>() else {
+            continue;
+        };
+        if parts[3] == "USB" {
+            continue;
+        }
+        let kind = match (parts[2], parts[3]) {
+            (_, "NVMe") => DriveKind::Nvme,
+            ("HDD", _) => DriveKind::Hdd,
+            ("SSD", _) => DriveKind::SataSsd,
+            _ => DriveKind::Unknown,
+        };
+        drives.push(DriveInfo {
+            device: format!("PhysicalDrive{}", parts[0]),
+            model: Some(parts[1].trim().to_string()).filter(|model| !model.is_empty()),
+            kind,
+            capacity_gb: bytes / 1_000_000_000,
+        });
+    }
+    drives.sort_by_key(|drive| std::cmp::Reverse(drive.capacity_gb));
+    drives
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_physical_disk_csv() {
+        let stdout = "\"DeviceId\",\"FriendlyName\",\"MediaType\",\"BusType\",\"Size\"\n\
+                      \"1\",\"ST2000DM008-2FR102\",\"HDD\",\"SATA\",\"2000398934016\"\n\
+                      \"0\",\"Samsung SSD 990 PRO 1TB\",\"SSD\",\"NVMe\",\"1000204886016\"\n\
+                      \"2\",\"SanDisk Ultra\",\"Unspecified\",\"USB\",\"64023257088\"\n";
+        let drives = parse_physical_disk_csv(stdout);
+        assert_eq!(drives.len(), 2);
+        assert_eq!(drives[0].kind, DriveKind::Hdd);
+        assert_eq!(drives[0].capacity_gb, 2000);
+        assert_eq!(drives[1].device, "PhysicalDrive0");
+        assert_eq!(
+            drives[1].summary(),
+            "Samsung SSD 990 PRO 1TB (NVMe SSD, 1000 GB)"
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_read_block_devices_fixture() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let add = |name: &str, model: Option<&str>, rotational: &str, sectors: u64| {
+            let dev = root.join("block").join(name);
+            fs::create_dir_all(dev.join("queue")).unwrap();
+            if let Some(model) = model {
+                fs::create_dir_all(dev.join("device")).unwrap();
+                fs::write(dev.join("device/model"), format!("{model}\n")).unwrap();
+            }
+            fs::write(dev.join("queue/rotational"), rotational).unwrap();
+            fs::write(dev.join("size"), sectors.to_string()).unwrap();
+            fs::write(dev.join("removable"), "0\n").unwrap();
+        };
+        add(
+            "nvme0n1",
+            Some("Samsung SSD 990 PRO 2TB"),
+            "0\n",
+            3_907_029_168,
+        );
+        add("sda", Some("CT1000MX500SSD1  "), "0\n", 1_953_525_168);
+        add("sdb", Some("WDC WD40EZAZ-00S"), "1\n", 7_814_037_168);
+        add("loop0", None, "0\n", 1_000_000);
+        add("sr0", Some("DVD-RW"), "1\n", 0);
+        add("mmcblk0", Some("SC64G"), "0\n", 124_735_488);
+
+        // A USB enclosure that claims to be fixed: sysfs links it under usbN.
+        let usb_dev = root.join("devices/pci0000:00/0000:00:14.0/usb2/2-1/host6/block/sdc");
+        fs::create_dir_all(usb_dev.join("device")).unwrap();
+        fs::write(usb_dev.join("device/model"), "Portable SSD T7\n").unwrap();
+        fs::write(usb_dev.join("size"), "1953525168").unwrap();
+        fs::write(usb_dev.join("removable"), "0\n").unwrap();
+        std::os::unix::fs::symlink(&usb_dev, root.join("block/sdc")).unwrap();
+
+        let drives = read_block_devices(root);
+        let summary: Vec<(&str, DriveKind, u64)> = drives
+            .iter()
+            .map(|drive| (drive.device.as_str(), drive.kind, drive.capacity_gb))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("sdb", DriveKind::Hdd, 4000),
+                ("nvme0n1", DriveKind::Nvme, 2000),
+                ("sda", DriveKind::SataSsd, 1000),
+                ("mmcblk0", DriveKind::Unknown, 63),
+            ]
+        );
+        assert_eq!(drives[2].model.as_deref(), Some("CT1000MX500SSD1"));
+    }
+}
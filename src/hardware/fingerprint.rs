@@ -0,0 +1,419 @@
+//! Canonical hardware identity and anonymised fingerprint
+//!
+//! The same machine is reported with slightly different names depending on the
+//! detection path (nvidia-smi, lspci, sysfs, dxdiag). This module reduces
+//! `SystemInfo` to a canonical identity: normalised CPU and GPU model strings
+//! plus bucketed RAM and VRAM sizes. It then hashes that identity into a salted
+//! SHA-256 fingerprint.
+//!
+//! There are only so many parts lists, so a fixed salt could be undone by
+//! hashing every common build. The salt is therefore a random value created
+//! per install (`fingerprint_salt` next to the config) that is never
+//! uploaded. The backend can group runs from the same install and see when
+//! its hardware changed, but cannot recover the parts from the fingerprint or
+//! match it across installs.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Write;
+use std::sync::OnceLock;
+
+use super::gpu::GpuVendor;
+use super::SystemInfo;
+use crate::config::Config;
+
+/// Domain-separation tag; bump the version when normalisation rules change.
+const FINGERPRINT_DOMAIN: &str = "fps-tracker/hardware-fingerprint/v2";
+const FINGERPRINT_PREFIX: &str = "hw2_";
+/// Per-install salt file, next to the user config.
+const SALT_FILE: &str = "fingerprint_salt";
+/// Shortest salt accepted from disk (hex characters).
+const MIN_SALT_LEN: usize = 32;
+/// Hex characters kept from the digest (128 bits).
+const FINGERPRINT_HEX_LEN: usize = 32;
+
+/// Standard installed-memory sizes (GB) that usable RAM is rounded up to.
+const RAM_BUCKETS_GB: [u32; 12] = [2, 4, 6, 8, 12, 16, 24, 32, 48, 64, 96, 128];
+
+/// Normalised hardware identity used for the fingerprint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardwareIdentity {
+    pub cpu_model: String,
+    pub gpu_vendor: String,
+    pub gpu_model: String,
+    pub ram_gb: u32,
+    pub vram_gb: Option<u32>,
+}
+
+impl HardwareIdentity {
+    pub fn from_system_info(info: &SystemInfo) -> Self {
+        let ram_mb = info.ram.installed_mb.unwrap_or(info.ram.usable_mb);
+        Self {
+            cpu_model: normalize_cpu_model(&info.cpu.name),
+            gpu_vendor: match info.gpu.vendor {
+                GpuVendor::Nvidia => "nvidia",
+                GpuVendor::Amd => "amd",
+                GpuVendor::Intel => "intel",
+                GpuVendor::Unknown => "unknown",
+            }
+            .to_string(),
+            gpu_model: normalize_gpu_model(&info.gpu.name),
+            ram_gb: bucket_ram_gb(ram_mb),
+            vram_gb: info.gpu.vram_mb.and_then(bucket_vram_gb),
+        }
+    }
+
+    /// Stable text form that gets hashed.
+    pub fn canonical(&self) -> String {
+        format!(
+            "cpu={};gpu={}:{};vram_gb={};ram_gb={}",
+            self.cpu_model,
+            self.gpu_vendor,
+            self.gpu_model,
+            self.vram_gb
+                .map(|gb| gb.to_string())
+                .unwrap_or_else(|| "?".to_string()),
+            self.ram_gb
+        )
+    }
+
+    /// Fingerprint salted with this install's secret, e.g. `hw2_3f2a…`.
+    pub fn fingerprint(&self) -> String {
+        self.fingerprint_with_salt(install_salt())
+    }
+
+    /// Fingerprint of the canonical identity under `salt`.
+    pub fn fingerprint_with_salt(&self, salt: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(FINGERPRINT_DOMAIN.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(salt.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(self.canonical().as_bytes());
+        let hex: String = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        format!("{FINGERPRINT_PREFIX}{}", &hex[..FINGERPRINT_HEX_LEN])
+    }
+}
+
+/// This install's salt, created on first use. If it cannot be stored, a salt
+/// for this process only is used, so fingerprints still never use a known salt.
+fn install_salt() -> &'static str {
+    static SALT: OnceLock<String> = OnceLock::new();
+    SALT.get_or_init(|| {
+        if cfg!(test) {
+            return "0".repeat(MIN_SALT_LEN);
+        }
+        load_or_create_salt().unwrap_or_else(|_| new_salt())
+    })
+}
+
+fn load_or_create_salt() -> Result<String> {
+    let path = Config::config_path()?.with_file_name(SALT_FILE);
+    if let Ok(existing) = fs::read_to_string(&path) {
+        let existing = existing.trim();
+        if existing.len() >= MIN_SALT_LEN {
+            return Ok(existing.to_string());
+        }
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let salt = new_salt();
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(&path)?.write_all(salt.as_bytes())?;
+    Ok(salt)
+}
+
+/// 256 random bits as hex
+fn new_salt() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// Normalise a CPU model string ("AMD Ryzen 7 5800X 8-Core Processor" → "amd ryzen 7 5800x").
+pub fn normalize_cpu_model(name: &str) -> String {
+    let cleaned = strip_trademarks(name);
+    // Drop the clock suffix Intel adds ("@ 3.60GHz").
+    let cleaned = cleaned.split('@').next().unwrap_or_default();
+
+    let words: Vec<&str> = cleaned.split_whitespace().collect();
+    let mut kept = Vec::with_capacity(words.len());
+    let mut idx = 0;
+    while idx < words.len() {
+        let word = words[idx];
+        let next = words.get(idx + 1).copied();
+        // "12th Gen Intel Core ..." → "intel core ..."
+        if next == Some("gen") && is_ordinal(word) {
+            idx += 2;
+            continue;
+        }
+        // "with Radeon Graphics" and everything after describes the iGPU.
+        if word == "with" {
+            break;
+        }
+        if word == "cpu" || word == "processor" || is_core_count(word) {
+            idx += 1;
+            continue;
+        }
+        kept.push(word);
+        idx += 1;
+    }
+    kept.join(" ")
+}
+
+/// Normalise a GPU model string across detection paths
+/// ("NVIDIA Corporation AD104 [GeForce RTX 4070 SUPER] (rev a1)" → "geforce rtx 4070 super").
+pub fn normalize_gpu_model(name: &str) -> String {
+    let mut model = name.to_string();
+
+    // lspci: "Vendor Corp Chip [Marketing Name] (rev xx)" — the last bracket is the product.
+    if let Some(bracketed) = last_bracketed(&model) {
+        model = bracketed;
+    }
+    if let Some(start) = model.to_lowercase().find("(rev ") {
+        model.truncate(start);
+    }
+
+    let cleaned = strip_trademarks(&model);
+    const VENDOR_WORDS: [&str; 9] = [
+        "nvidia",
+        "amd",
+        "ati",
+        "amd/ati",
+        "intel",
+        "corporation",
+        "advanced",
+        "micro",
+        "devices,",
+    ];
+    cleaned
+        .split_whitespace()
+        .filter(|word| !VENDOR_WORDS.contains(word) && *word != "inc." && *word != "inc")
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn last_bracketed(value: &str) -> Option<String> {
+    let mut found = None;
+    let mut rest = value;
+    while let Some(open) = rest.find('[') {
+        let after = &rest[open + 1..];
+        let Some(close) = after.find(']') else {
+            break;
+        };
+        let inner = after[..close].trim();
+        if !inner.is_empty() && !inner.eq_ignore_ascii_case("AMD/ATI") {
+            found = Some(inner.to_string());
+        }
+        rest = &after[close + 1..];
+    }
+    found
+}
+
+fn strip_trademarks(value: &str) -> String {
+    let mut lowered = value.to_lowercase();
+    for mark in ["(r)", "(tm)", "®", "™"] {
+        lowered = lowered.replace(mark, " ");
+    }
+    lowered
+}
+
+fn is_ordinal(word: &str) -> bool {
+    let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    !digits.is_empty()
+        && digits.len() < word.len()
+        && digits.chars().all(|c| c.is_ascii_digit())
+        && matches!(&word[digits.len()..], "st" | "nd" | "rd" | "th")
+}
+
+/// "8-core", "16-core" and similar counts appended by AMD.
+fn is_core_count(word: &str) -> bool {
+    word.strip_suffix("-core")
+        .is_some_and(|count| !count.is_empty() && count.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Round usable memory up to the nearest standard installed size.
+fn bucket_ram_gb(ram_mb: u64) -> u32 {
+    let gb = ram_mb as f64 / 1024.0;
+    RAM_BUCKETS_GB
+        .iter()
+        .copied()
+        .find(|bucket| gb <= *bucket as f64 + 0.5)
+        .unwrap_or_else(|| ((gb / 64.0).ceil() as u32).max(1) * 64)
+}
+
+/// Round VRAM to whole gigabytes; anything under 512 MB is an aperture, not real VRAM.
+fn bucket_vram_gb(vram_mb: u64) -> Option<u32> {
+    if vram_mb < 512 {
+        return None;
+    }
+    Some(((vram_mb as f64 / 1024.0).round() as u32).max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::cpu::CpuInfo;
+    use crate::hardware::gpu::GpuInfo;
+    use crate::hardware::ram::RamInfo;
+
+    fn system_info(cpu: &str, gpu: &str, vram_mb: Option<u64>, usable_mb: u64) -> SystemInfo {
+        SystemInfo {
+            gpu: GpuInfo {
+                name: gpu.to_string(),
+                vendor: GpuVendor::Nvidia,
+                pci_id: None,
+                vram_mb,
+                gpu_clock_mhz: None,
+                memory_clock_mhz: None,
+                temperature_c: None,
+                utilization_percent: None,
+                power_w: None,
+                driver_version: None,
+            },
+            cpu: CpuInfo {
+                name: cpu.to_string(),
+                cores: 12,
+                threads: 20,
+                frequency_mhz: None,
+                max_frequency_mhz: None,
+                topology: None,
+                architecture: None,
+                vendor: "Intel".to_string(),
+            },
+            ram: RamInfo {
+                installed_mb: None,
+                usable_mb,
+                speed_mhz: None,
+                ram_type: None,
+                stick_count: None,
+                model: None,
+            },
+            os: "Linux".to_string(),
+            os_version: None,
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn cpu_names_from_different_paths_normalise_to_one_model() {
+        for name in [
+            "12th Gen Intel(R) Core(TM) i7-12700K",
+            "Intel(R) Core(TM) i7-12700K CPU @ 3.60GHz",
+            "intel core i7-12700k",
+        ] {
+            assert_eq!(normalize_cpu_model(name), "intel core i7-12700k", "{name}");
+        }
+        assert_eq!(
+            normalize_cpu_model("AMD Ryzen 7 5800X 8-Core Processor"),
+            "amd ryzen 7 5800x"
+        );
+        assert_eq!(
+            normalize_cpu_model("AMD Ryzen 7 7840HS w/ Radeon 780M Graphics"),
+            "amd ryzen 7 7840hs w/ radeon 780m graphics"
+        );
+        assert_eq!(
+            normalize_cpu_model("AMD Ryzen 5 5600G with Radeon Graphics"),
+            "amd ryzen 5 5600g"
+        );
+    }
+
+    #[test]
+    fn gpu_names_from_different_paths_normalise_to_one_model() {
+        for name in [
+            "NVIDIA GeForce RTX 4070 SUPER",
+            "NVIDIA Corporation AD104 [GeForce RTX 4070 SUPER] (rev a1)",
+            "GeForce RTX 4070 SUPER",
+        ] {
+            assert_eq!(
+                normalize_gpu_model(name),
+                "geforce rtx 4070 super",
+                "{name}"
+            );
+        }
+        for name in [
+            "Advanced Micro Devices, Inc. [AMD/ATI] Navi 32 [Radeon RX 7800 XT] (rev c8)",
+            "AMD Radeon RX 7800 XT",
+        ] {
+            assert_eq!(normalize_gpu_model(name), "radeon rx 7800 xt", "{name}");
+        }
+        assert_eq!(
+            normalize_gpu_model("Intel(R) Arc(TM) A770 Graphics"),
+            "arc a770 graphics"
+        );
+    }
+
+    #[test]
+    fn fingerprint_is_stable_across_detection_paths_and_changes_with_hardware() {
+        let smi = system_info(
+            "12th Gen Intel(R) Core(TM) i7-12700K",
+            "NVIDIA GeForce RTX 4070 SUPER",
+            Some(12282),
+            31_890,
+        );
+        let lspci = system_info(
+            "Intel(R) Core(TM) i7-12700K CPU @ 3.60GHz",
+            "NVIDIA Corporation AD104 [GeForce RTX 4070 SUPER] (rev a1)",
+            Some(12288),
+            32_768,
+        );
+
+        let a = HardwareIdentity::from_system_info(&smi);
+        let b = HardwareIdentity::from_system_info(&lspci);
+        assert_eq!(a, b);
+        assert_eq!(a.ram_gb, 32);
+        assert_eq!(a.vram_gb, Some(12));
+
+        let fingerprint = a.fingerprint();
+        assert_eq!(fingerprint, b.fingerprint());
+        assert!(fingerprint.starts_with("hw2_"));
+        assert_eq!(fingerprint.len(), 4 + 32);
+        assert!(fingerprint[4..].chars().all(|c| c.is_ascii_hexdigit()));
+
+        let upgraded = system_info(
+            "Intel(R) Core(TM) i7-12700K CPU @ 3.60GHz",
+            "NVIDIA GeForce RTX 4080 SUPER",
+            Some(16376),
+            32_768,
+        );
+        assert_ne!(
+            HardwareIdentity::from_system_info(&upgraded).fingerprint(),
+            fingerprint
+        );
+
+        // Another install's salt yields an unrelated fingerprint for the same parts.
+        assert_ne!(a.fingerprint_with_salt(&"f".repeat(64)), fingerprint);
+    }
+
+    #[test]
+    fn memory_is_bucketed_to_standard_sizes() {
+        assert_eq!(bucket_ram_gb(15_600), 16);
+        assert_eq!(bucket_ram_gb(16_384), 16);
+        assert_eq!(bucket_ram_gb(30_000), 32);
+        assert_eq!(bucket_ram_gb(190_000), 192);
+        assert_eq!(bucket_vram_gb(256), None);
+        assert_eq!(bucket_vram_gb(8_176), Some(8));
+    }
+}
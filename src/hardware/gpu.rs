@@ -4,12 +4,17 @@
 //! - Linux: Parse /sys/class/drm and lspci output
 //! - Windows: WMI, DirectX, or dxdiag
 //! - NVIDIA: Can use nvidia-smi if available (cross-platform)
-//! - AMD: Parse amdgpu sysfs (Linux) or WMI (Windows)
-//! - Intel: Parse i915 sysfs (Linux) or WMI (Windows)
+//! - AMD: Parse amdgpu sysfs/hwmon (Linux) or WMI (Windows)
+//! - Intel: Parse i915/xe sysfs/hwmon (Linux) or WMI (Windows)
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::process::Command;
+
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 #[cfg(any(target_os = "linux", target_os = "windows"))]
 use std::fs;
@@ -17,6 +22,8 @@ use std::fs;
 #[cfg(target_os = "linux")]
 use std::path::Path;
 
+use super::probe::Probe;
+
 /// GPU vendor
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum GpuVendor {
@@ -58,40 +65,134 @@ pub struct GpuInfo {
     pub temperature_c: Option<u64>,
     /// GPU utilization percentage (if detectable)
     pub utilization_percent: Option<u64>,
+    /// Board power draw in watts (if detectable)
+    #[serde(default)]
+    pub power_w: Option<f64>,
+}
+
+/// Live readings from a DRM card's sysfs/hwmon attributes (amdgpu, i915, xe)
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SysfsGpuStats {
+    pub vram_mb: Option<u64>,
+    pub gpu_clock_mhz: Option<u64>,
+    pub memory_clock_mhz: Option<u64>,
+    pub utilization_percent: Option<u64>,
+    pub temperature_c: Option<u64>,
+    pub power_w: Option<f64>,
+}
+
+#[cfg(target_os = "linux")]
+impl SysfsGpuStats {
+    /// Read stats for `<sys_root>/class/drm/<card>` (e.g. card = "card1")
+    pub fn read(card_path: &Path) -> Self {
+        let device = card_path.join("device");
+        let read = |path: &Path| {
+            fs::read_to_string(path)
+                .ok()
+                .map(|value| value.trim().to_string())
+        };
+        let read_u64 = |path: &Path| read(path).and_then(|value| value.parse::<u64>().ok());
+
+        let vram_mb = read_u64(&device.join("mem_info_vram_total"))
+            .map(|bytes| bytes / 1024 / 1024)
+            .filter(|mb| *mb > 0);
+
+        // amdgpu exposes DPM tables with the active level marked "*"; i915 and
+        // xe expose the current frequency directly.
+        let gpu_clock_mhz = read(&device.join("pp_dpm_sclk"))
+            .and_then(|table| parse_dpm_current_mhz(&table))
+            .or_else(|| read_u64(&card_path.join("gt_act_freq_mhz")).filter(|mhz| *mhz > 0))
+            .or_else(|| read_u64(&card_path.join("gt_cur_freq_mhz")))
+            .or_else(|| read_u64(&device.join("tile0/gt0/freq0/act_freq")).filter(|mhz| *mhz > 0))
+            .or_else(|| read_u64(&device.join("tile0/gt0/freq0/cur_freq")));
+        let memory_clock_mhz =
+            read(&device.join("pp_dpm_mclk")).and_then(|table| parse_dpm_current_mhz(&table));
+        let utilization_percent =
+            read_u64(&device.join("gpu_busy_percent")).filter(|pct| *pct <= 100);
+
+        let mut temperature_c = None;
+        let mut power_w = None;
+        if let Ok(entries) = fs::read_dir(device.join("hwmon")) {
+            let mut hwmons: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+            hwmons.sort();
+            for hwmon in hwmons {
+                // Millidegrees Celsius; temp1 is "edge" on amdgpu and "pkg" on xe.
+                temperature_c = temperature_c
+                    .or_else(|| read_u64(&hwmon.join("temp1_input")).map(|milli_c| milli_c / 1000));
+                // Microwatts; older amdgpu only has power1_average.
+                power_w = power_w.or_else(|| {
+                    read_u64(&hwmon.join("power1_average"))
+                        .or_else(|| read_u64(&hwmon.join("power1_input")))
+                        .map(|micro_w| micro_w as f64 / 1_000_000.0)
+                });
+            }
+        }
+
+        SysfsGpuStats {
+            vram_mb,
+            gpu_clock_mhz,
+            memory_clock_mhz,
+            utilization_percent,
+            temperature_c,
+            power_w,
+        }
+    }
+}
+
+/// Parse the active level of an amdgpu `pp_dpm_*` table ("1: 1200Mhz *")
+#[cfg(target_os = "linux")]
+fn parse_dpm_current_mhz(table: &str) -> Option<u64> {
+    table
+        .lines()
+        .find(|line| line.trim_end().ends_with('*'))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|clock| {
+            clock
+                .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+                .parse()
+                .ok()
+        })
 }
 
 impl GpuInfo {
     /// Detect primary GPU (platform-specific)
-    pub fn detect() -> Result<Self> {
+    pub fn detect(probe: &Probe) -> Result<Self> {
         // Try multiple detection methods in order of reliability
 
         // Method 1: Try nvidia-smi for NVIDIA GPUs (cross-platform)
-        if let Ok(gpu) = Self::detect_nvidia_smi() {
+        if let Ok(gpu) = Self::detect_nvidia_smi(probe) {
             return Ok(gpu);
         }
 
         #[cfg(target_os = "linux")]
         {
-            // Method 2: Parse lspci output (Linux)
-            if let Ok(gpu) = Self::detect_lspci() {
+            let sys_root = probe.path("/sys");
+
+            // Method 2: Parse lspci output, then fill live stats from sysfs (Linux)
+            if let Ok(mut gpu) = Self::detect_lspci(probe) {
+                let slot = gpu.pci_id.clone();
+                gpu.apply_sysfs_stats(&sys_root, slot.as_deref());
                 return Ok(gpu);
             }
 
             // Method 3: Parse /sys/class/drm (Linux)
-            if let Ok(gpu) = Self::detect_sysfs() {
+            if let Ok(gpu) = Self::detect_sysfs(&sys_root) {
                 return Ok(gpu);
             }
         }
 
         #[cfg(target_os = "windows")]
         {
-            // Method 2: Use dxdiag on Windows
-            if let Ok(gpu) = Self::detect_dxdiag() {
-                return Ok(gpu);
+            // Method 2: Use dxdiag on Windows (its report file cannot be replayed)
+            if probe.is_live() {
+                if let Ok(gpu) = Self::detect_dxdiag() {
+                    return Ok(gpu);
+                }
             }
 
             // Method 3: Use WMI on Windows
-            if let Ok(gpu) = Self::detect_wmi() {
+            if let Ok(gpu) = Self::detect_wmi(probe) {
                 return Ok(gpu);
             }
         }
@@ -107,25 +208,23 @@ impl GpuInfo {
             memory_clock_mhz: None,
             temperature_c: None,
             utilization_percent: None,
+            power_w: None,
         })
     }
 
     /// Detect NVIDIA GPU using nvidia-smi (cross-platform)
-    fn detect_nvidia_smi() -> Result<Self> {
+    fn detect_nvidia_smi(probe: &Probe) -> Result<Self> {
         // Query basic info
-        let output = Command::new("nvidia-smi")
-            .args([
-                "--query-gpu=name,memory.total,driver_version",
-                "--format=csv,noheader,nounits",
-            ])
-            .output()
-            .context("nvidia-smi not found")?;
-
-        if !output.status.success() {
-            anyhow::bail!("nvidia-smi failed");
-        }
+        let stdout = probe
+            .run(
+                "nvidia-smi",
+                &[
+                    "--query-gpu=name,memory.total,driver_version",
+                    "--format=csv,noheader,nounits",
+                ],
+            )
+            .context("nvidia-smi not found or failed")?;
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
         let line = stdout.lines().next().context("No GPU found")?;
         let parts: Vec<&str> = line.split(", ").collect();
 
@@ -143,7 +242,7 @@ impl GpuInfo {
         let driver_version = parts[2].trim().to_string();
 
         // Try to get additional info (clocks, temp, utilization)
-        let (gpu_clock, memory_clock, temp, utilization) = Self::get_nvidia_smi_extra();
+        let (gpu_clock, memory_clock, temp, utilization) = Self::get_nvidia_smi_extra(probe);
 
         Ok(GpuInfo {
             name,
@@ -155,36 +254,30 @@ impl GpuInfo {
             memory_clock_mhz: memory_clock,
             temperature_c: temp,
             utilization_percent: utilization,
+            power_w: None,
         })
     }
 
     /// Get additional NVIDIA GPU info (clocks, temp, utilization)
-    fn get_nvidia_smi_extra() -> (Option<u64>, Option<u64>, Option<u64>, Option<u64>) {
+    fn get_nvidia_smi_extra(probe: &Probe) -> (Option<u64>, Option<u64>, Option<u64>, Option<u64>) {
         let mut gpu_clock = None;
         let mut memory_clock = None;
         let mut temp = None;
         let mut utilization = None;
 
         // Query clocks and temperature
-        if let Ok(output) = Command::new("nvidia-smi")
-            .args([
+        if let Some(stdout) = probe.run(
+            "nvidia-smi",
+            &[
                 "--query-gpu=clocks.gr,clocks.mem,temperature.gpu,utilization.gpu",
                 "--format=csv,noheader,nounits",
-            ])
-            .output()
-        {
-            if output.status.success() {
-                let stdout = String::from_utf8_lossy(&output.stdout);
-                if let Some(line) = stdout.lines().next() {
-                    let parts: Vec<&str> = line.split(", ").collect();
-                    if parts.len() >= 4 {
-                        // Parse clock speeds (remove " MHz" suffix if present)
-                        gpu_clock = parts[0].trim().replace(" MHz", "").parse().ok();
-                        memory_clock = parts[1].trim().replace(" MHz", "").parse().ok();
-                        temp = parts[2].trim().replace(" C", "").parse().ok();
-                        utilization = parts[3].trim().replace(" %", "").parse().ok();
-                    }
-                }
+            ],
+        ) {
+            if let Some(stats) = stdout.lines().next().and_then(NvidiaSmiStats::parse) {
+                gpu_clock = stats.gpu_clock_mhz;
+                memory_clock = stats.memory_clock_mhz;
+                temp = stats.temperature_c;
+                utilization = stats.utilization_percent;
             }
         }
 
@@ -193,19 +286,10 @@ impl GpuInfo {
 
     /// Detect GPU using lspci (Linux only)
     #[cfg(target_os = "linux")]
-    fn detect_lspci() -> Result<Self> {
-        let _output = Command::new("lspci")
-            .args(["-v", "-s", "0000:00:02.0"]) // Try integrated first
-            .output();
-
-        // Try to find discrete GPU
-        let output = Command::new("lspci").output().context("lspci not found")?;
-
-        if !output.status.success() {
-            anyhow::bail!("lspci failed");
-        }
-
-        let stdout = String::from_utf8_lossy(&output.stdout);
+    fn detect_lspci(probe: &Probe) -> Result<Self> {
+        let stdout = probe
+            .run("lspci", &[])
+            .context("lspci not found or failed")?;
 
         // Look for VGA or 3D controller
         for line in stdout.lines() {
@@ -221,6 +305,7 @@ impl GpuInfo {
                     memory_clock_mhz: None,
                     temperature_c: None,
                     utilization_percent: None,
+                    power_w: None,
                 });
             }
         }
@@ -258,64 +343,138 @@ impl GpuInfo {
         (vendor, name)
     }
 
-    /// Detect GPU using sysfs (Linux only)
+    /// Detect GPU using sysfs rooted at `sys_root` (normally "/sys", Linux only)
     #[cfg(target_os = "linux")]
-    fn detect_sysfs() -> Result<Self> {
-        let drm_path = Path::new("/sys/class/drm");
+    fn detect_sysfs(sys_root: &Path) -> Result<Self> {
+        let drm_path = sys_root.join("class/drm");
         if !drm_path.exists() {
-            anyhow::bail!("/sys/class/drm not found");
+            anyhow::bail!("{} not found", drm_path.display());
         }
 
-        // Look for card0, card1, etc.
-        for entry in fs::read_dir(drm_path)? {
-            let entry = entry?;
-            let name = entry.file_name();
-            let name_str = name.to_string_lossy();
-
-            if name_str.starts_with("card") && !name_str.contains('-') {
-                let device_path = entry.path().join("device");
-
-                // Try to read vendor
-                let vendor_path = device_path.join("vendor");
-                if let Ok(vendor_id) = fs::read_to_string(&vendor_path) {
-                    let vendor_id = vendor_id.trim();
-                    let vendor = match vendor_id {
-                        "0x10de" => GpuVendor::Nvidia,
-                        "0x1002" => GpuVendor::Amd,
-                        "0x8086" => GpuVendor::Intel,
-                        _ => GpuVendor::Unknown,
-                    };
-
-                    // Try to get device name
-                    let uevent_path = device_path.join("uevent");
-                    let gpu_name = if let Ok(uevent) = fs::read_to_string(&uevent_path) {
-                        uevent
-                            .lines()
-                            .find(|l| l.starts_with("PCI_ID="))
-                            .map(|l| l.replace("PCI_ID=", ""))
-                            .unwrap_or_else(|| format!("{} GPU", vendor))
-                    } else {
-                        format!("{} GPU", vendor)
-                    };
-
-                    return Ok(GpuInfo {
-                        name: gpu_name,
-                        vendor,
-                        vram_mb: None,
-                        driver_version: None,
-                        pci_id: None,
-                        gpu_clock_mhz: None,
-                        memory_clock_mhz: None,
-                        temperature_c: None,
-                        utilization_percent: None,
-                    });
-                }
+        // Look for card0, card1, etc. (connectors such as card0-DP-1 are skipped)
+        for card_path in Self::drm_cards(&drm_path)? {
+            let device_path = card_path.join("device");
+
+            // Try to read vendor
+            let vendor_path = device_path.join("vendor");
+            if let Ok(vendor_id) = fs::read_to_string(&vendor_path) {
+                let vendor_id = vendor_id.trim();
+                let vendor = match vendor_id {
+                    "0x10de" => GpuVendor::Nvidia,
+                    "0x1002" => GpuVendor::Amd,
+                    "0x8086" => GpuVendor::Intel,
+                    _ => GpuVendor::Unknown,
+                };
+
+                // Try to get device name
+                let uevent = fs::read_to_string(device_path.join("uevent")).unwrap_or_default();
+                let pci_id = uevent
+                    .lines()
+                    .find_map(|l| l.strip_prefix("PCI_ID="))
+                    .map(|id| id.trim().to_lowercase());
+                let gpu_name = pci_id
+                    .as_ref()
+                    .map(|id| format!("{} GPU [{}]", vendor, id))
+                    .unwrap_or_else(|| format!("{} GPU", vendor));
+
+                let stats = SysfsGpuStats::read(&card_path);
+                return Ok(GpuInfo {
+                    name: gpu_name,
+                    vendor,
+                    vram_mb: stats.vram_mb,
+                    driver_version: None,
+                    pci_id,
+                    gpu_clock_mhz: stats.gpu_clock_mhz,
+                    memory_clock_mhz: stats.memory_clock_mhz,
+                    temperature_c: stats.temperature_c,
+                    utilization_percent: stats.utilization_percent,
+                    power_w: stats.power_w,
+                });
             }
         }
 
         anyhow::bail!("No GPU found in sysfs")
     }
 
+    /// Fill missing VRAM/clock/temperature/utilisation/power from the sysfs card
+    /// at `pci_slot` ("0000:03:00.0" or lspci's short "03:00.0"); without a slot
+    /// the first card from the same vendor is used. NVIDIA's proprietary driver
+    /// exposes none of these, so it is left to nvidia-smi.
+    #[cfg(target_os = "linux")]
+    pub fn apply_sysfs_stats(&mut self, sys_root: &Path, pci_slot: Option<&str>) {
+        if self.vendor == GpuVendor::Nvidia {
+            return;
+        }
+        let Ok(cards) = Self::drm_cards(&sys_root.join("class/drm")) else {
+            return;
+        };
+
+        let vendor_id = match self.vendor {
+            GpuVendor::Amd => "0x1002",
+            GpuVendor::Intel => "0x8086",
+            _ => "",
+        };
+        let pci_slot = pci_slot.map(str::to_lowercase);
+        let card = cards.into_iter().find(|card| {
+            let device = card.join("device");
+            match pci_slot.as_deref() {
+                Some(slot) => fs::read_to_string(device.join("uevent"))
+                    .unwrap_or_default()
+                    .lines()
+                    .filter_map(|l| l.strip_prefix("PCI_SLOT_NAME="))
+                    .any(|name| name.trim().to_lowercase().ends_with(slot)),
+                None => fs::read_to_string(device.join("vendor"))
+                    .is_ok_and(|vendor| vendor.trim() == vendor_id),
+            }
+        });
+        let Some(card) = card else {
+            return;
+        };
+
+        let stats = SysfsGpuStats::read(&card);
+        self.vram_mb = self.vram_mb.or(stats.vram_mb);
+        self.gpu_clock_mhz = self.gpu_clock_mhz.or(stats.gpu_clock_mhz);
+        self.memory_clock_mhz = self.memory_clock_mhz.or(stats.memory_clock_mhz);
+        self.temperature_c = self.temperature_c.or(stats.temperature_c);
+        self.utilization_percent = self.utilization_percent.or(stats.utilization_percent);
+        self.power_w = self.power_w.or(stats.power_w);
+    }
+
+    /// Re-read the live clock, temperature, utilisation and power readings
+    /// from sysfs, e.g. when sampling the render GPU during a capture. NVIDIA
+    /// cards are sampled through an [`NvidiaSmiMonitor`] instead.
+    pub fn refresh_live_stats(&mut self, probe: &Probe, pci_slot: Option<&str>) {
+        self.gpu_clock_mhz = None;
+        self.memory_clock_mhz = None;
+        self.temperature_c = None;
+        self.utilization_percent = None;
+        self.power_w = None;
+
+        #[cfg(target_os = "linux")]
+        self.apply_sysfs_stats(&probe.path("/sys"), pci_slot);
+        #[cfg(not(target_os = "linux"))]
+        let _ = (probe, pci_slot);
+    }
+
+    /// `cardN` directories under a DRM class path, in index order
+    #[cfg(target_os = "linux")]
+    fn drm_cards(drm_path: &Path) -> Result<Vec<std::path::PathBuf>> {
+        let mut cards: Vec<(u32, std::path::PathBuf)> = fs::read_dir(drm_path)?
+            .flatten()
+            .filter_map(|entry| {
+                let index = entry
+                    .file_name()
+                    .to_string_lossy()
+                    .strip_prefix("card")?
+                    .parse::<u32>()
+                    .ok()?;
+                Some((index, entry.path()))
+            })
+            .collect();
+        cards.sort_by_key(|(index, _)| *index);
+        Ok(cards.into_iter().map(|(_, path)| path).collect())
+    }
+
     /// Detect GPU using dxdiag on Windows
     #[cfg(target_os = "windows")]
     fn detect_dxdiag() -> Result<Self> {
@@ -404,6 +563,7 @@ impl GpuInfo {
             memory_clock_mhz: None,
             temperature_c: None,
             utilization_percent: None,
+            power_w: None,
         })
     }
 
@@ -433,24 +593,20 @@ impl GpuInfo {
 
     /// Detect GPU using WMI on Windows
     #[cfg(target_os = "windows")]
-    fn detect_wmi() -> Result<Self> {
+    fn detect_wmi(probe: &Probe) -> Result<Self> {
         // Use wmic to query video controller
-        let output = Command::new("wmic")
-            .args([
-                "path",
-                "win32_VideoController",
-                "get",
-                "Name,AdapterRAM,DriverVersion",
-                "/format:csv",
-            ])
-            .output()
-            .context("wmic not found")?;
-
-        if !output.status.success() {
-            anyhow::bail!("wmic failed");
-        }
-
-        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stdout = probe
+            .run(
+                "wmic",
+                &[
+                    "path",
+                    "win32_VideoController",
+                    "get",
+                    "Name,AdapterRAM,DriverVersion",
+                    "/format:csv",
+                ],
+            )
+            .context("wmic not found or failed")?;
 
         // Parse CSV output
         for line in stdout.lines().skip(1) {
@@ -490,6 +646,7 @@ impl GpuInfo {
                         memory_clock_mhz: None,
                         temperature_c: None,
                         utilization_percent: None,
+                        power_w: None,
                     });
                 }
             }
@@ -498,3 +655,262 @@ impl GpuInfo {
         anyhow::bail!("No GPU found in WMI output")
     }
 }
+
+/// One `nvidia-smi --query-gpu=clocks.gr,clocks.mem,temperature.gpu,utilization.gpu` row
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NvidiaSmiStats {
+    pub gpu_clock_mhz: Option<u64>,
+    pub memory_clock_mhz: Option<u64>,
+    pub temperature_c: Option<u64>,
+    pub utilization_percent: Option<u64>,
+}
+
+impl NvidiaSmiStats {
+    /// Parse one CSV row; columns past the fourth are ignored
+    fn parse(line: &str) -> Option<Self> {
+        let parts: Vec<&str> = line.split(',').map(str::trim).collect();
+        if parts.len() < 4 {
+            return None;
+        }
+        // Units are present unless `nounits` was requested.
+        let value = |part: &str| {
+            part.trim_end_matches(" MHz")
+                .trim_end_matches(" C")
+                .trim_end_matches(" %")
+                .parse()
+                .ok()
+        };
+        Some(Self {
+            gpu_clock_mhz: value(parts[0]),
+            memory_clock_mhz: value(parts[1]),
+            temperature_c: value(parts[2]),
+            utilization_percent: value(parts[3]),
+        })
+    }
+}
+
+/// Streams the clocks, temperature and utilisation of one NVIDIA GPU from a
+/// single `nvidia-smi -lms <interval>` process kept alive for the whole
+/// capture, rather than spawning nvidia-smi for every sample.
+pub struct NvidiaSmiMonitor {
+    child: Child,
+    latest: Arc<Mutex<Option<NvidiaSmiStats>>>,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl NvidiaSmiMonitor {
+    /// Start monitoring the GPU at `pci_slot` (e.g. "0000:01:00.0"). Without a
+    /// slot, rows are matched on the `vendor:device` PCI id instead.
+    pub fn start(pci_slot: Option<&str>, pci_id: Option<&str>, interval: Duration) -> Option<Self> {
+        let interval_ms = interval.as_millis().max(100).to_string();
+        let mut command = Command::new("nvidia-smi");
+        command.args([
+            "--query-gpu=clocks.gr,clocks.mem,temperature.gpu,utilization.gpu,pci.device_id",
+            "--format=csv,noheader,nounits",
+            "-lms",
+            &interval_ms,
+        ]);
+        if let Some(slot) = pci_slot {
+            command.args(["-i", slot]);
+        }
+        // nvidia-smi prints the PCI id as 0xDDDDVVVV.
+        let wanted_device_id = match (pci_slot, pci_id.and_then(|id| id.split_once(':'))) {
+            (None, Some((vendor, device))) => Some(format!("0x{device}{vendor}")),
+            _ => None,
+        };
+
+        let mut child = command
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .ok()?;
+        let Some(stdout) = child.stdout.take() else {
+            let _ = child.kill();
+            let _ = child.wait();
+            return None;
+        };
+        let latest = Arc::new(Mutex::new(None));
+        let reader_latest = Arc::clone(&latest);
+        let reader = thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if !is_wanted_device(&line, wanted_device_id.as_deref()) {
+                    continue;
+                }
+                if let Some(stats) = NvidiaSmiStats::parse(&line) {
+                    if let Ok(mut latest) = reader_latest.lock() {
+                        *latest = Some(stats);
+                    }
+                }
+            }
+        });
+
+        Some(Self {
+            child,
+            latest,
+            reader: Some(reader),
+        })
+    }
+
+    /// Most recent reading, if nvidia-smi has printed one yet
+    pub fn latest(&self) -> Option<NvidiaSmiStats> {
+        self.latest.lock().ok()?.clone()
+    }
+}
+
+impl Drop for NvidiaSmiMonitor {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+    }
+}
+
+/// Whether a monitor row belongs to the wanted `0xDDDDVVVV` device id
+fn is_wanted_device(line: &str, wanted_device_id: Option<&str>) -> bool {
+    wanted_device_id.is_none_or(|wanted| {
+        line.rsplit(',')
+            .next()
+            .is_some_and(|id| id.trim().eq_ignore_ascii_case(wanted))
+    })
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn amdgpu_fixture(root: &Path) {
+        let device = "class/drm/card1/device";
+        write(root, &format!("{device}/vendor"), "0x1002\n");
+        write(
+            root,
+            &format!("{device}/uevent"),
+            "DRIVER=amdgpu\nPCI_ID=1002:747E\nPCI_SLOT_NAME=0000:03:00.0\n",
+        );
+        write(
+            root,
+            &format!("{device}/mem_info_vram_total"),
+            "17163091968\n",
+        );
+        write(
+            root,
+            &format!("{device}/pp_dpm_sclk"),
+            "0: 500Mhz\n1: 2124Mhz *\n2: 2565Mhz\n",
+        );
+        write(
+            root,
+            &format!("{device}/pp_dpm_mclk"),
+            "0: 96Mhz\n1: 456Mhz\n2: 1249Mhz *\n",
+        );
+        write(root, &format!("{device}/gpu_busy_percent"), "97\n");
+        write(
+            root,
+            &format!("{device}/hwmon/hwmon4/temp1_input"),
+            "61000\n",
+        );
+        write(
+            root,
+            &format!("{device}/hwmon/hwmon4/power1_average"),
+            "241000000\n",
+        );
+        write(root, "class/drm/card1-DP-1/status", "connected\n");
+    }
+
+    #[test]
+    fn test_sysfs_reads_amdgpu_clocks_busy_and_hwmon() {
+        let temp_dir = TempDir::new().unwrap();
+        amdgpu_fixture(temp_dir.path());
+
+        let gpu = GpuInfo::detect_sysfs(temp_dir.path()).unwrap();
+        assert_eq!(gpu.vendor, GpuVendor::Amd);
+        assert_eq!(gpu.pci_id.as_deref(), Some("1002:747e"));
+        assert_eq!(gpu.vram_mb, Some(16368));
+        assert_eq!(gpu.gpu_clock_mhz, Some(2124));
+        assert_eq!(gpu.memory_clock_mhz, Some(1249));
+        assert_eq!(gpu.utilization_percent, Some(97));
+        assert_eq!(gpu.temperature_c, Some(61));
+        assert_eq!(gpu.power_w, Some(241.0));
+    }
+
+    #[test]
+    fn test_sysfs_reads_intel_frequency_and_fills_lspci_result_by_slot() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        amdgpu_fixture(root);
+        write(root, "class/drm/card0/device/vendor", "0x8086\n");
+        write(
+            root,
+            "class/drm/card0/device/uevent",
+            "DRIVER=i915\nPCI_ID=8086:A780\nPCI_SLOT_NAME=0000:00:02.0\n",
+        );
+        write(root, "class/drm/card0/gt_act_freq_mhz", "0\n");
+        write(root, "class/drm/card0/gt_cur_freq_mhz", "1450\n");
+        write(
+            root,
+            "class/drm/card0/device/hwmon/hwmon2/power1_input",
+            "0\n",
+        );
+
+        let intel = SysfsGpuStats::read(&root.join("class/drm/card0"));
+        assert_eq!(intel.gpu_clock_mhz, Some(1450), "idle act_freq falls back");
+        assert_eq!(intel.vram_mb, None);
+        assert_eq!(intel.temperature_c, None);
+
+        // lspci reports the short slot; stats must come from the AMD card, not card0.
+        let mut gpu = GpuInfo {
+            name: "Navi 32 [Radeon RX 7800 XT]".to_string(),
+            vendor: GpuVendor::Amd,
+            vram_mb: None,
+            driver_version: None,
+            pci_id: Some("03:00.0".to_string()),
+            gpu_clock_mhz: None,
+            memory_clock_mhz: None,
+            temperature_c: Some(55),
+            utilization_percent: None,
+            power_w: None,
+        };
+        gpu.apply_sysfs_stats(root, Some("03:00.0"));
+        assert_eq!(gpu.gpu_clock_mhz, Some(2124));
+        assert_eq!(gpu.temperature_c, Some(55), "existing readings are kept");
+        assert_eq!(gpu.power_w, Some(241.0));
+    }
+
+    #[test]
+    fn test_parse_dpm_current_mhz() {
+        assert_eq!(parse_dpm_current_mhz("0: 500Mhz\n1: 800Mhz *\n"), Some(800));
+        assert_eq!(parse_dpm_current_mhz("0: 500Mhz\n1: 800Mhz\n"), None);
+    }
+
+    #[test]
+    fn test_nvidia_smi_monitor_rows() {
+        let row = "2610, 10501, 64, 97, 0x278310DE";
+        assert_eq!(
+            NvidiaSmiStats::parse(row),
+            Some(NvidiaSmiStats {
+                gpu_clock_mhz: Some(2610),
+                memory_clock_mhz: Some(10501),
+                temperature_c: Some(64),
+                utilization_percent: Some(97),
+            })
+        );
+        assert_eq!(
+            NvidiaSmiStats::parse("210 MHz, 405 MHz, 38 C, 0 %")
+                .and_then(|stats| stats.gpu_clock_mhz),
+            Some(210)
+        );
+        assert!(is_wanted_device(row, Some("0x278310de")));
+        assert!(!is_wanted_device(
+            "300, 405, 30, 0, 0x1C8210DE",
+            Some("0x278310de")
+        ));
+        assert!(is_wanted_device(row, None));
+    }
+}
@@ -0,0 +1,499 @@
+//! Linux graphics stack detection
+//!
+//! On Linux the GPU driver version alone says little: AMD and Intel run on
+//! Mesa, and games often go through Proton/Wine with DXVK or VKD3D-Proton.
+//! This module collects those versions plus the display server, from:
+//! - `vulkaninfo --summary` (Vulkan driver, driver/API version, Mesa build)
+//! - `glxinfo -B` (OpenGL renderer, Mesa fallback)
+//! - `/proc/version` (kernel fallback)
+//! - Session env vars (X11/Wayland, compositor, Proton/Wine paths)
+//! - Proton logs (`PROTON_LOG=1`) and MangoHud log headers
+
+use serde::{Deserialize, Serialize};
+
+use super::probe::Probe;
+
+/// A Proton log older than this is assumed to belong to an earlier session.
+#[cfg(target_os = "linux")]
+const PROTON_LOG_MAX_AGE_SECS: u64 = 6 * 60 * 60;
+/// Proton, DXVK and VKD3D-Proton announce themselves near the top of the log.
+#[cfg(target_os = "linux")]
+const PROTON_LOG_HEADER_LINES: usize = 500;
+/// Variables Steam sets to the running game's app id.
+#[cfg(target_os = "linux")]
+const STEAM_APP_ID_VARS: &[&str] = &["SteamAppId", "SteamGameId", "STEAM_COMPAT_APP_ID"];
+
+/// Graphics driver, display server and compatibility layer versions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GraphicsStack {
+    /// Mesa version (e.g. "24.0.5-1ubuntu1")
+    #[serde(default)]
+    pub mesa_version: Option<String>,
+    /// Vulkan driver ("RADV", "ANV", "NVK", "NVIDIA proprietary", ...)
+    #[serde(default)]
+    pub vulkan_driver: Option<String>,
+    /// Vulkan driver version as reported by the driver
+    #[serde(default)]
+    pub vulkan_driver_version: Option<String>,
+    /// Vulkan API version supported by the device
+    #[serde(default)]
+    pub vulkan_api_version: Option<String>,
+    /// OpenGL renderer string
+    #[serde(default)]
+    pub opengl_renderer: Option<String>,
+    /// "Wayland" or "X11"
+    #[serde(default)]
+    pub display_server: Option<String>,
+    /// Compositor / desktop (e.g. "KWin", "Mutter", "gamescope")
+    #[serde(default)]
+    pub compositor: Option<String>,
+    #[serde(default)]
+    pub proton_version: Option<String>,
+    #[serde(default)]
+    pub wine_version: Option<String>,
+    #[serde(default)]
+    pub dxvk_version: Option<String>,
+    #[serde(default)]
+    pub vkd3d_version: Option<String>,
+}
+
+impl GraphicsStack {
+    pub fn is_empty(&self) -> bool {
+        *self == GraphicsStack::default()
+    }
+
+    /// Detect the graphics stack for the GPU with `pci_id` ("vvvv:dddd", if known)
+    #[cfg(target_os = "linux")]
+    pub fn detect(probe: &Probe, pci_id: Option<&str>) -> Self {
+        let mut stack = GraphicsStack::default();
+
+        if let Some(stdout) = probe.run("vulkaninfo", &["--summary"]) {
+            stack.apply_vulkaninfo_summary(&stdout, pci_id);
+        }
+        if let Some(stdout) = probe.run("glxinfo", &["-B"]) {
+            stack.apply_glxinfo(&stdout);
+        }
+
+        stack.apply_session_env(|key| probe.var(key));
+
+        // Proton and Wine live under the user's home, which snapshots never carry.
+        if let Some(dir) = proton_dir_from_env(probe) {
+            stack.proton_version = read_proton_version(&dir);
+        }
+        if let Some(text) = recent_proton_log(probe) {
+            stack.apply_proton_log(&text);
+        }
+        if stack.wine_version.is_none() && stack.proton_version.is_none() {
+            // Lutris and plain Wine prefixes export the wine binary they use.
+            if let Some(wine) = probe.var("WINELOADER").or_else(|| probe.var("WINE")) {
+                stack.wine_version = probe
+                    .run(&wine, &["--version"])
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty());
+            }
+        }
+
+        stack
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn detect(_probe: &Probe, _pci_id: Option<&str>) -> Self {
+        GraphicsStack::default()
+    }
+
+    /// Fill Vulkan fields from `vulkaninfo --summary`, preferring the device that
+    /// matches `pci_id` and skipping software renderers (llvmpipe).
+    pub fn apply_vulkaninfo_summary(&mut self, stdout: &str, pci_id: Option<&str>) {
+        let devices = parse_vulkaninfo_devices(stdout);
+        let wanted = pci_id.and_then(|id| id.split_once(':'));
+        let field = |device: &Vec<(String, String)>, key: &str| {
+            device
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+        };
+        let hex = |value: Option<String>| value.map(|v| v.trim_start_matches("0x").to_lowercase());
+
+        let matches_pci = |device: &Vec<(String, String)>| {
+            wanted.is_some_and(|(vendor, dev)| {
+                hex(field(device, "vendorID")).as_deref() == Some(vendor)
+                    && hex(field(device, "deviceID")).as_deref() == Some(dev)
+            })
+        };
+        let is_hardware = |device: &Vec<(String, String)>| {
+            field(device, "deviceType").is_some_and(|t| !t.contains("CPU"))
+        };
+        let Some(device) = devices
+            .iter()
+            .find(|device| matches_pci(device))
+            .or_else(|| devices.iter().find(|device| is_hardware(device)))
+        else {
+            return;
+        };
+
+        let driver_id = field(device, "driverID").unwrap_or_default();
+        self.vulkan_driver = match driver_id.trim_start_matches("DRIVER_ID_") {
+            "MESA_RADV" => Some("RADV".to_string()),
+            "INTEL_OPEN_SOURCE_MESA" => Some("ANV".to_string()),
+            "MESA_NVK" => Some("NVK".to_string()),
+            "NVIDIA_PROPRIETARY" => Some("NVIDIA proprietary".to_string()),
+            "AMD_OPEN_SOURCE" => Some("AMDVLK".to_string()),
+            "AMD_PROPRIETARY" => Some("AMD proprietary".to_string()),
+            "" => field(device, "driverName"),
+            other => Some(other.to_string()),
+        };
+        self.vulkan_driver_version = field(device, "driverVersion");
+        self.vulkan_api_version = field(device, "apiVersion");
+        if let Some(mesa) = field(device, "driverInfo").and_then(|info| mesa_from(&info)) {
+            self.mesa_version = Some(mesa);
+        }
+    }
+
+    /// Fill the OpenGL renderer and (if still unknown) the Mesa version from `glxinfo -B`
+    pub fn apply_glxinfo(&mut self, stdout: &str) {
+        for line in stdout.lines() {
+            let line = line.trim();
+            if let Some(renderer) = line.strip_prefix("OpenGL renderer string:") {
+                self.opengl_renderer = Some(renderer.trim().to_string());
+            } else if line.starts_with("OpenGL")
+                && line.contains("version string:")
+                && self.mesa_version.is_none()
+            {
+                self.mesa_version = mesa_from(line);
+            }
+        }
+    }
+
+    /// Fill display server and compositor from session variables
+    pub fn apply_session_env(&mut self, var: impl Fn(&str) -> Option<String>) {
+        self.display_server = match var("XDG_SESSION_TYPE").map(|v| v.to_lowercase()) {
+            Some(kind) if kind == "wayland" => Some("Wayland".to_string()),
+            Some(kind) if kind == "x11" => Some("X11".to_string()),
+            _ if var("WAYLAND_DISPLAY").is_some() => Some("Wayland".to_string()),
+            _ if var("DISPLAY").is_some() => Some("X11".to_string()),
+            _ => None,
+        };
+
+        self.compositor = if var("GAMESCOPE_WAYLAND_DISPLAY").is_some() {
+            Some("gamescope".to_string())
+        } else if var("HYPRLAND_INSTANCE_SIGNATURE").is_some() {
+            Some("Hyprland".to_string())
+        } else if var("SWAYSOCK").is_some() {
+            Some("sway".to_string())
+        } else {
+            var("XDG_CURRENT_DESKTOP").map(|desktop| {
+                let desktop = desktop
+                    .split(':')
+                    .next_back()
+                    .unwrap_or(&desktop)
+                    .to_string();
+                match desktop.as_str() {
+                    "GNOME" => "Mutter (GNOME)".to_string(),
+                    "KDE" => "KWin (KDE Plasma)".to_string(),
+                    "X-Cinnamon" => "Muffin (Cinnamon)".to_string(),
+                    "XFCE" => "xfwm4 (Xfce)".to_string(),
+                    _ => desktop,
+                }
+            })
+        };
+    }
+
+    /// Fill missing Proton/DXVK/VKD3D-Proton versions from a `PROTON_LOG=1` log
+    pub fn apply_proton_log(&mut self, text: &str) {
+        for line in text.lines().take(20_000) {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("Proton:") {
+                // "Proton: 1712345678 proton-9.0-2"
+                if self.proton_version.is_none() {
+                    self.proton_version = rest.split_whitespace().last().map(str::to_string);
+                }
+            } else if let Some(idx) = line.find("DXVK: ") {
+                if self.dxvk_version.is_none() {
+                    self.dxvk_version = version_token(&line[idx + "DXVK: ".len()..]);
+                }
+            } else if let Some(idx) = line.find("vkd3d-proton") {
+                if self.vkd3d_version.is_none() {
+                    self.vkd3d_version = version_token(&line[idx + "vkd3d-proton".len()..]);
+                }
+            }
+        }
+    }
+
+    /// Apply a MangoHud log's system header (`os,cpu,gpu,ram,kernel,driver,...`).
+    /// These describe the captured session, so they win over detected values.
+    pub fn apply_mangohud_header(&mut self, header: &[(String, String)]) {
+        for (key, value) in header {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match key.as_str() {
+                "driver" => {
+                    if let Some(mesa) = mesa_from(value) {
+                        self.mesa_version = Some(mesa);
+                    }
+                }
+                "wine" => self.wine_version = Some(value.to_string()),
+                "proton" => self.proton_version = Some(value.to_string()),
+                "dxvk" => self.dxvk_version = version_token(value),
+                "vkd3d" | "vkd3d-proton" => self.vkd3d_version = version_token(value),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// GPU driver version as `SystemInfo` reports it: "Mesa <version>" for Mesa
+/// drivers (matching detection), otherwise the text as given.
+pub fn driver_version_label(text: &str) -> String {
+    match mesa_from(text) {
+        Some(mesa) => format!("Mesa {}", mesa),
+        None => text.trim().to_string(),
+    }
+}
+
+/// Kernel release from `/proc/version` ("Linux version 6.8.0-31-generic (...)")
+pub fn kernel_from_proc_version(text: &str) -> Option<String> {
+    text.trim()
+        .strip_prefix("Linux version ")?
+        .split_whitespace()
+        .next()
+        .map(str::to_string)
+}
+
+/// Split `vulkaninfo --summary` into per-GPU `key = value` lists.
+fn parse_vulkaninfo_devices(stdout: &str) -> Vec<Vec<(String, String)>> {
+    let mut devices: Vec<Vec<(String, String)>> = Vec::new();
+    let mut in_devices = false;
+    for line in stdout.lines() {
+        let trimmed = line.trim();
+        if trimmed == "Devices:" {
+            in_devices = true;
+            continue;
+        }
+        if !in_devices {
+            continue;
+        }
+        if trimmed.starts_with("GPU") && trimmed.ends_with(':') {
+            devices.push(Vec::new());
+        } else if let (Some(device), Some((key, value))) =
+            (devices.last_mut(), trimmed.split_once('='))
+        {
+            device.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    devices
+}
+
+/// "4.6 (Core Profile) Mesa 24.0.5-1ubuntu1" → "24.0.5-1ubuntu1"
+fn mesa_from(text: &str) -> Option<String> {
+    let idx = text.find("Mesa ")?;
+    text[idx + "Mesa ".len()..]
+        .split_whitespace()
+        .next()
+        .map(|v| v.trim_matches(|c| c == '(' || c == ')').to_string())
+        .filter(|v| v.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// First "v2.3.1"/"2.3.1"-style token, without the leading "v"
+fn version_token(text: &str) -> Option<String> {
+    text.split(|c: char| c.is_whitespace() || c == ',' || c == ':' || c == '(' || c == ')')
+        .map(|token| token.trim_start_matches('v'))
+        .find(|token| token.starts_with(|c: char| c.is_ascii_digit()) && token.contains('.'))
+        .map(str::to_string)
+}
+
+/// Proton install dir from Steam (`STEAM_COMPAT_TOOL_PATHS`) or umu (`PROTONPATH`)
+#[cfg(target_os = "linux")]
+fn proton_dir_from_env(probe: &Probe) -> Option<std::path::PathBuf> {
+    probe
+        .var("PROTONPATH")
+        .or_else(|| {
+            probe
+                .var("STEAM_COMPAT_TOOL_PATHS")
+                .and_then(|paths| paths.split(':').next().map(str::to_string))
+        })
+        .filter(|path| !path.is_empty())
+        .map(std::path::PathBuf::from)
+}
+
+/// Proton's `version` file reads "1712345678 proton-9.0-2"; fall back to the dir name.
+#[cfg(target_os = "linux")]
+fn read_proton_version(dir: &std::path::Path) -> Option<String> {
+    std::fs::read_to_string(dir.join("version"))
+        .ok()
+        .and_then(|text| text.split_whitespace().last().map(str::to_string))
+        .or_else(|| {
+            dir.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+}
+
+/// Head of the running game's `steam-<appid>.log` (written by `PROTON_LOG=1`),
+/// if it was written within the last few hours. Without a Steam app id there
+/// is no way to tell which game a log belongs to, so none is read.
+#[cfg(target_os = "linux")]
+fn recent_proton_log(probe: &Probe) -> Option<String> {
+    use std::io::BufRead;
+
+    let app_id = STEAM_APP_ID_VARS
+        .iter()
+        .find_map(|name| probe.var(name))
+        .filter(|id| id.chars().all(|c| c.is_ascii_digit()))?;
+    let dir = probe.var("PROTON_LOG_DIR").or_else(|| probe.var("HOME"))?;
+    let path = std::path::Path::new(&dir).join(format!("steam-{app_id}.log"));
+
+    let age = std::fs::metadata(&path)
+        .ok()?
+        .modified()
+        .ok()?
+        .elapsed()
+        .ok()?;
+    if age.as_secs() > PROTON_LOG_MAX_AGE_SECS {
+        return None;
+    }
+    let file = std::fs::File::open(path).ok()?;
+    let head: Vec<String> = std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .take(PROTON_LOG_HEADER_LINES)
+        .collect();
+    Some(head.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VULKANINFO_SUMMARY: &str = "\
+==========
+VULKANINFO
+==========
+
+Vulkan Instance Version: 1.3.275
+
+Devices:
+========
+GPU0:
+\tapiVersion         = 1.3.274
+\tdriverVersion      = 24.0.5
+\tvendorID           = 0x1002
+\tdeviceID           = 0x747e
+\tdeviceType         = PHYSICAL_DEVICE_TYPE_DISCRETE_GPU
+\tdeviceName         = AMD Radeon RX 7800 XT (RADV NAVI32)
+\tdriverID           = DRIVER_ID_MESA_RADV
+\tdriverName         = radv
+\tdriverInfo         = Mesa 24.0.5-1ubuntu1
+\tconformanceVersion = 1.3.0.0
+GPU1:
+\tapiVersion         = 1.3.274
+\tdriverVersion      = 0.0.1
+\tvendorID           = 0x10005
+\tdeviceID           = 0x0000
+\tdeviceType         = PHYSICAL_DEVICE_TYPE_CPU
+\tdeviceName         = llvmpipe (LLVM 17.0.6, 256 bits)
+\tdriverID           = DRIVER_ID_MESA_LLVMPIPE
+\tdriverName         = llvmpipe
+\tdriverInfo         = Mesa 24.0.5-1ubuntu1 (LLVM 17.0.6)
+";
+
+    #[test]
+    fn vulkaninfo_and_glxinfo_fill_driver_fields() {
+        let mut stack = GraphicsStack::default();
+        stack.apply_vulkaninfo_summary(VULKANINFO_SUMMARY, Some("1002:747e"));
+        assert_eq!(stack.vulkan_driver.as_deref(), Some("RADV"));
+        assert_eq!(stack.vulkan_driver_version.as_deref(), Some("24.0.5"));
+        assert_eq!(stack.vulkan_api_version.as_deref(), Some("1.3.274"));
+        assert_eq!(stack.mesa_version.as_deref(), Some("24.0.5-1ubuntu1"));
+
+        // Unknown PCI id: the first hardware device wins over llvmpipe.
+        let mut other = GraphicsStack::default();
+        other.apply_vulkaninfo_summary(VULKANINFO_SUMMARY, Some("8086:a780"));
+        assert_eq!(other.vulkan_driver.as_deref(), Some("RADV"));
+
+        let mut nvidia = GraphicsStack::default();
+        nvidia.apply_glxinfo(
+            "name of display: :0\n\
+             OpenGL vendor string: NVIDIA Corporation\n\
+             OpenGL renderer string: NVIDIA GeForce RTX 4070/PCIe/SSE2\n\
+             OpenGL core profile version string: 4.6.0 NVIDIA 550.78\n",
+        );
+        assert_eq!(
+            nvidia.opengl_renderer.as_deref(),
+            Some("NVIDIA GeForce RTX 4070/PCIe/SSE2")
+        );
+        assert!(nvidia.mesa_version.is_none());
+
+        let mut mesa = GraphicsStack::default();
+        mesa.apply_glxinfo("OpenGL version string: 4.6 (Compatibility Profile) Mesa 23.3.6\n");
+        assert_eq!(mesa.mesa_version.as_deref(), Some("23.3.6"));
+    }
+
+    #[test]
+    fn session_env_detects_display_server_and_compositor() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |key: &str| {
+                pairs
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
+
+        let mut stack = GraphicsStack::default();
+        stack.apply_session_env(env(&[
+            ("XDG_SESSION_TYPE", "wayland"),
+            ("XDG_CURRENT_DESKTOP", "KDE"),
+        ]));
+        assert_eq!(stack.display_server.as_deref(), Some("Wayland"));
+        assert_eq!(stack.compositor.as_deref(), Some("KWin (KDE Plasma)"));
+
+        stack.apply_session_env(env(&[
+            ("DISPLAY", ":0"),
+            ("XDG_CURRENT_DESKTOP", "ubuntu:GNOME"),
+        ]));
+        assert_eq!(stack.display_server.as_deref(), Some("X11"));
+        assert_eq!(stack.compositor.as_deref(), Some("Mutter (GNOME)"));
+
+        stack.apply_session_env(env(&[
+            ("WAYLAND_DISPLAY", "gamescope-0"),
+            ("GAMESCOPE_WAYLAND_DISPLAY", "gamescope-0"),
+        ]));
+        assert_eq!(stack.compositor.as_deref(), Some("gamescope"));
+    }
+
+    #[test]
+    fn proton_log_and_mangohud_header_fill_compat_layer_versions() {
+        let mut stack = GraphicsStack::default();
+        stack.apply_proton_log(
+            "======================\n\
+             Proton: 1712345678 proton-9.0-2\n\
+             SteamGameId: 1091500\n\
+             ======================\n\
+             info:  Game: Cyberpunk2077.exe\n\
+             info:  DXVK: v2.3.1\n\
+             info:  vkd3d-proton v2.12 (a2a5f6c)\n\
+             info:  DXVK: v1.0\n",
+        );
+        assert_eq!(stack.proton_version.as_deref(), Some("proton-9.0-2"));
+        assert_eq!(stack.dxvk_version.as_deref(), Some("2.3.1"));
+        assert_eq!(stack.vkd3d_version.as_deref(), Some("2.12"));
+
+        stack.mesa_version = Some("24.0.5".to_string());
+        stack.apply_mangohud_header(&[
+            ("kernel".to_string(), "6.8.0-31-generic".to_string()),
+            ("driver".to_string(), "Mesa 24.1.0-devel".to_string()),
+            ("dxvk".to_string(), "v2.4".to_string()),
+        ]);
+        assert_eq!(stack.mesa_version.as_deref(), Some("24.1.0-devel"));
+        assert_eq!(stack.dxvk_version.as_deref(), Some("2.4"));
+
+        assert_eq!(
+            kernel_from_proc_version(
+                "Linux version 6.8.0-31-generic (buildd@lcy02-amd64-080) (gcc 13.2.0) #31-Ubuntu SMP\n"
+            )
+            .as_deref(),
+            Some("6.8.0-31-generic")
+        );
+    }
+}
@@ -3,8 +3,16 @@
 //! Detects GPU, CPU, RAM, and other system information using sysinfo
 //! and platform-specific APIs (NVML for NVIDIA, sysfs for AMD).
 
+pub mod adapters;
+pub mod board;
 pub mod cpu;
+pub mod display;
+pub mod drives;
+pub mod fingerprint;
 pub mod gpu;
+pub mod graphics;
+pub mod power;
+pub mod probe;
 pub mod ram;
 mod system;
 
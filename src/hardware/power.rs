@@ -0,0 +1,740 @@
+//! Power, thermal and throttling telemetry
+//!
+//! Sampled in the background while a live capture runs, so results from a
+//! laptop on battery or a machine at its thermal limit can be flagged:
+//! - AC vs battery from /sys/class/power_supply
+//! - CPU governor, EPP and ACPI platform profile (cpufreq, /sys/firmware/acpi)
+//! - CPU package temperature (hwmon k10temp/coretemp/zenpower) and `scaling_cur_freq`
+//! - Intel thermal throttle counters (`thermal_throttle/*_throttle_count`)
+//! - Render GPU temperature and clock (sysfs/hwmon, one long-running nvidia-smi)
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+#[cfg(target_os = "linux")]
+use std::{fs, path::Path};
+
+use super::adapters::{self, GpuSelectionHints};
+use super::gpu::{GpuInfo, GpuVendor, NvidiaSmiMonitor};
+use super::probe::Probe;
+
+/// CPU package temperature at which desktop and laptop parts start to pull
+/// clocks. Zen 4 boosts until Tctl reaches 95 °C by design, so this only
+/// counts as throttling when the clock drops at the same time.
+const CPU_THERMAL_LIMIT_C: f64 = 95.0;
+
+/// A sample below this share of the capture's peak clock is a clock drop
+const CLOCK_DROP_RATIO: f64 = 0.85;
+
+/// GPU temperature at which boost clocks are usually being cut; like the CPU
+/// limit, it only counts when the GPU clock drops at the same time
+const GPU_THERMAL_LIMIT_C: u64 = 88;
+
+/// One reading of the power and thermal state
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PowerSample {
+    pub on_battery: Option<bool>,
+    pub cpu_temp_c: Option<f64>,
+    pub cpu_freq_mhz: Option<f64>,
+    pub gpu_temp_c: Option<u64>,
+    pub gpu_clock_mhz: Option<u64>,
+}
+
+/// CPU power policy (read once per capture)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PowerProfile {
+    pub cpu_governor: Option<String>,
+    pub energy_performance_preference: Option<String>,
+    pub platform_profile: Option<String>,
+}
+
+impl PowerProfile {
+    /// Whether the policy favours battery life over performance
+    pub fn is_low_power(&self) -> bool {
+        matches!(
+            self.energy_performance_preference.as_deref(),
+            Some("power") | Some("balance_power")
+        ) || matches!(
+            self.platform_profile.as_deref(),
+            Some("low-power") | Some("quiet") | Some("cool")
+        )
+    }
+}
+
+/// Power, thermal and throttling state over a capture window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PowerSummary {
+    pub samples: usize,
+    #[serde(default)]
+    pub cpu_governor: Option<String>,
+    #[serde(default)]
+    pub energy_performance_preference: Option<String>,
+    #[serde(default)]
+    pub platform_profile: Option<String>,
+    /// Battery power was seen at any point during the capture
+    pub on_battery: bool,
+    pub cpu_temp_max_c: Option<f64>,
+    pub cpu_freq_avg_mhz: Option<f64>,
+    pub cpu_freq_min_mhz: Option<f64>,
+    pub gpu_temp_max_c: Option<u64>,
+    pub gpu_clock_avg_mhz: Option<f64>,
+    pub gpu_clock_min_mhz: Option<u64>,
+    /// Thermal throttle events the CPU counted during the capture (Intel)
+    #[serde(default)]
+    pub cpu_throttle_events: u64,
+    pub cpu_throttled: bool,
+    pub gpu_throttled: bool,
+}
+
+impl PowerSummary {
+    pub fn from_samples(
+        profile: &PowerProfile,
+        samples: &[PowerSample],
+        cpu_throttle_events: u64,
+    ) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let cpu_temps: Vec<f64> = samples.iter().filter_map(|s| s.cpu_temp_c).collect();
+        let cpu_freqs: Vec<f64> = samples.iter().filter_map(|s| s.cpu_freq_mhz).collect();
+        let gpu_temps: Vec<u64> = samples.iter().filter_map(|s| s.gpu_temp_c).collect();
+        let gpu_clocks: Vec<u64> = samples.iter().filter_map(|s| s.gpu_clock_mhz).collect();
+
+        let cpu_temp_max_c = cpu_temps.iter().copied().reduce(f64::max);
+        let cpu_readings: Vec<(f64, f64)> = samples
+            .iter()
+            .filter_map(|s| Some((s.cpu_temp_c?, s.cpu_freq_mhz?)))
+            .collect();
+        let gpu_readings: Vec<(f64, f64)> = samples
+            .iter()
+            .filter_map(|s| Some((s.gpu_temp_c? as f64, s.gpu_clock_mhz? as f64)))
+            .collect();
+        let gpu_temp_max_c = gpu_temps.iter().copied().max();
+        let mean = |values: &[f64]| {
+            (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+        };
+        let gpu_clocks_f64: Vec<f64> = gpu_clocks.iter().map(|mhz| *mhz as f64).collect();
+
+        Some(Self {
+            samples: samples.len(),
+            cpu_governor: profile.cpu_governor.clone(),
+            energy_performance_preference: profile.energy_performance_preference.clone(),
+            platform_profile: profile.platform_profile.clone(),
+            on_battery: samples.iter().any(|s| s.on_battery == Some(true)),
+            cpu_temp_max_c,
+            cpu_freq_avg_mhz: mean(&cpu_freqs),
+            cpu_freq_min_mhz: cpu_freqs.iter().copied().reduce(f64::min),
+            gpu_temp_max_c,
+            gpu_clock_avg_mhz: mean(&gpu_clocks_f64),
+            gpu_clock_min_mhz: gpu_clocks.iter().copied().min(),
+            cpu_throttle_events,
+            cpu_throttled: cpu_throttle_events > 0
+                || hot_and_slowed(&cpu_readings, CPU_THERMAL_LIMIT_C),
+            gpu_throttled: hot_and_slowed(&gpu_readings, GPU_THERMAL_LIMIT_C as f64),
+        })
+    }
+
+    fn is_low_power(&self) -> bool {
+        PowerProfile {
+            cpu_governor: self.cpu_governor.clone(),
+            energy_performance_preference: self.energy_performance_preference.clone(),
+            platform_profile: self.platform_profile.clone(),
+        }
+        .is_low_power()
+    }
+
+    /// Points taken off the capture quality score
+    pub fn quality_penalty(&self) -> u8 {
+        let mut penalty = 0;
+        if self.on_battery {
+            penalty += 25;
+        }
+        if self.cpu_throttled {
+            penalty += 15;
+        }
+        if self.gpu_throttled {
+            penalty += 15;
+        }
+        if self.is_low_power() {
+            penalty += 10;
+        }
+        penalty
+    }
+
+    /// Human-readable reasons the result may not be representative
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.on_battery {
+            warnings.push("Ran on battery power".to_string());
+        }
+        if self.cpu_throttled {
+            let mut warning = "CPU thermal throttling".to_string();
+            if self.cpu_throttle_events > 0 {
+                warning.push_str(&format!(" ({} events)", self.cpu_throttle_events));
+            } else if let (Some(temp), Some(min)) = (self.cpu_temp_max_c, self.cpu_freq_min_mhz) {
+                warning.push_str(&format!(" (peak {:.0}°C, down to {:.0} MHz)", temp, min));
+            }
+            warnings.push(warning);
+        }
+        if self.gpu_throttled {
+            let peak = match (self.gpu_temp_max_c, self.gpu_clock_min_mhz) {
+                (Some(temp), Some(min)) => format!(" (peak {}°C, down to {} MHz)", temp, min),
+                _ => String::new(),
+            };
+            warnings.push(format!("GPU thermal throttling{}", peak));
+        }
+        if self.is_low_power() {
+            warnings.push("Low-power CPU profile active".to_string());
+        }
+        warnings
+    }
+}
+
+/// Whether any `(temperature, clock)` reading is at `limit` while its clock is
+/// below [`CLOCK_DROP_RATIO`] of the highest clock seen
+fn hot_and_slowed(readings: &[(f64, f64)], limit: f64) -> bool {
+    let Some(peak) = readings.iter().map(|(_, clock)| *clock).reduce(f64::max) else {
+        return false;
+    };
+    readings
+        .iter()
+        .any(|(temp, clock)| *temp >= limit && *clock < peak * CLOCK_DROP_RATIO)
+}
+
+impl std::fmt::Display for PowerSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut policy = vec![if self.on_battery { "Battery" } else { "AC" }.to_string()];
+        policy.extend(self.cpu_governor.clone());
+        policy.extend(self.energy_performance_preference.clone());
+        policy.extend(self.platform_profile.clone());
+        writeln!(f, "Power: {}", policy.join(" / "))?;
+
+        let mut cpu = Vec::new();
+        if let Some(temp) = self.cpu_temp_max_c {
+            cpu.push(format!("max {:.0}°C", temp));
+        }
+        if let (Some(avg), Some(min)) = (self.cpu_freq_avg_mhz, self.cpu_freq_min_mhz) {
+            cpu.push(format!("{:.0} MHz avg / {:.0} MHz min", avg, min));
+        }
+        if !cpu.is_empty() {
+            writeln!(f, "CPU: {}", cpu.join(", "))?;
+        }
+
+        let mut gpu = Vec::new();
+        if let Some(temp) = self.gpu_temp_max_c {
+            gpu.push(format!("max {}°C", temp));
+        }
+        if let (Some(avg), Some(min)) = (self.gpu_clock_avg_mhz, self.gpu_clock_min_mhz) {
+            gpu.push(format!("{:.0} MHz avg / {} MHz min", avg, min));
+        }
+        if !gpu.is_empty() {
+            writeln!(f, "GPU: {}", gpu.join(", "))?;
+        }
+
+        let warnings = self.warnings();
+        if warnings.is_empty() {
+            write!(f, "Throttling: none detected")
+        } else {
+            write!(f, "Warnings: {}", warnings.join("; "))
+        }
+    }
+}
+
+/// Samples power and thermal state on a background thread until finished
+pub struct PowerSampler {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<Option<PowerSummary>>>,
+}
+
+impl PowerSampler {
+    pub fn start(interval: Duration) -> Self {
+        Self::start_with(interval, move || LivePowerSource::new(interval))
+    }
+
+    /// Start sampling from the source `make_source` builds. Detection and
+    /// every read happen on the sampler thread, never on the caller's.
+    fn start_with<S, F>(interval: Duration, make_source: F) -> Self
+    where
+        S: PowerSource,
+        F: FnOnce() -> S + Send + 'static,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            let mut source = make_source();
+            let profile = source.profile();
+            let throttle_start = source.throttle_count();
+
+            let mut samples = Vec::new();
+            while !thread_stop.load(Ordering::Relaxed) {
+                samples.push(source.sample());
+
+                let next = Instant::now() + interval;
+                while !thread_stop.load(Ordering::Relaxed) && Instant::now() < next {
+                    thread::sleep(Duration::from_millis(100));
+                }
+            }
+
+            let throttle_events = match (throttle_start, source.throttle_count()) {
+                (Some(start), Some(end)) => end.saturating_sub(start),
+                _ => 0,
+            };
+            PowerSummary::from_samples(&profile, &samples, throttle_events)
+        });
+
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Stop sampling and summarise what was seen
+    pub fn finish(mut self) -> Option<PowerSummary> {
+        self.stop.store(true, Ordering::Relaxed);
+        self.handle.take()?.join().ok().flatten()
+    }
+}
+
+impl Drop for PowerSampler {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Where the sampler thread reads power and thermal state from
+trait PowerSource {
+    fn profile(&self) -> PowerProfile;
+    fn throttle_count(&self) -> Option<u64>;
+    fn sample(&mut self) -> PowerSample;
+}
+
+/// This machine's sysfs, plus an nvidia-smi monitor when the render GPU is NVIDIA
+struct LivePowerSource {
+    probe: Probe,
+    render_gpu: Option<(GpuInfo, Option<String>)>,
+    nvidia_smi: Option<NvidiaSmiMonitor>,
+}
+
+impl LivePowerSource {
+    fn new(interval: Duration) -> Self {
+        let probe = Probe::live();
+        let render_gpu = render_gpu(&probe);
+        let nvidia_smi = render_gpu
+            .as_ref()
+            .filter(|(gpu, _)| gpu.vendor == GpuVendor::Nvidia)
+            .and_then(|(gpu, pci_slot)| {
+                NvidiaSmiMonitor::start(pci_slot.as_deref(), gpu.pci_id.as_deref(), interval)
+            });
+        Self {
+            probe,
+            render_gpu,
+            nvidia_smi,
+        }
+    }
+}
+
+impl PowerSource for LivePowerSource {
+    fn profile(&self) -> PowerProfile {
+        read_power_profile(&self.probe)
+    }
+
+    fn throttle_count(&self) -> Option<u64> {
+        read_throttle_count(&self.probe)
+    }
+
+    fn sample(&mut self) -> PowerSample {
+        let mut sample = read_power_sample(&self.probe);
+        if let Some(monitor) = &self.nvidia_smi {
+            let stats = monitor.latest().unwrap_or_default();
+            sample.gpu_temp_c = stats.temperature_c;
+            sample.gpu_clock_mhz = stats.gpu_clock_mhz;
+        } else if let Some((gpu, pci_slot)) = &self.render_gpu {
+            let mut gpu = gpu.clone();
+            gpu.refresh_live_stats(&self.probe, pci_slot.as_deref());
+            sample.gpu_temp_c = gpu.temperature_c;
+            sample.gpu_clock_mhz = gpu.gpu_clock_mhz;
+        }
+        sample
+    }
+}
+
+/// The render GPU and its PCI slot, as picked by the adapter inventory
+fn render_gpu(probe: &Probe) -> Option<(GpuInfo, Option<String>)> {
+    let gpus = adapters::detect_adapters(probe);
+    let selection = adapters::select_render_adapter(&gpus, &GpuSelectionHints::from_env(probe))?;
+    let adapter = &gpus[selection.adapter_index];
+    Some((adapter.to_gpu_info(), adapter.pci_slot.clone()))
+}
+
+fn read_power_profile(probe: &Probe) -> PowerProfile {
+    #[cfg(target_os = "linux")]
+    {
+        read_power_profile_from(&probe.path("/sys"))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = probe;
+        PowerProfile::default()
+    }
+}
+
+fn read_power_sample(probe: &Probe) -> PowerSample {
+    #[cfg(target_os = "linux")]
+    {
+        read_power_sample_from(&probe.path("/sys"))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = probe;
+        PowerSample::default()
+    }
+}
+
+fn read_throttle_count(probe: &Probe) -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        read_throttle_count_from(&probe.path("/sys"))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = probe;
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// `cpuN` directories under `<sys_root>/devices/system/cpu`
+#[cfg(target_os = "linux")]
+fn cpu_dirs(sys_root: &Path) -> Vec<std::path::PathBuf> {
+    let Ok(entries) = fs::read_dir(sys_root.join("devices/system/cpu")) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .strip_prefix("cpu")
+                .is_some_and(|index| index.parse::<u32>().is_ok())
+        })
+        .map(|entry| entry.path())
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_power_profile_from(sys_root: &Path) -> PowerProfile {
+    let cpufreq = sys_root.join("devices/system/cpu/cpu0/cpufreq");
+    PowerProfile {
+        cpu_governor: read_trimmed(&cpufreq.join("scaling_governor")),
+        energy_performance_preference: read_trimmed(&cpufreq.join("energy_performance_preference")),
+        platform_profile: read_trimmed(&sys_root.join("firmware/acpi/platform_profile")),
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_power_sample_from(sys_root: &Path) -> PowerSample {
+    PowerSample {
+        on_battery: read_on_battery(sys_root),
+        cpu_temp_c: read_cpu_temp_c(sys_root),
+        cpu_freq_mhz: read_cpu_freq_mhz(sys_root),
+        gpu_temp_c: None,
+        gpu_clock_mhz: None,
+    }
+}
+
+/// `Some(true)` when running from battery, `None` on machines without one
+#[cfg(target_os = "linux")]
+fn read_on_battery(sys_root: &Path) -> Option<bool> {
+    let entries = fs::read_dir(sys_root.join("class/power_supply")).ok()?;
+    let mut mains_seen = false;
+    let mut mains_online = false;
+    let mut discharging = false;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match read_trimmed(&path.join("type")).as_deref() {
+            Some("Mains") | Some("USB") => {
+                mains_seen = true;
+                mains_online |= read_trimmed(&path.join("online")).as_deref() == Some("1");
+            }
+            Some("Battery") => {
+                // Peripheral batteries (mice, controllers) are scoped "Device".
+                if read_trimmed(&path.join("scope")).as_deref() == Some("Device") {
+                    continue;
+                }
+                discharging |= read_trimmed(&path.join("status")).as_deref() == Some("Discharging");
+            }
+            _ => {}
+        }
+    }
+    if discharging {
+        Some(true)
+    } else if mains_seen {
+        Some(!mains_online)
+    } else {
+        None
+    }
+}
+
+/// CPU package / Tctl temperature from the CPU's hwmon driver
+#[cfg(target_os = "linux")]
+fn read_cpu_temp_c(sys_root: &Path) -> Option<f64> {
+    const CPU_HWMON_NAMES: &[&str] = &["k10temp", "zenpower", "coretemp", "cpu_thermal"];
+    let entries = fs::read_dir(sys_root.join("class/hwmon")).ok()?;
+    let mut hwmons: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+    hwmons.sort();
+    hwmons
+        .into_iter()
+        .filter(|hwmon| {
+            read_trimmed(&hwmon.join("name"))
+                .is_some_and(|name| CPU_HWMON_NAMES.contains(&name.as_str()))
+        })
+        // temp1 is Tctl on k10temp/zenpower and "Package id 0" on coretemp.
+        .find_map(|hwmon| read_trimmed(&hwmon.join("temp1_input")))
+        .and_then(|milli_c| milli_c.parse::<f64>().ok())
+        .map(|milli_c| milli_c / 1000.0)
+}
+
+/// Mean current frequency across online cores
+#[cfg(target_os = "linux")]
+fn read_cpu_freq_mhz(sys_root: &Path) -> Option<f64> {
+    let khz: Vec<f64> = cpu_dirs(sys_root)
+        .iter()
+        .filter_map(|cpu| read_trimmed(&cpu.join("cpufreq/scaling_cur_freq")))
+        .filter_map(|value| value.parse::<f64>().ok())
+        .collect();
+    (!khz.is_empty()).then(|| khz.iter().sum::<f64>() / khz.len() as f64 / 1000.0)
+}
+
+/// Core throttle events summed over cores, plus the package counter
+#[cfg(target_os = "linux")]
+fn read_throttle_count_from(sys_root: &Path) -> Option<u64> {
+    let mut found = false;
+    let mut core_total = 0u64;
+    let mut package_max = 0u64;
+    for cpu in cpu_dirs(sys_root) {
+        let throttle = cpu.join("thermal_throttle");
+        let read_u64 = |name: &str| {
+            read_trimmed(&throttle.join(name)).and_then(|value| value.parse::<u64>().ok())
+        };
+        if let Some(count) = read_u64("core_throttle_count") {
+            found = true;
+            core_total = core_total.saturating_add(count);
+        }
+        // Every core in a package reports the same package counter.
+        if let Some(count) = read_u64("package_throttle_count") {
+            found = true;
+            package_max = package_max.max(count);
+        }
+    }
+    found.then_some(core_total.saturating_add(package_max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(on_battery: bool, cpu_temp_c: f64, gpu_temp_c: u64) -> PowerSample {
+        PowerSample {
+            on_battery: Some(on_battery),
+            cpu_temp_c: Some(cpu_temp_c),
+            cpu_freq_mhz: Some(4200.0),
+            gpu_temp_c: Some(gpu_temp_c),
+            gpu_clock_mhz: Some(2500),
+        }
+    }
+
+    #[test]
+    fn test_summary_flags_battery_and_thermal_limits() {
+        let profile = PowerProfile::default();
+        let cool = PowerSummary::from_samples(
+            &profile,
+            &[sample(false, 70.0, 65), sample(false, 74.0, 68)],
+            0,
+        )
+        .unwrap();
+        assert!(!cool.cpu_throttled && !cool.gpu_throttled);
+        assert!(!cool.on_battery);
+        assert_eq!(cool.quality_penalty(), 0);
+        assert_eq!(cool.cpu_temp_max_c, Some(74.0));
+        assert!(cool.to_string().contains("Throttling: none detected"));
+
+        let hot = PowerSummary::from_samples(
+            &PowerProfile {
+                energy_performance_preference: Some("balance_power".to_string()),
+                ..PowerProfile::default()
+            },
+            &[
+                sample(false, 80.0, 70),
+                PowerSample {
+                    cpu_freq_mhz: Some(3100.0),
+                    gpu_clock_mhz: Some(1900),
+                    ..sample(true, 97.0, 91)
+                },
+            ],
+            0,
+        )
+        .unwrap();
+        assert!(hot.on_battery && hot.cpu_throttled && hot.gpu_throttled);
+        assert_eq!(hot.quality_penalty(), 65);
+        assert_eq!(hot.warnings().len(), 4);
+        assert!(hot.warnings()[1].contains("down to 3100 MHz"));
+        assert!(hot.warnings()[2].contains("down to 1900 MHz"));
+
+        // Zen 4 sits at Tctl 95 °C under load while holding its clocks.
+        let zen4 = PowerSummary::from_samples(
+            &profile,
+            &[sample(false, 95.0, 70), sample(false, 95.3, 70)],
+            0,
+        )
+        .unwrap();
+        assert!(!zen4.cpu_throttled);
+
+        // A hot GPU that holds its boost clock is not throttling.
+        let hot_gpu = PowerSummary::from_samples(
+            &profile,
+            &[sample(false, 70.0, 86), sample(false, 70.0, 90)],
+            0,
+        )
+        .unwrap();
+        assert_eq!(hot_gpu.gpu_temp_max_c, Some(90));
+        assert!(!hot_gpu.gpu_throttled);
+        assert_eq!(hot_gpu.quality_penalty(), 0);
+
+        let counted = PowerSummary::from_samples(&profile, &[sample(false, 60.0, 60)], 12).unwrap();
+        assert!(counted.cpu_throttled);
+        assert!(counted.warnings()[0].contains("12 events"));
+
+        assert!(PowerSummary::from_samples(&profile, &[], 0).is_none());
+    }
+
+    /// Records the thread every source call runs on
+    struct ThreadRecordingSource {
+        threads: Arc<std::sync::Mutex<Vec<thread::ThreadId>>>,
+    }
+
+    impl ThreadRecordingSource {
+        fn record(&self) {
+            self.threads.lock().unwrap().push(thread::current().id());
+        }
+    }
+
+    impl PowerSource for ThreadRecordingSource {
+        fn profile(&self) -> PowerProfile {
+            self.record();
+            PowerProfile::default()
+        }
+
+        fn throttle_count(&self) -> Option<u64> {
+            self.record();
+            Some(0)
+        }
+
+        fn sample(&mut self) -> PowerSample {
+            self.record();
+            sample(false, 60.0, 60)
+        }
+    }
+
+    #[test]
+    fn test_sampler_does_no_work_on_the_capture_thread() {
+        let threads = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let source_threads = Arc::clone(&threads);
+        let sampler = PowerSampler::start_with(Duration::from_millis(10), move || {
+            let source = ThreadRecordingSource {
+                threads: source_threads,
+            };
+            source.record();
+            source
+        });
+        thread::sleep(Duration::from_millis(50));
+        let summary = sampler.finish().unwrap();
+
+        let threads = threads.lock().unwrap();
+        assert!(summary.samples >= 1);
+        // Construction, profile, both throttle counts and every sample.
+        assert_eq!(threads.len(), summary.samples + 4);
+        assert!(threads.iter().all(|id| *id != thread::current().id()));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_reads_sysfs_fixture() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write("class/power_supply/AC/type", "Mains\n");
+        write("class/power_supply/AC/online", "0\n");
+        write("class/power_supply/BAT0/type", "Battery\n");
+        write("class/power_supply/BAT0/status", "Discharging\n");
+        write("class/hwmon/hwmon0/name", "amdgpu\n");
+        write("class/hwmon/hwmon0/temp1_input", "55000\n");
+        write("class/hwmon/hwmon1/name", "k10temp\n");
+        write("class/hwmon/hwmon1/temp1_input", "88250\n");
+        write(
+            "devices/system/cpu/cpu0/cpufreq/scaling_governor",
+            "powersave\n",
+        );
+        write(
+            "devices/system/cpu/cpu0/cpufreq/energy_performance_preference",
+            "power\n",
+        );
+        write(
+            "devices/system/cpu/cpu0/cpufreq/scaling_cur_freq",
+            "3000000\n",
+        );
+        write(
+            "devices/system/cpu/cpu1/cpufreq/scaling_cur_freq",
+            "4000000\n",
+        );
+        write(
+            "devices/system/cpu/cpu0/thermal_throttle/core_throttle_count",
+            "3\n",
+        );
+        write(
+            "devices/system/cpu/cpu1/thermal_throttle/core_throttle_count",
+            "2\n",
+        );
+        write(
+            "devices/system/cpu/cpu0/thermal_throttle/package_throttle_count",
+            "7\n",
+        );
+        write(
+            "devices/system/cpu/cpu1/thermal_throttle/package_throttle_count",
+            "7\n",
+        );
+        write("firmware/acpi/platform_profile", "balanced\n");
+
+        let sample = read_power_sample_from(root);
+        assert_eq!(sample.on_battery, Some(true));
+        assert_eq!(sample.cpu_temp_c, Some(88.25));
+        assert_eq!(sample.cpu_freq_mhz, Some(3500.0));
+        assert_eq!(read_throttle_count_from(root), Some(12));
+
+        let profile = read_power_profile_from(root);
+        assert_eq!(profile.cpu_governor.as_deref(), Some("powersave"));
+        assert_eq!(profile.platform_profile.as_deref(), Some("balanced"));
+        assert!(profile.is_low_power());
+
+        // A desktop without a battery or power_supply class reports nothing.
+        assert_eq!(read_on_battery(&root.join("missing")), None);
+    }
+}
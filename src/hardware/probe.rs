@@ -0,0 +1,848 @@
+//! Hardware probe provider
+//!
+//! Every file read, command and environment variable that hardware detection
+//! (and the focus backends) depend on goes through a [`Probe`]:
+//! - `Probe::live()` reads the running system
+//! - `Probe::snapshot(dir)` replays a snapshot captured with
+//!   `fps-tracker detect --dump-snapshot <dir>`
+//!
+//! A snapshot directory holds `snapshot.json` (host facts, command output and
+//! allow-listed environment variables) and `root/`, a copy of the curated
+//! /proc, /sys and /etc files laid out as on the host. Serial numbers, asset
+//! tags and EDID serials are stripped before anything is written; home
+//! directories and logs are never copied.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use sysinfo::System;
+
+use super::SystemInfo;
+
+/// Bump when the snapshot layout changes incompatibly
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "snapshot.json";
+const ROOT_DIR: &str = "root";
+
+/// Environment variables detection may consult and a snapshot may record
+const RECORDED_ENV: &[&str] = &[
+    "XDG_SESSION_TYPE",
+    "XDG_CURRENT_DESKTOP",
+    "WAYLAND_DISPLAY",
+    "DISPLAY",
+    "GAMESCOPE_WAYLAND_DISPLAY",
+    "HYPRLAND_INSTANCE_SIGNATURE",
+    "SWAYSOCK",
+    "DRI_PRIME",
+    "__NV_PRIME_RENDER_OFFLOAD",
+];
+
+/// Command output lines that identify a machine rather than describe it
+const REDACTED_LINE_MARKERS: &[&str] = &[
+    "Serial Number",
+    "SerialNumber",
+    "Asset Tag",
+    "UUID",
+    // Window titles (compositor JSON) can name documents, chats or sites.
+    "\"title\"",
+];
+
+/// Kernel log sources; only the "Memory:" lines RAM detection parses are kept
+const KERNEL_LOG_COMMANDS: &[&str] = &["dmesg"];
+
+/// Files copied into a snapshot: (directory pattern, file names). `*` in a
+/// directory component matches any entry name containing the fixed parts.
+const SNAPSHOT_FILES: &[(&str, &[&str])] = &[
+    ("/proc", &["cpuinfo", "meminfo", "version"]),
+    ("/etc", &["os-release"]),
+    (
+        "/sys/class/dmi/id",
+        &[
+            "board_vendor",
+            "board_name",
+            "bios_vendor",
+            "bios_version",
+            "bios_date",
+        ],
+    ),
+    (
+        "/sys/class/drm/card*",
+        &[
+            "status",
+            "enabled",
+            "modes",
+            "edid",
+            "gt_act_freq_mhz",
+            "gt_cur_freq_mhz",
+        ],
+    ),
+    (
+        "/sys/class/drm/card*/device",
+        &[
+            "vendor",
+            "device",
+            "uevent",
+            "boot_vga",
+            "mem_info_vram_total",
+            "pp_dpm_sclk",
+            "pp_dpm_mclk",
+            "gpu_busy_percent",
+        ],
+    ),
+    (
+        "/sys/class/drm/card*/device/hwmon/hwmon*",
+        &["name", "temp1_input", "power1_average", "power1_input"],
+    ),
+    (
+        "/sys/class/drm/card*/device/tile0/gt0/freq0",
+        &["act_freq", "cur_freq"],
+    ),
+    ("/sys/module/amdgpu", &["version"]),
+    ("/sys/module/i915", &["version"]),
+    ("/sys/module/xe", &["version"]),
+    ("/sys/module/nvidia", &["version"]),
+    ("/sys/module/nouveau", &["version"]),
+    ("/sys/module/radeon", &["version"]),
+    ("/sys/devices/system/cpu", &["online"]),
+    ("/sys/devices/system/cpu/cpu*", &["cpu_capacity"]),
+    (
+        "/sys/devices/system/cpu/cpu*/topology",
+        &["core_id", "physical_package_id", "die_id", "cluster_id"],
+    ),
+    (
+        "/sys/devices/system/cpu/cpu*/cache/index*",
+        &["level", "type", "size", "shared_cpu_list"],
+    ),
+    (
+        "/sys/devices/system/cpu/cpu*/cpufreq",
+        &[
+            "scaling_max_freq",
+            "scaling_cur_freq",
+            "scaling_governor",
+            "energy_performance_preference",
+        ],
+    ),
+    (
+        "/sys/devices/system/cpu/cpu*/thermal_throttle",
+        &["core_throttle_count", "package_throttle_count"],
+    ),
+    ("/sys/devices/cpu_core", &["cpus"]),
+    ("/sys/devices/cpu_atom", &["cpus"]),
+    ("/sys/devices/system/edac/mc/mc*", &["mc_name", "size_mb"]),
+    (
+        "/sys/devices/system/edac/mc/mc*/dimm*",
+        &["dimm_mem_type", "size"],
+    ),
+    ("/sys/block/*", &["size", "removable"]),
+    ("/sys/block/*/queue", &["rotational"]),
+    ("/sys/block/*/device", &["model"]),
+    (
+        "/sys/class/power_supply/*",
+        &["type", "online", "status", "scope"],
+    ),
+    ("/sys/class/hwmon/hwmon*", &["name", "temp1_input"]),
+    ("/sys/firmware/acpi", &["platform_profile"]),
+];
+
+/// Largest single file copied into a snapshot
+const MAX_SNAPSHOT_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Facts normally read through sysinfo
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HostFacts {
+    pub os: Option<String>,
+    pub os_version: Option<String>,
+    pub kernel_version: Option<String>,
+    pub total_memory_mb: u64,
+    pub cpu_brand: String,
+    pub cpu_vendor: String,
+    pub cpu_frequency_mhz: Option<u64>,
+    pub cpu_threads: usize,
+    pub cpu_cores: Option<usize>,
+}
+
+impl HostFacts {
+    /// Read the running system
+    pub fn read_live() -> Self {
+        let mut sys = System::new();
+        sys.refresh_memory();
+        sys.refresh_cpu_all();
+
+        let cpus = sys.cpus();
+        Self {
+            os: System::name(),
+            os_version: System::os_version(),
+            kernel_version: System::kernel_version(),
+            total_memory_mb: sys.total_memory() / 1024 / 1024, // bytes to MB
+            cpu_brand: cpus
+                .first()
+                .map(|cpu| cpu.brand().to_string())
+                .unwrap_or_default(),
+            cpu_vendor: cpus
+                .first()
+                .map(|cpu| cpu.vendor_id().to_string())
+                .unwrap_or_default(),
+            cpu_frequency_mhz: cpus.first().map(|cpu| cpu.frequency()),
+            cpu_threads: cpus.len(),
+            cpu_cores: sys.physical_core_count(),
+        }
+    }
+}
+
+/// `snapshot.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    pub format_version: u32,
+    pub tracker_version: String,
+    pub captured_at: DateTime<Utc>,
+    /// `std::env::consts::OS` of the captured machine
+    pub platform: String,
+    pub host: HostFacts,
+    /// Successful command output, keyed by the command line
+    #[serde(default)]
+    pub commands: BTreeMap<String, String>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// Error detection hit on the captured machine, if any
+    #[serde(default)]
+    pub detect_error: Option<String>,
+}
+
+/// What was read while detecting, for `write_snapshot`
+#[derive(Debug, Default)]
+struct Recording {
+    host: Option<HostFacts>,
+    files: BTreeMap<PathBuf, Vec<u8>>,
+    commands: BTreeMap<String, String>,
+    env: BTreeMap<String, String>,
+}
+
+enum Source {
+    Live,
+    Recording(Box<Mutex<Recording>>),
+    Snapshot(Box<SnapshotManifest>),
+}
+
+/// Source of files, command output and environment for hardware detection
+pub struct Probe {
+    /// Directory absolute paths are resolved against in snapshot mode
+    root: PathBuf,
+    source: Source,
+}
+
+impl Probe {
+    /// Probe the running system
+    pub fn live() -> Self {
+        Self {
+            root: PathBuf::from("/"),
+            source: Source::Live,
+        }
+    }
+
+    /// Replay a snapshot written by `write_snapshot`
+    pub fn snapshot(dir: &Path) -> Result<Self> {
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let text = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+        let manifest: SnapshotManifest = serde_json::from_str(&text)
+            .with_context(|| format!("Invalid snapshot manifest {}", manifest_path.display()))?;
+        if manifest.format_version > SNAPSHOT_FORMAT_VERSION {
+            bail!(
+                "Snapshot format {} is newer than this version supports ({})",
+                manifest.format_version,
+                SNAPSHOT_FORMAT_VERSION
+            );
+        }
+        if manifest.platform != std::env::consts::OS {
+            bail!(
+                "Snapshot was captured on {} and cannot be replayed on {}",
+                manifest.platform,
+                std::env::consts::OS
+            );
+        }
+        Ok(Self {
+            root: dir.join(ROOT_DIR),
+            source: Source::Snapshot(Box::new(manifest)),
+        })
+    }
+
+    fn recording() -> Self {
+        Self {
+            root: PathBuf::from("/"),
+            source: Source::Recording(Box::new(Mutex::new(Recording::default()))),
+        }
+    }
+
+    /// Whether this probe reads the running system without recording it;
+    /// probes that cannot be replayed (dxdiag, Win32 calls) only run then
+    pub fn is_live(&self) -> bool {
+        matches!(self.source, Source::Live)
+    }
+
+    /// Where the absolute host path `path` lives for this probe, for readers
+    /// that walk a directory tree (e.g. `probe.path("/sys")`)
+    pub fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path = path.as_ref();
+        match self.source {
+            Source::Snapshot(_) => self.root.join(path.strip_prefix("/").unwrap_or(path)),
+            _ => path.to_path_buf(),
+        }
+    }
+
+    /// Read a file by its absolute host path
+    pub fn read_to_string(&self, path: impl AsRef<Path>) -> Option<String> {
+        let path = path.as_ref();
+        let bytes = fs::read(self.path(path)).ok()?;
+        if let Source::Recording(recording) = &self.source {
+            if let Ok(mut recording) = recording.lock() {
+                recording.files.insert(path.to_path_buf(), bytes.clone());
+            }
+        }
+        Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Stdout of `program args...`, or `None` if it is missing or fails
+    pub fn run(&self, program: &str, args: &[&str]) -> Option<String> {
+        let key = command_key(program, args);
+        match &self.source {
+            Source::Snapshot(manifest) => manifest.commands.get(&key).cloned(),
+            Source::Live => run_command(program, args),
+            Source::Recording(recording) => {
+                let stdout = run_command(program, args)?;
+                // Binaries run by path (e.g. a Wine build in a prefix) reveal
+                // the user's directories and are not replayable anyway.
+                if program.contains('/') || program.contains('\\') {
+                    return Some(stdout);
+                }
+                if let Ok(mut recording) = recording.lock() {
+                    let recorded = if KERNEL_LOG_COMMANDS.contains(&program) {
+                        kernel_log_memory_lines(&stdout)
+                    } else {
+                        redact_command_output(&stdout)
+                    };
+                    recording.commands.insert(key, recorded);
+                }
+                Some(stdout)
+            }
+        }
+    }
+
+    /// A non-empty environment variable. Snapshots only carry the
+    /// allow-listed session variables.
+    pub fn var(&self, name: &str) -> Option<String> {
+        let value = match &self.source {
+            Source::Snapshot(manifest) => return manifest.env.get(name).cloned(),
+            _ => std::env::var(name).ok().filter(|value| !value.is_empty())?,
+        };
+        if let Source::Recording(recording) = &self.source {
+            if RECORDED_ENV.contains(&name) {
+                if let Ok(mut recording) = recording.lock() {
+                    recording.env.insert(name.to_string(), value.clone());
+                }
+            }
+        }
+        Some(value)
+    }
+
+    /// OS, memory and CPU facts (sysinfo on a live system)
+    pub fn host(&self) -> HostFacts {
+        match &self.source {
+            Source::Live => HostFacts::read_live(),
+            Source::Snapshot(manifest) => manifest.host.clone(),
+            Source::Recording(recording) => {
+                let mut recording = recording.lock().unwrap_or_else(|e| e.into_inner());
+                recording
+                    .host
+                    .get_or_insert_with(HostFacts::read_live)
+                    .clone()
+            }
+        }
+    }
+}
+
+fn command_key(program: &str, args: &[&str]) -> String {
+    std::iter::once(program)
+        .chain(args.iter().copied())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn run_command(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn kernel_log_memory_lines(stdout: &str) -> String {
+    stdout
+        .lines()
+        .filter(|line| line.contains("Memory:"))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+fn redact_command_output(stdout: &str) -> String {
+    let mut redacted = String::new();
+    let mut lines = stdout.lines().peekable();
+    while let Some(line) = lines.next() {
+        if REDACTED_LINE_MARKERS.iter().any(|m| line.contains(m)) {
+            continue;
+        }
+        redacted.push_str(line);
+        redacted.push('\n');
+        if line.trim() != "EDID:" {
+            continue;
+        }
+
+        // `xrandr --props` prints each EDID as indented hex rows after "EDID:".
+        let mut hex = String::new();
+        let mut indent = "";
+        while let Some(&row) = lines.peek() {
+            let trimmed = row.trim();
+            if trimmed.is_empty() || !trimmed.chars().all(|c| c.is_ascii_hexdigit()) {
+                break;
+            }
+            indent = &row[..row.len() - row.trim_start().len()];
+            hex.push_str(trimmed);
+            lines.next();
+        }
+        let bytes: Option<Vec<u8>> = (0..hex.len() / 2)
+            .map(|i| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok())
+            .collect();
+        for row in redact_edid(&bytes.unwrap_or_default()).chunks(16) {
+            redacted.push_str(indent);
+            for byte in row {
+                redacted.push_str(&format!("{:02x}", byte));
+            }
+            redacted.push('\n');
+        }
+    }
+    redacted
+}
+
+/// What `write_snapshot` captured
+#[derive(Debug, Clone)]
+pub struct SnapshotSummary {
+    pub files: usize,
+    pub commands: usize,
+    pub detect_error: Option<String>,
+}
+
+/// Run detection against the live system and write everything it (and the
+/// curated file list) read to `dir`, which must be empty or not exist yet.
+/// The focus backends are not run: their output describes the user's
+/// windows, not the hardware.
+pub fn write_snapshot(dir: &Path) -> Result<SnapshotSummary> {
+    if fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_some()) {
+        bail!("{} is not empty", dir.display());
+    }
+    let root = dir.join(ROOT_DIR);
+    fs::create_dir_all(&root).with_context(|| format!("Failed to create {}", root.display()))?;
+
+    let probe = Probe::recording();
+    let detect_error = SystemInfo::detect_with(&probe)
+        .err()
+        .map(|err| format!("{:#}", err));
+
+    let mut files = copy_snapshot_files(Path::new("/"), &root)?;
+    let Source::Recording(recording) = probe.source else {
+        unreachable!("write_snapshot always records");
+    };
+    let recording = (*recording).into_inner().unwrap_or_else(|e| e.into_inner());
+    for (path, bytes) in &recording.files {
+        let dest = root.join(path.strip_prefix("/").unwrap_or(path));
+        if dest.exists() || is_private_path(path) {
+            continue;
+        }
+        write_file(&dest, &redact_file(path, bytes))?;
+        files += 1;
+    }
+
+    let manifest = SnapshotManifest {
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        tracker_version: env!("CARGO_PKG_VERSION").to_string(),
+        captured_at: Utc::now(),
+        platform: std::env::consts::OS.to_string(),
+        host: recording.host.unwrap_or_else(HostFacts::read_live),
+        commands: recording.commands,
+        env: recording.env,
+        detect_error: detect_error.clone(),
+    };
+    fs::write(
+        dir.join(MANIFEST_FILE),
+        serde_json::to_string_pretty(&manifest)?,
+    )
+    .with_context(|| format!("Failed to write {}", dir.join(MANIFEST_FILE).display()))?;
+
+    Ok(SnapshotSummary {
+        files,
+        commands: manifest.commands.len(),
+        detect_error,
+    })
+}
+
+/// Copy `SNAPSHOT_FILES` from under `source_root` into `dest_root`
+fn copy_snapshot_files(source_root: &Path, dest_root: &Path) -> Result<usize> {
+    let mut copied = 0;
+    for (dir_pattern, names) in SNAPSHOT_FILES {
+        for dir in expand_pattern(source_root, dir_pattern) {
+            for name in names.iter() {
+                let relative = dir.join(name);
+                let source = source_root.join(&relative);
+                let Ok(metadata) = fs::metadata(&source) else {
+                    continue;
+                };
+                if !metadata.is_file() || metadata.len() > MAX_SNAPSHOT_FILE_BYTES {
+                    continue;
+                }
+                let Ok(bytes) = fs::read(&source) else {
+                    continue;
+                };
+                write_file(&dest_root.join(&relative), &redact_file(&relative, &bytes))?;
+                copied += 1;
+            }
+        }
+    }
+    Ok(copied)
+}
+
+/// Directories under `source_root` matching `pattern`, relative to it
+fn expand_pattern(source_root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut dirs = vec![PathBuf::new()];
+    for component in pattern.trim_start_matches('/').split('/') {
+        let mut next = Vec::new();
+        for dir in &dirs {
+            if !component.contains('*') {
+                next.push(dir.join(component));
+                continue;
+            }
+            let Ok(entries) = fs::read_dir(source_root.join(dir)) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if wildcard_match(component, &name) {
+                    next.push(dir.join(name));
+                }
+            }
+        }
+        dirs = next;
+    }
+    dirs.sort();
+    dirs
+}
+
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    for (index, part) in parts.iter().enumerate() {
+        if index == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    rest.is_empty()
+}
+
+/// Paths read during detection that must not leave the machine
+fn is_private_path(path: &Path) -> bool {
+    let text = path.to_string_lossy();
+    text.starts_with("/home")
+        || text.starts_with("/root")
+        || text.starts_with("/var/log")
+        || text == "/proc/kmsg"
+        || text.contains("serial")
+        || text.ends_with("/cmdline")
+}
+
+fn redact_file(path: &Path, bytes: &[u8]) -> Vec<u8> {
+    if path.file_name().is_some_and(|name| name == "edid") {
+        redact_edid(bytes)
+    } else {
+        bytes.to_vec()
+    }
+}
+
+/// Zero the serial number fields of an EDID base block and fix its checksum
+fn redact_edid(bytes: &[u8]) -> Vec<u8> {
+    let mut edid = bytes.to_vec();
+    if edid.len() < 128 {
+        return edid;
+    }
+    edid[12..16].fill(0);
+    // Descriptor type 0xFF is the monitor serial string.
+    for offset in [54, 72, 90, 108] {
+        if edid[offset..offset + 3] == [0, 0, 0] && edid[offset + 3] == 0xFF {
+            edid[offset + 5..offset + 18].fill(b' ');
+            edid[offset + 5] = b'\n';
+        }
+    }
+    let sum = edid[..127]
+        .iter()
+        .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    edid[127] = 0u8.wrapping_sub(sum);
+    edid
+}
+
+fn write_file(dest: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(dest, bytes).with_context(|| format!("Failed to write {}", dest.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("card*", "card1"));
+        assert!(wildcard_match("card*", "card1-DP-1"));
+        assert!(!wildcard_match("card*", "renderD128"));
+        assert!(wildcard_match("index*", "index3"));
+        assert!(wildcard_match("*", "nvme0n1"));
+        assert!(!wildcard_match("mc*", "power"));
+    }
+
+    #[test]
+    fn test_copy_snapshot_files_follows_patterns_and_redacts_edid() {
+        let source = TempDir::new().unwrap();
+        let write = |path: &str, bytes: &[u8]| write_file(&source.path().join(path), bytes);
+
+        let mut edid = vec![0u8; 128];
+        edid[..8].copy_from_slice(&[0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0]);
+        edid[12..16].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+        edid[108..111].copy_from_slice(&[0, 0, 0]);
+        edid[111] = 0xFF;
+        edid[113..122].copy_from_slice(b"SN123456\n");
+
+        write("sys/class/drm/card1-DP-1/edid", &edid).unwrap();
+        write("sys/class/drm/card1-DP-1/status", b"connected\n").unwrap();
+        write("sys/class/drm/card1/device/vendor", b"0x1002\n").unwrap();
+        write("sys/class/drm/card1/device/serial_number", b"secret\n").unwrap();
+        write("sys/block/nvme0n1/size", b"3907029168\n").unwrap();
+        write(
+            "sys/block/nvme0n1/device/model",
+            b"Samsung SSD 990 PRO 2TB\n",
+        )
+        .unwrap();
+        write("sys/block/nvme0n1/device/serial", b"S6B0NL0W123456\n").unwrap();
+        write("proc/cpuinfo", b"model name\t: AMD Ryzen 7 7800X3D\n").unwrap();
+        write("home/user/.bash_history", b"secret\n").unwrap();
+
+        let dest = TempDir::new().unwrap();
+        let copied = copy_snapshot_files(source.path(), dest.path()).unwrap();
+        assert_eq!(copied, 6);
+
+        let dest = dest.path();
+        assert!(dest.join("sys/class/drm/card1/device/vendor").exists());
+        assert!(dest.join("sys/block/nvme0n1/device/model").exists());
+        assert!(!dest.join("sys/block/nvme0n1/device/serial").exists());
+        assert!(!dest
+            .join("sys/class/drm/card1/device/serial_number")
+            .exists());
+        assert!(!dest.join("home").exists());
+
+        let redacted = fs::read(dest.join("sys/class/drm/card1-DP-1/edid")).unwrap();
+        assert_eq!(&redacted[12..16], &[0, 0, 0, 0]);
+        assert!(!redacted.windows(8).any(|window| window == b"SN123456"));
+        let sum = redacted
+            .iter()
+            .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+        assert_eq!(sum, 0);
+    }
+
+    fn write_manifest(dir: &Path, host: HostFacts, commands: &[(&str, &str)]) {
+        let manifest = SnapshotManifest {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            tracker_version: "0.0.0".to_string(),
+            captured_at: Utc::now(),
+            platform: std::env::consts::OS.to_string(),
+            host,
+            commands: commands
+                .iter()
+                .map(|(key, stdout)| (key.to_string(), stdout.to_string()))
+                .collect(),
+            env: BTreeMap::from([("XDG_SESSION_TYPE".to_string(), "wayland".to_string())]),
+            detect_error: None,
+        };
+        fs::write(
+            dir.join(MANIFEST_FILE),
+            serde_json::to_string(&manifest).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_probe_maps_paths_commands_and_env() {
+        let dir = TempDir::new().unwrap();
+        write_manifest(
+            dir.path(),
+            HostFacts::default(),
+            &[("lspci -D", "00:02.0 VGA\n")],
+        );
+        write_file(
+            &dir.path().join("root/proc/version"),
+            b"Linux version 6.9\n",
+        )
+        .unwrap();
+
+        let probe = Probe::snapshot(dir.path()).unwrap();
+        assert!(!probe.is_live());
+        assert_eq!(probe.path("/sys"), dir.path().join("root/sys"));
+        assert_eq!(
+            probe.read_to_string("/proc/version").as_deref(),
+            Some("Linux version 6.9\n")
+        );
+        assert_eq!(
+            probe.run("lspci", &["-D"]).as_deref(),
+            Some("00:02.0 VGA\n")
+        );
+        assert!(probe.run("nvidia-smi", &[]).is_none());
+        assert_eq!(probe.var("XDG_SESSION_TYPE").as_deref(), Some("wayland"));
+        assert!(probe.var("HOME").is_none());
+
+        let mut manifest: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(dir.path().join(MANIFEST_FILE)).unwrap())
+                .unwrap();
+        manifest["platform"] = "plan9".into();
+        fs::write(dir.path().join(MANIFEST_FILE), manifest.to_string()).unwrap();
+        assert!(Probe::snapshot(dir.path()).is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_detect_replays_linux_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let lspci = "VGA compatible controller: Advanced Micro Devices, Inc. [AMD/ATI] \
+                     Navi 31 [Radeon RX 7900 XTX] (rev c8)\n";
+        write_manifest(
+            dir.path(),
+            HostFacts {
+                os: Some("Arch Linux".to_string()),
+                os_version: Some("rolling".to_string()),
+                kernel_version: Some("6.10.5-arch1-1".to_string()),
+                total_memory_mb: 32_031,
+                cpu_brand: "AMD Ryzen 7 7800X3D 8-Core Processor".to_string(),
+                cpu_vendor: "AuthenticAMD".to_string(),
+                cpu_frequency_mhz: Some(4200),
+                cpu_threads: 16,
+                cpu_cores: Some(8),
+            },
+            &[
+                ("lspci", format!("03:00.0 {}", lspci).as_str()),
+                ("lspci -D", format!("0000:03:00.0 {}", lspci).as_str()),
+            ],
+        );
+        let root = dir.path().join(ROOT_DIR);
+        let write = |path: &str, content: &str| write_file(&root.join(path), content.as_bytes());
+        write("proc/meminfo", "MemTotal:       32800000 kB\n").unwrap();
+        write("sys/class/drm/card1/device/vendor", "0x1002\n").unwrap();
+        write("sys/class/drm/card1/device/device", "0x744c\n").unwrap();
+        write(
+            "sys/class/drm/card1/device/uevent",
+            "DRIVER=amdgpu\nPCI_SLOT_NAME=0000:03:00.0\n",
+        )
+        .unwrap();
+        write(
+            "sys/class/drm/card1/device/mem_info_vram_total",
+            "25753026560\n",
+        )
+        .unwrap();
+        write("sys/class/drm/card1/device/boot_vga", "1\n").unwrap();
+        write("sys/class/drm/card1-DP-1/status", "connected\n").unwrap();
+        write("sys/class/drm/card1-DP-1/enabled", "enabled\n").unwrap();
+        write("sys/class/drm/card1-DP-1/modes", "2560x1440\n1920x1080\n").unwrap();
+        write(
+            "sys/class/dmi/id/board_vendor",
+            "Gigabyte Technology Co., Ltd.\n",
+        )
+        .unwrap();
+        write("sys/class/dmi/id/board_name", "B650 AORUS ELITE AX\n").unwrap();
+        write("sys/block/nvme0n1/size", "3907029168\n").unwrap();
+        write("sys/block/nvme0n1/removable", "0\n").unwrap();
+        write(
+            "sys/block/nvme0n1/device/model",
+            "Samsung SSD 990 PRO 2TB\n",
+        )
+        .unwrap();
+
+        let info = SystemInfo::detect_with(&Probe::snapshot(dir.path()).unwrap()).unwrap();
+        assert_eq!(
+            info.gpu.name,
+            "Advanced Micro Devices, Inc. [AMD/ATI] Navi 31 [Radeon RX 7900 XTX]"
+        );
+        assert_eq!(info.gpu.vram_mb, Some(24560));
+        assert_eq!(info.gpus.len(), 1);
+        assert_eq!(info.cpu.name, "AMD Ryzen 7 7800X3D 8-Core Processor");
+        assert_eq!((info.cpu.cores, info.cpu.threads), (8, 16));
+        assert_eq!(info.ram.usable_mb, 32_031);
+        assert_eq!(info.ram.installed_mb, Some(32_768));
+        assert_eq!(info.os, "Arch Linux");
+        assert_eq!(info.kernel_version.as_deref(), Some("6.10.5-arch1-1"));
+        assert_eq!(
+            info.motherboard.and_then(|board| board.name()).as_deref(),
+            Some("Gigabyte Technology Co., Ltd. B650 AORUS ELITE AX")
+        );
+        assert_eq!(info.drives.len(), 1);
+        assert_eq!(info.drives[0].capacity_gb, 2000);
+        assert_eq!((info.displays.len(), info.displays[0].width), (1, 2560));
+        assert_eq!(
+            info.graphics_stack
+                .and_then(|stack| stack.display_server)
+                .as_deref(),
+            Some("Wayland")
+        );
+    }
+
+    #[test]
+    fn test_redact_command_output_drops_serials() {
+        let stdout = "Memory Device\n\tSize: 16 GB\n\tSerial Number: 0x1234\n\tAsset Tag: 9876\n";
+        assert_eq!(
+            redact_command_output(stdout),
+            "Memory Device\n\tSize: 16 GB\n"
+        );
+        let stdout = "Capacity    : 17179869184\r\nSerialNumber: 0x1234\n  \"class\": \"steam\",\n  \"title\": \"Inbox - Mail\",\n";
+        assert_eq!(
+            redact_command_output(stdout),
+            "Capacity    : 17179869184\n  \"class\": \"steam\",\n"
+        );
+
+        let mut edid = [0u8; 128];
+        edid[12..16].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+        let rows: String = edid
+            .chunks(16)
+            .map(|row| {
+                let hex: String = row.iter().map(|b| format!("{:02x}", b)).collect();
+                format!("\t\t{}\n", hex)
+            })
+            .collect();
+        let stdout = format!(
+            "DP-1 connected primary\n\tEDID: \n{}\tvrr_capable: 1\n",
+            rows
+        );
+        let redacted = redact_command_output(&stdout);
+        assert!(redacted.contains("\t\t000000000000000000000000000000"));
+        assert!(!redacted.contains("12345678"));
+        assert!(redacted.ends_with("\tvrr_capable: 1\n"));
+        assert_eq!(redacted.lines().count(), stdout.lines().count());
+    }
+}
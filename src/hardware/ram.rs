@@ -7,10 +7,11 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::process::Command;
 
 #[cfg(target_os = "linux")]
-use std::{fs, path::Path};
+use std::fs;
+
+use super::probe::Probe;
 
 #[cfg(target_os = "linux")]
 type DmidecodeInfo = (Option<u64>, Option<String>, Option<u32>, Option<String>);
@@ -34,7 +35,7 @@ pub struct RamInfo {
 
 impl RamInfo {
     /// Detect RAM information (platform-specific)
-    pub fn detect(usable_mb: u64) -> Result<Self> {
+    pub fn detect(probe: &Probe, usable_mb: u64) -> Result<Self> {
         let mut info = RamInfo {
             installed_mb: None,
             usable_mb,
@@ -47,16 +48,16 @@ impl RamInfo {
         #[cfg(target_os = "linux")]
         {
             // Get usable RAM from /proc/meminfo (always available, no root needed)
-            if let Some(mem_total) = Self::get_meminfo_total() {
+            if let Some(mem_total) = Self::get_meminfo_total(probe) {
                 info.usable_mb = mem_total;
             }
 
             // Try to get installed RAM from various sources (no root required)
-            info.installed_mb = Self::get_installed_ram_linux()
+            info.installed_mb = Self::get_installed_ram_linux(probe)
                 .or_else(|| Some(Self::estimate_installed_from_usable(info.usable_mb)));
 
             // Try to get detailed info from dmidecode (requires root, but we try anyway)
-            if let Ok((speed, ram_type, sticks, model)) = Self::get_dmidecode_info() {
+            if let Ok((speed, ram_type, sticks, model)) = Self::get_dmidecode_info(probe) {
                 info.speed_mhz = speed;
                 info.ram_type = ram_type;
                 info.stick_count = sticks;
@@ -65,23 +66,23 @@ impl RamInfo {
 
             // Fallback: try to detect RAM type and speed from other sources
             if info.ram_type.is_none() {
-                info.ram_type = Self::detect_ram_type_from_sys();
+                info.ram_type = Self::detect_ram_type_from_sys(probe);
             }
 
             if info.speed_mhz.is_none() {
-                info.speed_mhz = Self::get_speed_from_sys();
+                info.speed_mhz = Self::get_speed_from_sys(probe);
             }
 
             // Try to count sticks from sysfs
             if info.stick_count.is_none() {
-                info.stick_count = Self::count_sticks_from_sys();
+                info.stick_count = Self::count_sticks_from_sys(probe);
             }
         }
 
         #[cfg(target_os = "windows")]
         {
             // Get installed RAM from WMI
-            if let Some((installed, sticks)) = Self::get_windows_ram_info() {
+            if let Some((installed, sticks)) = Self::get_windows_ram_info(probe) {
                 info.installed_mb = Some(installed);
                 info.stick_count = sticks;
             } else {
@@ -89,20 +90,23 @@ impl RamInfo {
             }
 
             // Get RAM speed and type from WMI
-            if let Ok((speed, ram_type, model)) = Self::get_windows_ram_details() {
+            if let Ok((speed, ram_type, model)) = Self::get_windows_ram_details(probe) {
                 info.speed_mhz = speed;
                 info.ram_type = ram_type;
                 info.model = model;
             }
         }
 
+        #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+        let _ = probe;
+
         Ok(info)
     }
 
     /// Get total memory from /proc/meminfo (Linux only)
     #[cfg(target_os = "linux")]
-    fn get_meminfo_total() -> Option<u64> {
-        let content = fs::read_to_string("/proc/meminfo").ok()?;
+    fn get_meminfo_total(probe: &Probe) -> Option<u64> {
+        let content = probe.read_to_string("/proc/meminfo")?;
 
         for line in content.lines() {
             if line.starts_with("MemTotal:") {
@@ -121,14 +125,14 @@ impl RamInfo {
 
     /// Try to detect installed RAM from various sources on Linux
     #[cfg(target_os = "linux")]
-    fn get_installed_ram_linux() -> Option<u64> {
+    fn get_installed_ram_linux(probe: &Probe) -> Option<u64> {
         // Try to parse from dmesg logs
-        if let Some(mem) = Self::get_installed_from_dmesg() {
+        if let Some(mem) = Self::get_installed_from_dmesg(probe) {
             return Some(mem);
         }
 
         // Try /proc/kmsg
-        if let Some(mem) = Self::get_installed_from_kmsg() {
+        if let Some(mem) = Self::get_installed_from_kmsg(probe) {
             return Some(mem);
         }
 
@@ -157,11 +161,9 @@ impl RamInfo {
 
     /// Parse installed RAM from dmesg output (Linux only)
     #[cfg(target_os = "linux")]
-    fn get_installed_from_dmesg() -> Option<u64> {
+    fn get_installed_from_dmesg(probe: &Probe) -> Option<u64> {
         // Try to read from dmesg command
-        if let Ok(output) = Command::new("dmesg").output() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-
+        if let Some(stdout) = probe.run("dmesg", &[]) {
             // Look for patterns like:
             // "Memory: 16384000K/16777216K available"
             // "RAM: 16384 MB"
@@ -190,7 +192,7 @@ impl RamInfo {
         }
 
         // Try to read from /var/log/dmesg if available
-        if let Ok(content) = fs::read_to_string("/var/log/dmesg") {
+        if let Some(content) = probe.read_to_string("/var/log/dmesg") {
             for line in content.lines() {
                 if line.contains("Memory:") && line.contains("available") {
                     if let Some(idx) = line.find('/') {
@@ -219,9 +221,9 @@ impl RamInfo {
 
     /// Try to get installed RAM from /dev/kmsg or /proc/kmsg (Linux only)
     #[cfg(target_os = "linux")]
-    fn get_installed_from_kmsg() -> Option<u64> {
+    fn get_installed_from_kmsg(probe: &Probe) -> Option<u64> {
         // Try /proc/kmsg (might need root, but we try anyway)
-        if let Ok(content) = fs::read_to_string("/proc/kmsg") {
+        if let Some(content) = probe.read_to_string("/proc/kmsg") {
             let lines: Vec<&str> = content.lines().take(100).collect();
             for line in lines {
                 if line.contains("Memory:") {
@@ -248,7 +250,7 @@ impl RamInfo {
 
     /// Detect RAM type from sysfs (DDR4, DDR5, etc.) - Linux only
     #[cfg(target_os = "linux")]
-    fn detect_ram_type_from_sys() -> Option<String> {
+    fn detect_ram_type_from_sys(probe: &Probe) -> Option<String> {
         // Try to read from EDAC (Error Detection and Correction) if available
         let edac_paths = [
             "/sys/devices/system/edac/mc/mc0/",
@@ -256,10 +258,10 @@ impl RamInfo {
         ];
 
         for path in &edac_paths {
-            let mc_path = Path::new(path);
+            let mc_path = probe.path(path);
             if mc_path.exists() {
                 // Check for any files that might indicate memory type
-                if let Ok(entries) = fs::read_dir(mc_path) {
+                if let Ok(entries) = fs::read_dir(&mc_path) {
                     for entry in entries.flatten() {
                         let name = entry.file_name().to_string_lossy().to_string();
                         if name.contains("ddr4") || name.contains("DDR4") {
@@ -279,7 +281,7 @@ impl RamInfo {
 
     /// Get RAM speed from various sys sources - Linux only
     #[cfg(target_os = "linux")]
-    fn get_speed_from_sys() -> Option<u64> {
+    fn get_speed_from_sys(probe: &Probe) -> Option<u64> {
         // Try memory controller clock speed
         let paths = [
             "/sys/devices/system/edac/mc/mc0/clock_speed",
@@ -288,11 +290,9 @@ impl RamInfo {
         ];
 
         for path in &paths {
-            if Path::new(path).exists() {
-                if let Ok(content) = fs::read_to_string(path) {
-                    if let Ok(freq) = content.trim().parse::<u64>() {
-                        return Some(freq);
-                    }
+            if let Some(content) = probe.read_to_string(path) {
+                if let Ok(freq) = content.trim().parse::<u64>() {
+                    return Some(freq);
                 }
             }
         }
@@ -302,15 +302,15 @@ impl RamInfo {
 
     /// Count RAM sticks from sysfs - Linux only
     #[cfg(target_os = "linux")]
-    fn count_sticks_from_sys() -> Option<u32> {
+    fn count_sticks_from_sys(probe: &Probe) -> Option<u32> {
         // Try to count from memory controller info if available
         let mut count = 0;
 
         // Check if we can read EDAC (Error Detection and Correction) info
         // This requires kernel support and may not be available
-        let edac_path = Path::new("/sys/devices/system/edac/mc/");
+        let edac_path = probe.path("/sys/devices/system/edac/mc/");
         if edac_path.exists() {
-            if let Ok(entries) = fs::read_dir(edac_path) {
+            if let Ok(entries) = fs::read_dir(&edac_path) {
                 for entry in entries.flatten() {
                     let name = entry.file_name().to_string_lossy().to_string();
                     if name.starts_with("mc") && name.len() > 2 {
@@ -345,16 +345,12 @@ impl RamInfo {
 
     /// Get RAM info from dmidecode (Linux only, requires root)
     #[cfg(target_os = "linux")]
-    fn get_dmidecode_info() -> Result<DmidecodeInfo> {
-        let output = Command::new("dmidecode")
-            .args(["-t", "17"]) // Type 17 = Memory Device
-            .output()?;
-
-        if !output.status.success() {
+    fn get_dmidecode_info(probe: &Probe) -> Result<DmidecodeInfo> {
+        // Type 17 = Memory Device
+        let Some(stdout) = probe.run("dmidecode", &["-t", "17"]) else {
             anyhow::bail!("dmidecode failed or not available");
-        }
+        };
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
         let mut speed = None;
         let mut ram_type = None;
         let mut sticks = 0u32;
@@ -400,23 +396,17 @@ impl RamInfo {
 
     /// Get Windows RAM info using WMI
     #[cfg(target_os = "windows")]
-    fn get_windows_ram_info() -> Option<(u64, Option<u32>)> {
+    fn get_windows_ram_info(probe: &Probe) -> Option<(u64, Option<u32>)> {
         // Get total physical memory
-        let output = Command::new("wmic")
-            .args([
+        let stdout = probe.run(
+            "wmic",
+            &[
                 "ComputerSystem",
                 "get",
                 "TotalPhysicalMemory",
                 "/format:csv",
-            ])
-            .output()
-            .ok()?;
-
-        if !output.status.success() {
-            return None;
-        }
-
-        let stdout = String::from_utf8_lossy(&output.stdout);
+            ],
+        )?;
         let mut total_mb = 0u64;
 
         // Parse CSV output (skip header)
@@ -432,14 +422,8 @@ impl RamInfo {
         }
 
         // Get number of memory devices
-        let output = Command::new("wmic")
-            .args(["MemoryChip", "get", "Capacity", "/format:csv"])
-            .output()
-            .ok()?;
-
         let mut stick_count = 0u32;
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
+        if let Some(stdout) = probe.run("wmic", &["MemoryChip", "get", "Capacity", "/format:csv"]) {
             // Count non-empty lines (skip header)
             stick_count = stdout
                 .lines()
@@ -464,21 +448,21 @@ impl RamInfo {
 
     /// Get Windows RAM details (speed, type, model) using WMI
     #[cfg(target_os = "windows")]
-    fn get_windows_ram_details() -> Result<(Option<u64>, Option<String>, Option<String>)> {
-        let output = Command::new("wmic")
-            .args([
+    fn get_windows_ram_details(
+        probe: &Probe,
+    ) -> Result<(Option<u64>, Option<String>, Option<String>)> {
+        let Some(stdout) = probe.run(
+            "wmic",
+            &[
                 "MemoryChip",
                 "get",
                 "Speed,SMBIOSMemoryType,PartNumber",
                 "/format:csv",
-            ])
-            .output()?;
-
-        if !output.status.success() {
+            ],
+        ) else {
             anyhow::bail!("wmic failed");
-        }
+        };
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
         let mut speed = None;
         let mut ram_type = None;
         let mut model = None;
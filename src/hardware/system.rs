@@ -2,17 +2,29 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use sysinfo::System;
 
+use super::adapters::{self, GpuAdapter, GpuSelection, GpuSelectionHints};
+use super::board::MotherboardInfo;
 use super::cpu::CpuInfo;
+use super::display::{self, DisplayInfo};
+use super::drives::{self, DriveInfo, DriveKind};
+use super::fingerprint::HardwareIdentity;
 use super::gpu::GpuInfo;
+use super::graphics::{self, GraphicsStack};
+use super::probe::Probe;
 use super::ram::RamInfo;
 
 /// Complete system information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemInfo {
-    /// GPU info
+    /// GPU info (the adapter believed to render the game)
     pub gpu: GpuInfo,
+    /// Every display adapter found (iGPU and dGPU on hybrid systems)
+    #[serde(default)]
+    pub gpus: Vec<GpuAdapter>,
+    /// Which entry of `gpus` was picked as the render GPU, and why
+    #[serde(default)]
+    pub gpu_selection: Option<GpuSelection>,
     /// CPU info
     pub cpu: CpuInfo,
     /// RAM info
@@ -21,32 +33,135 @@ pub struct SystemInfo {
     pub os: String,
     /// OS version
     pub os_version: Option<String>,
+    /// Kernel version (Linux kernel release, Windows build)
+    #[serde(default)]
+    pub kernel_version: Option<String>,
+    /// Mesa/Vulkan driver, display server and Proton/Wine/DXVK versions (Linux)
+    #[serde(default)]
+    pub graphics_stack: Option<GraphicsStack>,
+    /// Connected monitors and their modes
+    #[serde(default)]
+    pub displays: Vec<DisplayInfo>,
+    /// Motherboard and BIOS
+    #[serde(default)]
+    pub motherboard: Option<MotherboardInfo>,
+    /// Physical storage drives, largest first
+    #[serde(default)]
+    pub drives: Vec<DriveInfo>,
 }
 
 impl SystemInfo {
     /// Detect all system information
     pub fn detect() -> Result<Self> {
-        let gpu = GpuInfo::detect()?;
-        let cpu = CpuInfo::detect()?;
+        Self::detect_with(&Probe::live())
+    }
 
-        let mut sys = System::new();
-        sys.refresh_memory();
+    /// Detect through `probe` (the live system or a captured snapshot)
+    pub fn detect_with(probe: &Probe) -> Result<Self> {
+        let mut gpu = GpuInfo::detect(probe)?;
+        let gpus = adapters::detect_adapters(probe);
+        let gpu_selection =
+            adapters::select_render_adapter(&gpus, &GpuSelectionHints::from_env(probe));
+        if let Some(selected) = gpu_selection.as_ref().map(|s| &gpus[s.adapter_index]) {
+            // The single-GPU probes report whichever adapter they saw first;
+            // swap in the selected one when it is a different device.
+            if !is_same_device(&gpu, selected, &gpus) {
+                gpu = selected.to_gpu_info();
+            } else {
+                gpu.pci_id = gpu.pci_id.or_else(|| selected.pci_id.clone());
+                gpu.vram_mb = gpu.vram_mb.or(selected.vram_mb);
+                gpu.driver_version = gpu
+                    .driver_version
+                    .or_else(|| selected.driver_version.clone());
+            }
+            #[cfg(target_os = "linux")]
+            gpu.apply_sysfs_stats(&probe.path("/sys"), selected.pci_slot.as_deref());
+        }
+        let cpu = CpuInfo::detect(probe)?;
 
-        let ram_mb = sys.total_memory() / 1024 / 1024; // bytes to MB
-        let ram = RamInfo::detect(ram_mb)?;
+        let host = probe.host();
+        let ram = RamInfo::detect(probe, host.total_memory_mb)?;
 
-        let os = System::name().unwrap_or_else(|| "Unknown".to_string());
-        let os_version = System::os_version();
+        let os = host.os.unwrap_or_else(|| "Unknown".to_string());
+        let os_version = host.os_version;
+        let kernel_version = host.kernel_version.or_else(|| {
+            probe
+                .read_to_string("/proc/version")
+                .and_then(|text| graphics::kernel_from_proc_version(&text))
+        });
+
+        // lspci leaves a slot in `gpu.pci_id`; the adapter inventory has vendor:device.
+        let render_pci_id = gpu_selection
+            .as_ref()
+            .and_then(|selection| gpus[selection.adapter_index].pci_id.clone())
+            .or_else(|| gpu.pci_id.clone());
+        let graphics_stack = Some(GraphicsStack::detect(probe, render_pci_id.as_deref()))
+            .filter(|stack| !stack.is_empty());
+        if let Some(stack) = &graphics_stack {
+            // Mesa drivers have no version of their own; Mesa's is the useful one.
+            if gpu.driver_version.is_none() {
+                gpu.driver_version = stack
+                    .mesa_version
+                    .as_ref()
+                    .map(|mesa| format!("Mesa {}", mesa));
+            }
+        }
 
         Ok(SystemInfo {
             gpu,
+            gpus,
+            gpu_selection,
             cpu,
             ram,
             os,
             os_version,
+            kernel_version,
+            graphics_stack,
+            displays: display::detect_displays(probe),
+            motherboard: MotherboardInfo::detect(probe),
+            drives: drives::detect_drives(probe),
         })
     }
 
+    /// The primary display (or the first one found)
+    pub fn primary_display(&self) -> Option<&DisplayInfo> {
+        self.displays
+            .iter()
+            .find(|display| display.primary)
+            .or_else(|| self.displays.first())
+    }
+
+    /// Fastest kind of drive installed (NVMe > SATA SSD > HDD)
+    pub fn fastest_drive_kind(&self) -> Option<DriveKind> {
+        [DriveKind::Nvme, DriveKind::SataSsd, DriveKind::Hdd]
+            .into_iter()
+            .find(|kind| self.drives.iter().any(|drive| drive.kind == *kind))
+    }
+
+    /// Salted, anonymised fingerprint of the normalised hardware (see `fingerprint`).
+    pub fn hardware_fingerprint(&self) -> String {
+        HardwareIdentity::from_system_info(self).fingerprint()
+    }
+
+    /// Apply a MangoHud log's system header. It describes the captured session,
+    /// so its kernel and driver win over the detected ones.
+    pub fn apply_mangohud_header(&mut self, header: &[(String, String)]) {
+        self.graphics_stack
+            .get_or_insert_with(Default::default)
+            .apply_mangohud_header(header);
+        for (key, value) in header {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match key.as_str() {
+                "kernel" => self.kernel_version = Some(value.to_string()),
+                "driver" => self.gpu.driver_version = Some(graphics::driver_version_label(value)),
+                _ => {}
+            }
+        }
+    }
+
     /// Display system info as formatted string
     pub fn display(&self) -> String {
         const WIDTH: usize = 62;
@@ -96,9 +211,30 @@ impl SystemInfo {
         if let Some(util) = self.gpu.utilization_percent {
             output.push_str(&format_line("      ", &format!("Utilization: {}%", util)));
         }
+        if let Some(power) = self.gpu.power_w {
+            output.push_str(&format_line("      ", &format!("Power: {:.0} W", power)));
+        }
         if let Some(ref driver) = self.gpu.driver_version {
             output.push_str(&format_line("      ", &format!("Driver: {}", driver)));
         }
+        if self.gpus.len() > 1 {
+            if let Some(ref selection) = self.gpu_selection {
+                output.push_str(&format_line(
+                    "      ",
+                    &format!("Render GPU: picked by {}", selection.reason),
+                ));
+            }
+            for (index, adapter) in self.gpus.iter().enumerate() {
+                if self.gpu_selection.as_ref().map(|s| s.adapter_index) == Some(index) {
+                    continue;
+                }
+                let kind = if adapter.integrated { "iGPU" } else { "dGPU" };
+                output.push_str(&format_line(
+                    "      ",
+                    &format!("Also: {} ({})", adapter.name, kind),
+                ));
+            }
+        }
 
         output.push_str(&format!("╠{}╣\n", "═".repeat(WIDTH)));
 
@@ -120,6 +256,12 @@ impl SystemInfo {
         if let Some(ref arch) = self.cpu.architecture {
             output.push_str(&format_line("      ", &format!("Architecture: {}", arch)));
         }
+        if let Some(ref topology) = self.cpu.topology {
+            let summary = topology.summary();
+            if !summary.is_empty() {
+                output.push_str(&format_line("      ", &summary));
+            }
+        }
 
         output.push_str(&format!("╠{}╣\n", "═".repeat(WIDTH)));
 
@@ -161,9 +303,231 @@ impl SystemInfo {
             None => self.os.clone(),
         };
         output.push_str(&format_line("OS:  ", &os_str));
+        if let Some(ref kernel) = self.kernel_version {
+            output.push_str(&format_line("      ", &format!("Kernel: {}", kernel)));
+        }
+
+        // Board and storage section
+        if self.motherboard.is_some() || !self.drives.is_empty() {
+            output.push_str(&format!("╠{}╣\n", "═".repeat(WIDTH)));
+            if let Some(ref board) = self.motherboard {
+                if let Some(name) = board.name() {
+                    output.push_str(&format_line("Board: ", &name));
+                }
+                if let Some(ref version) = board.bios_version {
+                    let bios = match board.bios_date {
+                        Some(ref date) => format!("BIOS: {} ({})", version, date),
+                        None => format!("BIOS: {}", version),
+                    };
+                    output.push_str(&format_line("       ", &bios));
+                }
+            }
+            for (index, drive) in self.drives.iter().enumerate() {
+                let label = if index == 0 { "Disk:  " } else { "       " };
+                output.push_str(&format_line(label, &drive.summary()));
+            }
+        }
+
+        // Display section
+        if !self.displays.is_empty() {
+            output.push_str(&format!("╠{}╣\n", "═".repeat(WIDTH)));
+            for (index, display) in self.displays.iter().enumerate() {
+                let label = if index == 0 { "Display: " } else { "         " };
+                output.push_str(&format_line(label, &display.summary()));
+                if let Some(ref name) = display.name {
+                    output.push_str(&format_line(
+                        "         ",
+                        &format!("{} ({})", name, display.connector),
+                    ));
+                }
+            }
+        }
+
+        // Graphics stack section (Linux)
+        if let Some(ref stack) = self.graphics_stack {
+            output.push_str(&format!("╠{}╣\n", "═".repeat(WIDTH)));
+            let mut lines: Vec<(&str, String)> = Vec::new();
+            if let Some(ref driver) = stack.vulkan_driver {
+                let mut vulkan = driver.clone();
+                if let Some(ref version) = stack.vulkan_driver_version {
+                    vulkan.push_str(&format!(" {}", version));
+                }
+                if let Some(ref api) = stack.vulkan_api_version {
+                    vulkan.push_str(&format!(" (Vulkan {})", api));
+                }
+                lines.push(("Vulkan: ", vulkan));
+            }
+            if let Some(ref mesa) = stack.mesa_version {
+                lines.push(("Mesa: ", mesa.clone()));
+            }
+            if let Some(ref server) = stack.display_server {
+                let display = match stack.compositor {
+                    Some(ref compositor) => format!("{} / {}", server, compositor),
+                    None => server.clone(),
+                };
+                lines.push(("Session: ", display));
+            }
+            if let Some(ref proton) = stack.proton_version {
+                lines.push(("Proton: ", proton.clone()));
+            }
+            if let Some(ref wine) = stack.wine_version {
+                lines.push(("Wine: ", wine.clone()));
+            }
+            if let Some(ref dxvk) = stack.dxvk_version {
+                lines.push(("DXVK: ", dxvk.clone()));
+            }
+            if let Some(ref vkd3d) = stack.vkd3d_version {
+                lines.push(("VKD3D: ", vkd3d.clone()));
+            }
+            for (label, content) in lines {
+                output.push_str(&format_line(label, &content));
+            }
+        }
 
         output.push_str(&format!("╚{}╝", "═".repeat(WIDTH)));
 
         output
     }
 }
+
+/// Whether the single-GPU probe result describes the selected adapter.
+///
+/// Probes report either a vendor:device id or a PCI slot (lspci, without the
+/// domain). Without either, the vendor only settles it when no other adapter
+/// shares it.
+fn is_same_device(gpu: &GpuInfo, selected: &GpuAdapter, adapters: &[GpuAdapter]) -> bool {
+    match gpu.pci_id.as_deref().map(str::to_lowercase) {
+        Some(id) => {
+            selected.pci_id.as_deref() == Some(id.as_str())
+                || selected
+                    .pci_slot
+                    .as_deref()
+                    .is_some_and(|slot| slot == id || slot.ends_with(&format!(":{id}")))
+        }
+        None => {
+            gpu.vendor == selected.vendor
+                && adapters
+                    .iter()
+                    .filter(|adapter| adapter.vendor == selected.vendor)
+                    .count()
+                    == 1
+        }
+    }
+}
+
+#[cfg(test)]
+impl SystemInfo {
+    /// A fixed desktop (RTX 4070 SUPER, Ryzen 7 7800X3D, 32 GB DDR5) for tests.
+    pub fn test_fixture() -> Self {
+        SystemInfo {
+            gpu: GpuInfo {
+                name: "NVIDIA RTX 4070 SUPER".to_string(),
+                vendor: super::gpu::GpuVendor::Nvidia,
+                vram_mb: Some(12_288),
+                driver_version: Some("551.23".to_string()),
+                pci_id: None,
+                gpu_clock_mhz: None,
+                memory_clock_mhz: None,
+                temperature_c: None,
+                utilization_percent: None,
+                power_w: None,
+            },
+            cpu: CpuInfo {
+                name: "AMD Ryzen 7 7800X3D".to_string(),
+                cores: 8,
+                threads: 16,
+                frequency_mhz: Some(4200),
+                vendor: "AMD".to_string(),
+                architecture: Some("x86_64".to_string()),
+                max_frequency_mhz: Some(5000),
+                topology: None,
+            },
+            ram: RamInfo {
+                installed_mb: Some(32_768),
+                usable_mb: 31_990,
+                speed_mhz: Some(6000),
+                ram_type: Some("DDR5".to_string()),
+                stick_count: Some(2),
+                model: Some("Test RAM".to_string()),
+            },
+            os: "Linux".to_string(),
+            os_version: Some("6.8".to_string()),
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::gpu::GpuVendor;
+
+    fn nvidia_adapter(name: &str, pci_id: &str, slot: &str) -> GpuAdapter {
+        GpuAdapter {
+            name: name.to_string(),
+            vendor: GpuVendor::Nvidia,
+            vram_mb: None,
+            pci_id: Some(pci_id.to_string()),
+            pci_slot: Some(slot.to_string()),
+            driver: None,
+            driver_version: None,
+            integrated: false,
+            boot_vga: false,
+        }
+    }
+
+    #[test]
+    fn mangohud_header_overrides_kernel_and_driver() {
+        let mut info = SystemInfo {
+            kernel_version: Some("6.10.0".to_string()),
+            ..SystemInfo::test_fixture()
+        };
+        info.apply_mangohud_header(&[
+            ("kernel".to_string(), "6.9.1-arch1-1".to_string()),
+            (
+                "driver".to_string(),
+                "4.6 (Core Profile) Mesa 24.1.0".to_string(),
+            ),
+            ("dxvk".to_string(), "v2.4".to_string()),
+        ]);
+        assert_eq!(info.kernel_version.as_deref(), Some("6.9.1-arch1-1"));
+        assert_eq!(info.gpu.driver_version.as_deref(), Some("Mesa 24.1.0"));
+        let stack = info.graphics_stack.unwrap();
+        assert_eq!(stack.mesa_version.as_deref(), Some("24.1.0"));
+        assert_eq!(stack.dxvk_version.as_deref(), Some("2.4"));
+    }
+
+    #[test]
+    fn probe_result_is_matched_to_the_selected_adapter_by_pci_address() {
+        let adapters = vec![
+            nvidia_adapter("NVIDIA GeForce GTX 1650", "10de:1f82", "0000:01:00.0"),
+            nvidia_adapter("NVIDIA GeForce RTX 4070", "10de:2786", "0000:02:00.0"),
+        ];
+        let selected = &adapters[1];
+
+        let lspci = GpuInfo {
+            pci_id: Some("01:00.0".to_string()),
+            ..SystemInfo::test_fixture().gpu
+        };
+        assert!(!is_same_device(&lspci, selected, &adapters));
+        let lspci = GpuInfo {
+            pci_id: Some("02:00.0".to_string()),
+            ..lspci
+        };
+        assert!(is_same_device(&lspci, selected, &adapters));
+
+        // nvidia-smi reports no address; two cards of one vendor are ambiguous.
+        let nvidia_smi = GpuInfo {
+            pci_id: None,
+            ..lspci
+        };
+        assert!(!is_same_device(&nvidia_smi, selected, &adapters));
+        assert!(is_same_device(&nvidia_smi, selected, &adapters[1..]));
+    }
+}
@@ -0,0 +1,314 @@
+//! MSI Afterburner / RTSS hardware monitoring log parser
+//!
+//! Afterburner writes `.hml` logs when "Log history to file" is enabled.
+//! Every line starts with a record type and a timestamp:
+//!
+//!   00, 16-10-2024 20:15:41, Hardware monitoring log v1.6
+//!   01, 16-10-2024 20:15:41, NVIDIA GeForce RTX 3070
+//!   02, 16-10-2024 20:15:41, GPU temperature ,GPU usage ,Framerate ,Frametime
+//!   80, 16-10-2024 20:15:42, 55.000 ,97.000 ,143.2 ,6.9
+//!
+//! Type 02 names the columns and type 80 rows hold the samples. Some locales
+//! write `;` as the delimiter and `,` as the decimal separator.
+//!
+//! Rows are taken at the Afterburner polling interval rather than once per
+//! frame, so each "Frametime" value is one sample of the current frame time.
+//! The result is marked `sampled`: the average is time-weighted and no
+//! 1%/0.1% lows are reported.
+
+use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use super::common::{FrameData, FrameTelemetry};
+
+/// Tool name reported for Afterburner logs.
+pub const AFTERBURNER_SOURCE: &str = "MSI Afterburner";
+
+const TIMESTAMP_FORMAT: &str = "%d-%m-%Y %H:%M:%S";
+
+/// Whether a line is the Afterburner log preamble (`00, <time>, Hardware monitoring log`).
+pub fn looks_like_afterburner_line(line: &str) -> bool {
+    let fields = split_hml_line(line);
+    fields.first() == Some(&"00")
+        && fields.iter().any(|field| {
+            field
+                .to_ascii_lowercase()
+                .contains("hardware monitoring log")
+        })
+}
+
+/// Parse an Afterburner `.hml` log file
+pub fn parse_afterburner_hml<P: AsRef<Path>>(path: P) -> Result<FrameData> {
+    let file = File::open(path.as_ref())
+        .with_context(|| format!("Failed to open file: {:?}", path.as_ref()))?;
+    let reader = BufReader::new(file);
+
+    let mut frametime_col: Option<usize> = None;
+    let mut gpu_temp_col: Option<usize> = None;
+    let mut gpu_usage_col: Option<usize> = None;
+
+    let mut frame_times: Vec<f64> = Vec::new();
+    let mut gpu_temp_c: Vec<f64> = Vec::new();
+    let mut gpu_load_pct: Vec<f64> = Vec::new();
+    let mut first_sample_at: Option<NaiveDateTime> = None;
+    let mut last_sample_at: Option<NaiveDateTime> = None;
+
+    for line_result in reader.lines() {
+        // Unit strings such as "°C" (type 03 rows) are written in the system
+        // code page; those lines are not needed, so skip anything that is not UTF-8.
+        let line = match line_result {
+            Ok(line) => line,
+            Err(_) => continue,
+        };
+        let fields = split_hml_line(&line);
+        let (Some(record_type), Some(timestamp)) = (fields.first(), fields.get(1)) else {
+            continue;
+        };
+        let values = &fields[2..];
+
+        match *record_type {
+            "02" => {
+                let names: Vec<String> = values.iter().map(|v| v.to_ascii_lowercase()).collect();
+                frametime_col = names.iter().position(|name| name == "frametime");
+                gpu_temp_col = names
+                    .iter()
+                    .position(|name| is_gpu_metric(name, "temperature"));
+                gpu_usage_col = names.iter().position(|name| is_gpu_metric(name, "usage"));
+            }
+            "80" => {
+                let Some(col) = frametime_col else {
+                    continue;
+                };
+                let Some(ms) = values.get(col).and_then(|value| parse_hml_value(value)) else {
+                    continue;
+                };
+                if ms <= 0.0 || ms > 10_000.0 {
+                    continue;
+                }
+
+                frame_times.push(ms);
+                if let Some(col) = gpu_temp_col {
+                    gpu_temp_c.push(
+                        values
+                            .get(col)
+                            .and_then(|v| parse_hml_value(v))
+                            .unwrap_or(f64::NAN),
+                    );
+                }
+                if let Some(col) = gpu_usage_col {
+                    gpu_load_pct.push(
+                        values
+                            .get(col)
+                            .and_then(|v| parse_hml_value(v))
+                            .unwrap_or(f64::NAN),
+                    );
+                }
+
+                if let Ok(at) = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT) {
+                    first_sample_at.get_or_insert(at);
+                    last_sample_at = Some(at);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if frametime_col.is_none() {
+        anyhow::bail!(
+            "No Frametime column found in Afterburner log. Enable the \"Frametime\" graph and \"Log history to file\" in Afterburner monitoring settings."
+        );
+    }
+    if frame_times.is_empty() {
+        anyhow::bail!("No frame time data found in Afterburner log");
+    }
+
+    // Samples are polled, so wall-clock time is the honest duration.
+    let logged_secs = match (first_sample_at, last_sample_at) {
+        (Some(first), Some(last)) => (last - first).num_milliseconds() as f64 / 1000.0,
+        _ => 0.0,
+    };
+    let duration_secs = if logged_secs > 0.0 {
+        logged_secs
+    } else {
+        frame_times.iter().sum::<f64>() / 1000.0
+    };
+
+    Ok(FrameData {
+        frame_times_ms: frame_times,
+        application: None,
+        duration_secs,
+        source: AFTERBURNER_SOURCE.to_string(),
+        telemetry: FrameTelemetry {
+            gpu_temp_c,
+            gpu_load_pct,
+            ..FrameTelemetry::default()
+        },
+        sampled: true,
+    })
+}
+
+/// Find the most recent Afterburner log in the default install locations
+pub fn find_latest_afterburner_log() -> Option<PathBuf> {
+    let mut candidate_dirs: Vec<PathBuf> = ["ProgramFiles(x86)", "ProgramFiles"]
+        .iter()
+        .filter_map(std::env::var_os)
+        .map(|dir| PathBuf::from(dir).join("MSI Afterburner"))
+        .collect();
+    if let Some(documents) = directories::UserDirs::new()
+        .and_then(|dirs| dirs.document_dir().map(|dir| dir.join("MSI Afterburner")))
+    {
+        candidate_dirs.push(documents);
+    }
+
+    candidate_dirs
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.filter_map(|entry| entry.ok()))
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .map(|ext| ext.eq_ignore_ascii_case("hml"))
+                .unwrap_or(false)
+        })
+        .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok())
+        .map(|entry| entry.path())
+}
+
+/// Split an HML line on its delimiter and trim each field.
+fn split_hml_line(line: &str) -> Vec<&str> {
+    let delimiter = if line.contains(';') { ';' } else { ',' };
+    line.split(delimiter).map(str::trim).collect()
+}
+
+/// Parse a sample value, accepting `,` decimals and Afterburner's "N/A".
+fn parse_hml_value(value: &str) -> Option<f64> {
+    let value = value.trim().replace(',', ".");
+    value.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// Match "GPU usage" / "GPU1 usage" style column names.
+fn is_gpu_metric(name: &str, metric: &str) -> bool {
+    let Some(rest) = name.strip_prefix("gpu") else {
+        return false;
+    };
+    let rest = rest.trim_start_matches(|c: char| c.is_ascii_digit());
+    rest.trim() == metric
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_parse_afterburner_hml() {
+        let hml = "00, 16-10-2024 20:15:41, Hardware monitoring log v1.6
+01, 16-10-2024 20:15:41, NVIDIA GeForce RTX 3070
+02, 16-10-2024 20:15:41, GPU1 temperature ,GPU1 usage ,Framerate ,Frametime
+80, 16-10-2024 20:15:42, 55.000 ,97.000 ,143.2 ,6.9
+80, 16-10-2024 20:15:43, 56.000 ,N/A ,140.1 ,7.1
+80, 16-10-2024 20:15:44, 56.000 ,98.000 ,0.0 ,0.0
+80, 16-10-2024 20:15:45, 57.000 ,99.000 ,138.0 ,7.2
+";
+        assert!(looks_like_afterburner_line(hml.lines().next().unwrap()));
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", hml).unwrap();
+
+        let result = parse_afterburner_hml(file.path()).unwrap();
+        assert_eq!(result.frame_times_ms, vec![6.9, 7.1, 7.2]);
+        assert_eq!(result.source, AFTERBURNER_SOURCE);
+        assert!((result.duration_secs - 3.0).abs() < 1e-9);
+        assert_eq!(result.telemetry.gpu_temp_c, vec![55.0, 56.0, 57.0]);
+        assert_eq!(result.telemetry.gpu_load_pct.len(), 3);
+        assert!(result.telemetry.gpu_load_pct[1].is_nan());
+        assert!(result.sampled);
+    }
+
+    #[test]
+    fn test_afterburner_samples_report_time_weighted_average_and_no_lows() {
+        let mut hml = String::from(
+            "00, 16-10-2024 20:15:41, Hardware monitoring log v1.6\n\
+02, 16-10-2024 20:15:41, Framerate ,Frametime\n",
+        );
+        // Half the time at 100 FPS, half at 50 FPS.
+        for i in 0..200 {
+            let ms = if i < 100 { 10.0 } else { 20.0 };
+            hml.push_str(&format!("80, 16-10-2024 20:15:42, 0 ,{ms}\n"));
+        }
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", hml).unwrap();
+
+        let stats = parse_afterburner_hml(file.path())
+            .unwrap()
+            .calculate_stats()
+            .unwrap();
+        assert!((stats.avg_fps - 75.0).abs() < 1e-9);
+        assert_eq!(stats.fps_1_low, None);
+        assert_eq!(stats.fps_01_low, None);
+        assert_eq!(stats.frame_time_stats.fps_1_low_avg, None);
+    }
+
+    #[test]
+    fn test_afterburner_log_trims_by_polling_interval() {
+        use crate::import::trim::{TrimAmount, TrimOptions};
+
+        // 60 one-second polls at ~7 ms per frame.
+        let mut hml = String::from(
+            "00, 16-10-2024 20:15:00, Hardware monitoring log v1.6\n\
+02, 16-10-2024 20:15:00, Framerate ,Frametime\n",
+        );
+        for second in 0..60 {
+            hml.push_str(&format!("80, 16-10-2024 20:16:{second:02}, 143.0 ,7.0\n"));
+        }
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", hml).unwrap();
+        let data = parse_afterburner_hml(file.path()).unwrap();
+
+        let (trimmed, report) = data
+            .trimmed(&TrimOptions {
+                start: Some(TrimAmount::Seconds(10.0)),
+                end: Some(TrimAmount::Seconds(5.0)),
+                auto_detect: true,
+            })
+            .unwrap();
+        let interval_secs = data.duration_secs / 60.0;
+        assert_eq!(report.leading_frames, 10);
+        assert_eq!(report.trailing_frames, 5);
+        assert_eq!(report.auto_leading_frames + report.auto_trailing_frames, 0);
+        assert_eq!(trimmed.frame_times_ms.len(), 45);
+        assert!((report.leading_secs - 10.0 * interval_secs).abs() < 1e-9);
+        assert!((trimmed.duration_secs - 45.0 * interval_secs).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_afterburner_hml_with_semicolons_and_decimal_commas() {
+        let hml = "00; 16-10-2024 20:15:41; Hardware monitoring log v1.6
+02; 16-10-2024 20:15:41; Framerate ;Frametime
+80; 16-10-2024 20:15:42; 60,0 ;16,7
+80; 16-10-2024 20:15:43; 59,5 ;16,8
+";
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", hml).unwrap();
+
+        let result = parse_afterburner_hml(file.path()).unwrap();
+        assert_eq!(result.frame_times_ms, vec![16.7, 16.8]);
+        assert_eq!(result.telemetry, FrameTelemetry::default());
+    }
+
+    #[test]
+    fn test_afterburner_log_without_frametime_is_rejected() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            "00, 16-10-2024 20:15:41, Hardware monitoring log v1.6\n02, 16-10-2024 20:15:41, GPU usage\n80, 16-10-2024 20:15:42, 97.0\n"
+        )
+        .unwrap();
+        assert!(parse_afterburner_hml(file.path()).is_err());
+    }
+}
@@ -0,0 +1,500 @@
+//! CPU- vs GPU-bound classification
+//!
+//! Every frame gets a GPU utilisation figure. PresentMon's `GPUBusy` is the
+//! preferred source, expressed as a share of the frame time. MangoHud or
+//! Afterburner `gpu_load` samples are the fallback. A GPU that stays busy
+//! for the whole frame is the limiter. A GPU that idles for a large part of
+//! each frame is waiting on the CPU: game logic, draw submission or the driver.
+//!
+//! Shares are weighted by frame time, so they read as "share of capture time".
+//! A frame cap or vsync also leaves the GPU idle. When frame times cluster at
+//! a common refresh interval or cap and the CPU is not saturated either, the
+//! run is reported as limited rather than CPU-bound.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::{FrameTelemetry, TelemetryStat};
+
+/// GPU utilisation (%) at or above which a frame counts as GPU-bound.
+pub const GPU_BOUND_UTIL_PCT: f64 = 95.0;
+/// GPU utilisation (%) below which a frame counts as CPU-bound.
+pub const CPU_BOUND_UTIL_PCT: f64 = 80.0;
+/// Share of time (%) one side needs before it wins the verdict.
+const DOMINANT_SHARE_PCT: f64 = 60.0;
+/// Target length of each reported segment.
+const SEGMENT_SECS: f64 = 10.0;
+/// Share of time (%) near one frame interval that marks a cap or vsync.
+const LIMITED_SHARE_PCT: f64 = 85.0;
+/// Relative distance from the median frame time that still counts as "at the cap".
+const LIMITED_TOLERANCE: f64 = 0.03;
+/// CPU utilisation (%) at or above which a steady run is CPU-bound, not capped.
+const CPU_SATURATED_UTIL_PCT: f64 = 90.0;
+/// Fewest frames needed before clustering means anything.
+const MIN_FRAMES_FOR_LIMIT: usize = 30;
+/// Refresh rates and frame caps (FPS) a limited run usually settles on.
+const COMMON_FRAME_RATE_LIMITS: [f64; 20] = [
+    30.0, 40.0, 45.0, 48.0, 50.0, 60.0, 72.0, 75.0, 90.0, 100.0, 120.0, 144.0, 165.0, 170.0, 175.0,
+    180.0, 200.0, 240.0, 300.0, 360.0,
+];
+
+/// Which side limited the frame rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BottleneckVerdict {
+    GpuBound,
+    CpuBound,
+    Mixed,
+    /// Held at a refresh interval or frame cap; neither side is the limiter.
+    Limited,
+}
+
+impl std::fmt::Display for BottleneckVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            BottleneckVerdict::GpuBound => "GPU-bound",
+            BottleneckVerdict::CpuBound => "CPU-bound",
+            BottleneckVerdict::Mixed => "Mixed",
+            BottleneckVerdict::Limited => "Frame-rate limited (cap/vsync)",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Where the per-frame GPU utilisation came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UtilisationSource {
+    /// PresentMon `GPUBusy` relative to the frame time
+    GpuBusy,
+    /// Overlay/monitoring `gpu_load` samples
+    GpuLoad,
+}
+
+impl UtilisationSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            UtilisationSource::GpuBusy => "PresentMon GPUBusy",
+            UtilisationSource::GpuLoad => "GPU load telemetry",
+        }
+    }
+}
+
+/// Verdict plus the percentages backing it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BottleneckBreakdown {
+    pub verdict: BottleneckVerdict,
+    /// Share of time (%) spent on GPU-bound frames
+    pub gpu_bound_pct: f64,
+    /// Share of time (%) spent on CPU-bound frames
+    pub cpu_bound_pct: f64,
+    /// Share of time (%) spent between the two thresholds
+    pub mixed_pct: f64,
+    /// Time-weighted mean GPU utilisation (%)
+    pub avg_gpu_util_pct: f64,
+    /// Mean CPU utilisation (%), when the source recorded it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avg_cpu_util_pct: Option<f64>,
+}
+
+impl std::fmt::Display for BottleneckBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (GPU-bound {:.0}% / CPU-bound {:.0}% / mixed {:.0}%, GPU util {:.0}%",
+            self.verdict,
+            self.gpu_bound_pct,
+            self.cpu_bound_pct,
+            self.mixed_pct,
+            self.avg_gpu_util_pct
+        )?;
+        if let Some(cpu) = self.avg_cpu_util_pct {
+            write!(f, ", CPU util {:.0}%", cpu)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Verdict for one stretch of the capture.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BottleneckSegment {
+    /// Segment start, seconds from the first classified frame
+    pub start_secs: f64,
+    /// Segment end, seconds from the first classified frame
+    pub end_secs: f64,
+    pub breakdown: BottleneckBreakdown,
+}
+
+/// Overall and per-segment bottleneck verdicts for a capture.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BottleneckReport {
+    pub source: UtilisationSource,
+    pub overall: BottleneckBreakdown,
+    #[serde(default)]
+    pub segments: Vec<BottleneckSegment>,
+}
+
+/// One classified frame: its duration and utilisation readings.
+#[derive(Debug, Clone, Copy)]
+struct FrameSample {
+    frame_time_ms: f64,
+    gpu_util_pct: f64,
+    cpu_util_pct: Option<f64>,
+}
+
+impl BottleneckReport {
+    /// Classify a capture from frame times and the telemetry recorded with them.
+    ///
+    /// Frames outside `(0, max_frame_time_ms]` or without a utilisation reading
+    /// are skipped. Returns `None` when no GPU utilisation was recorded.
+    pub fn classify(
+        frame_times_ms: &[f64],
+        telemetry: &FrameTelemetry,
+        max_frame_time_ms: f64,
+    ) -> Option<Self> {
+        let ceiling = if max_frame_time_ms.is_finite() && max_frame_time_ms > 0.0 {
+            max_frame_time_ms
+        } else {
+            1000.0
+        };
+        let aligned = |series: &[f64]| {
+            series.len() == frame_times_ms.len() && series.iter().any(|v| v.is_finite())
+        };
+        let source = if aligned(&telemetry.gpu_busy_ms) {
+            UtilisationSource::GpuBusy
+        } else if aligned(&telemetry.gpu_load_pct) {
+            UtilisationSource::GpuLoad
+        } else {
+            return None;
+        };
+        let cpu_busy = aligned(&telemetry.cpu_busy_ms).then_some(&telemetry.cpu_busy_ms);
+        let cpu_load = aligned(&telemetry.cpu_load_pct).then_some(&telemetry.cpu_load_pct);
+
+        let samples: Vec<FrameSample> = frame_times_ms
+            .iter()
+            .enumerate()
+            .filter(|(_, ft)| ft.is_finite() && **ft > 0.0 && **ft <= ceiling)
+            .filter_map(|(idx, &frame_time_ms)| {
+                let gpu_util_pct = match source {
+                    UtilisationSource::GpuBusy => {
+                        telemetry.gpu_busy_ms[idx] / frame_time_ms * 100.0
+                    }
+                    UtilisationSource::GpuLoad => telemetry.gpu_load_pct[idx],
+                };
+                if !gpu_util_pct.is_finite() {
+                    return None;
+                }
+                let cpu_util_pct = cpu_busy
+                    .map(|busy| busy[idx] / frame_time_ms * 100.0)
+                    .or_else(|| cpu_load.map(|load| load[idx]))
+                    .filter(|v| v.is_finite())
+                    .map(|v| v.clamp(0.0, 100.0));
+                Some(FrameSample {
+                    frame_time_ms,
+                    gpu_util_pct: gpu_util_pct.clamp(0.0, 100.0),
+                    cpu_util_pct,
+                })
+            })
+            .collect();
+
+        let overall = breakdown(&samples)?;
+        Some(Self {
+            source,
+            overall,
+            segments: segments(&samples),
+        })
+    }
+
+    /// Combine per-pass reports: shares are averaged and segments laid end to end.
+    pub fn merge<'a>(reports: impl IntoIterator<Item = &'a BottleneckReport>) -> Option<Self> {
+        let reports: Vec<&BottleneckReport> = reports.into_iter().collect();
+        let first = reports.first()?;
+        let count = reports.len() as f64;
+        let mean = |value: fn(&BottleneckBreakdown) -> f64| {
+            reports.iter().map(|r| value(&r.overall)).sum::<f64>() / count
+        };
+        let gpu_bound_pct = mean(|b| b.gpu_bound_pct);
+        let cpu_bound_pct = mean(|b| b.cpu_bound_pct);
+        let cpu_utils: Vec<f64> = reports
+            .iter()
+            .filter_map(|r| r.overall.avg_cpu_util_pct)
+            .collect();
+        let limited = reports
+            .iter()
+            .all(|r| r.overall.verdict == BottleneckVerdict::Limited);
+
+        let mut segments = Vec::new();
+        let mut offset_secs = 0.0;
+        for report in &reports {
+            for segment in &report.segments {
+                segments.push(BottleneckSegment {
+                    start_secs: segment.start_secs + offset_secs,
+                    end_secs: segment.end_secs + offset_secs,
+                    breakdown: segment.breakdown.clone(),
+                });
+            }
+            offset_secs += report.segments.last().map_or(0.0, |s| s.end_secs);
+        }
+
+        Some(Self {
+            source: first.source,
+            overall: BottleneckBreakdown {
+                verdict: verdict_for(gpu_bound_pct, cpu_bound_pct, limited),
+                gpu_bound_pct,
+                cpu_bound_pct,
+                mixed_pct: mean(|b| b.mixed_pct),
+                avg_gpu_util_pct: mean(|b| b.avg_gpu_util_pct),
+                avg_cpu_util_pct: TelemetryStat::from_samples(&cpu_utils).map(|stat| stat.mean),
+            },
+            segments,
+        })
+    }
+}
+
+impl std::fmt::Display for BottleneckReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Bottleneck: {}", self.overall)?;
+        writeln!(f, "  Based on {}", self.source.label())?;
+        if self.segments.len() > 1 {
+            for segment in &self.segments {
+                writeln!(
+                    f,
+                    "  {:>5.0}-{:<5.0}s {}",
+                    segment.start_secs, segment.end_secs, segment.breakdown
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn breakdown(samples: &[FrameSample]) -> Option<BottleneckBreakdown> {
+    let total_ms: f64 = samples.iter().map(|s| s.frame_time_ms).sum();
+    if total_ms <= 0.0 {
+        return None;
+    }
+
+    let share = |pred: &dyn Fn(f64) -> bool| {
+        samples
+            .iter()
+            .filter(|s| pred(s.gpu_util_pct))
+            .map(|s| s.frame_time_ms)
+            .sum::<f64>()
+            / total_ms
+            * 100.0
+    };
+    let gpu_bound_pct = share(&|util| util >= GPU_BOUND_UTIL_PCT);
+    let cpu_bound_pct = share(&|util| util < CPU_BOUND_UTIL_PCT);
+    let mixed_pct = (100.0 - gpu_bound_pct - cpu_bound_pct).max(0.0);
+
+    let avg_gpu_util_pct = samples
+        .iter()
+        .map(|s| s.gpu_util_pct * s.frame_time_ms)
+        .sum::<f64>()
+        / total_ms;
+    let cpu_samples: Vec<f64> = samples.iter().filter_map(|s| s.cpu_util_pct).collect();
+    let avg_cpu_util_pct = TelemetryStat::from_samples(&cpu_samples).map(|stat| stat.mean);
+
+    let limited = avg_cpu_util_pct.is_none_or(|cpu| cpu < CPU_SATURATED_UTIL_PCT)
+        && is_frame_limited(samples);
+
+    Some(BottleneckBreakdown {
+        verdict: verdict_for(gpu_bound_pct, cpu_bound_pct, limited),
+        gpu_bound_pct,
+        cpu_bound_pct,
+        mixed_pct,
+        avg_gpu_util_pct,
+        avg_cpu_util_pct,
+    })
+}
+
+fn verdict_for(gpu_bound_pct: f64, cpu_bound_pct: f64, limited: bool) -> BottleneckVerdict {
+    if gpu_bound_pct >= DOMINANT_SHARE_PCT {
+        BottleneckVerdict::GpuBound
+    } else if limited {
+        BottleneckVerdict::Limited
+    } else if cpu_bound_pct >= DOMINANT_SHARE_PCT {
+        BottleneckVerdict::CpuBound
+    } else {
+        BottleneckVerdict::Mixed
+    }
+}
+
+/// Whether frame times sit at one common refresh interval or frame cap for
+/// most of the time.
+fn is_frame_limited(samples: &[FrameSample]) -> bool {
+    if samples.len() < MIN_FRAMES_FOR_LIMIT {
+        return false;
+    }
+    let mut sorted: Vec<f64> = samples.iter().map(|s| s.frame_time_ms).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let median_ms = sorted[sorted.len() / 2];
+    let median_fps = 1000.0 / median_ms;
+    if !COMMON_FRAME_RATE_LIMITS
+        .iter()
+        .any(|fps| (median_fps - fps).abs() <= fps * LIMITED_TOLERANCE)
+    {
+        return false;
+    }
+
+    let total_ms: f64 = sorted.iter().sum();
+    let at_limit_ms: f64 = sorted
+        .iter()
+        .filter(|ft| (**ft - median_ms).abs() <= median_ms * LIMITED_TOLERANCE)
+        .sum();
+    at_limit_ms / total_ms * 100.0 >= LIMITED_SHARE_PCT
+}
+
+/// Split samples into roughly `SEGMENT_SECS` stretches; a short tail joins the last one.
+fn segments(samples: &[FrameSample]) -> Vec<BottleneckSegment> {
+    let segment_ms = SEGMENT_SECS * 1000.0;
+    let mut bounds: Vec<(usize, usize, f64, f64)> = Vec::new();
+    let mut start_idx = 0;
+    let mut start_ms = 0.0;
+    let mut elapsed_ms = 0.0;
+
+    for (idx, sample) in samples.iter().enumerate() {
+        elapsed_ms += sample.frame_time_ms;
+        if elapsed_ms - start_ms >= segment_ms {
+            bounds.push((start_idx, idx + 1, start_ms, elapsed_ms));
+            start_idx = idx + 1;
+            start_ms = elapsed_ms;
+        }
+    }
+    if start_idx < samples.len() {
+        match bounds.last_mut() {
+            Some(last) if elapsed_ms - start_ms < segment_ms / 2.0 => {
+                last.1 = samples.len();
+                last.3 = elapsed_ms;
+            }
+            _ => bounds.push((start_idx, samples.len(), start_ms, elapsed_ms)),
+        }
+    }
+
+    bounds
+        .into_iter()
+        .filter_map(|(start, end, start_ms, end_ms)| {
+            Some(BottleneckSegment {
+                start_secs: start_ms / 1000.0,
+                end_secs: end_ms / 1000.0,
+                breakdown: breakdown(&samples[start..end])?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gpu_busy_share_of_frame_time_drives_the_verdict() {
+        // 60s at ~8ms: the first 30s the GPU is saturated, then it idles half of each frame.
+        let frames = vec![8.0; 7_500];
+        let mut gpu_busy = vec![7.8; 3_750];
+        gpu_busy.extend(vec![4.0; 3_750]);
+        let telemetry = FrameTelemetry {
+            gpu_busy_ms: gpu_busy,
+            cpu_busy_ms: vec![6.0; 7_500],
+            gpu_load_pct: vec![10.0; 7_500],
+            ..FrameTelemetry::default()
+        };
+
+        let report = BottleneckReport::classify(&frames, &telemetry, 1000.0).unwrap();
+        assert_eq!(report.source, UtilisationSource::GpuBusy);
+        assert_eq!(report.overall.verdict, BottleneckVerdict::Mixed);
+        assert!((report.overall.gpu_bound_pct - 50.0).abs() < 1e-6);
+        assert!((report.overall.cpu_bound_pct - 50.0).abs() < 1e-6);
+        assert!((report.overall.avg_cpu_util_pct.unwrap() - 75.0).abs() < 1e-6);
+
+        assert_eq!(report.segments.len(), 6);
+        assert_eq!(
+            report.segments[0].breakdown.verdict,
+            BottleneckVerdict::GpuBound
+        );
+        assert_eq!(
+            report.segments[5].breakdown.verdict,
+            BottleneckVerdict::CpuBound
+        );
+    }
+
+    #[test]
+    fn gpu_load_fallback_skips_gaps_and_invalid_frames() {
+        let frames = vec![16.0, 16.0, 16.0, 5_000.0];
+        let telemetry = FrameTelemetry {
+            gpu_load_pct: vec![99.0, f64::NAN, 97.0, 10.0],
+            ..FrameTelemetry::default()
+        };
+
+        let report = BottleneckReport::classify(&frames, &telemetry, 1000.0).unwrap();
+        assert_eq!(report.source, UtilisationSource::GpuLoad);
+        assert_eq!(report.overall.verdict, BottleneckVerdict::GpuBound);
+        assert!((report.overall.gpu_bound_pct - 100.0).abs() < 1e-9);
+        assert!(report.overall.avg_cpu_util_pct.is_none());
+        assert_eq!(report.segments.len(), 1);
+    }
+
+    #[test]
+    fn merged_passes_average_shares_and_chain_segments() {
+        let frames = vec![10.0; 2_000];
+        let gpu_bound = FrameTelemetry {
+            gpu_load_pct: vec![99.0; 2_000],
+            ..FrameTelemetry::default()
+        };
+        let cpu_bound = FrameTelemetry {
+            gpu_load_pct: vec![50.0; 2_000],
+            ..FrameTelemetry::default()
+        };
+        let a = BottleneckReport::classify(&frames, &gpu_bound, 1000.0).unwrap();
+        let b = BottleneckReport::classify(&frames, &cpu_bound, 1000.0).unwrap();
+
+        let merged = BottleneckReport::merge([&a, &b]).unwrap();
+        assert_eq!(merged.overall.verdict, BottleneckVerdict::Mixed);
+        assert!((merged.overall.gpu_bound_pct - 50.0).abs() < 1e-9);
+        assert_eq!(merged.segments.len(), 4);
+        assert!((merged.segments[2].start_secs - 20.0).abs() < 1e-9);
+        assert!(BottleneckReport::merge([]).is_none());
+    }
+
+    #[test]
+    fn steady_frames_at_refresh_interval_read_as_limited() {
+        // Vsync at 60 Hz with a mostly idle GPU and CPU.
+        let frames: Vec<f64> = (0..3_600)
+            .map(|i| 16.667 + if i % 2 == 0 { 0.1 } else { -0.1 })
+            .collect();
+        let telemetry = FrameTelemetry {
+            gpu_load_pct: vec![45.0; 3_600],
+            cpu_load_pct: vec![30.0; 3_600],
+            ..FrameTelemetry::default()
+        };
+        let report = BottleneckReport::classify(&frames, &telemetry, 1000.0).unwrap();
+        assert_eq!(report.overall.verdict, BottleneckVerdict::Limited);
+
+        // Same idle GPU, but frame times wander: the CPU is the limiter.
+        let uncapped: Vec<f64> = (0..3_600).map(|i| 12.0 + (i % 9) as f64).collect();
+        let report = BottleneckReport::classify(&uncapped, &telemetry, 1000.0).unwrap();
+        assert_eq!(report.overall.verdict, BottleneckVerdict::CpuBound);
+
+        // A steady run with a saturated CPU is CPU-bound, not capped.
+        let busy_cpu = FrameTelemetry {
+            cpu_load_pct: vec![97.0; 3_600],
+            ..telemetry
+        };
+        let report = BottleneckReport::classify(&frames, &busy_cpu, 1000.0).unwrap();
+        assert_eq!(report.overall.verdict, BottleneckVerdict::CpuBound);
+    }
+
+    #[test]
+    fn no_gpu_utilisation_means_no_verdict() {
+        let telemetry = FrameTelemetry {
+            gpu_temp_c: vec![70.0, 71.0],
+            ..FrameTelemetry::default()
+        };
+        assert!(BottleneckReport::classify(&[16.0, 16.0], &telemetry, 1000.0).is_none());
+        // Series that are not aligned with the frames cannot be attributed.
+        let misaligned = FrameTelemetry {
+            gpu_load_pct: vec![99.0],
+            ..FrameTelemetry::default()
+        };
+        assert!(BottleneckReport::classify(&[16.0, 16.0], &misaligned, 1000.0).is_none());
+    }
+}
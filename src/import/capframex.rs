@@ -7,6 +7,7 @@
 //! - Header row with metadata
 //! - "MsBetweenPresents" column contains frame times in milliseconds
 //! - "Application" column contains the game/app name
+//! - "CPUBusy"/"GPUBusy" columns, when present, are kept as telemetry
 
 use anyhow::{Context, Result};
 use csv::ReaderBuilder;
@@ -15,7 +16,8 @@ use std::path::Path;
 
 use crate::benchmark::focus;
 
-use super::common::FrameData;
+use super::common::{FrameData, FrameTelemetry};
+use super::presentmon::BusyColumns;
 
 /// Parse a CapFrameX capture CSV file
 pub fn parse_capframex_csv<P: AsRef<Path>>(path: P) -> Result<FrameData> {
@@ -38,9 +40,11 @@ pub fn parse_capframex_csv_for_process<P: AsRef<Path>>(
     let mut frametime_col: Option<usize> = None;
     let mut application_col: Option<usize> = None;
     let mut process_name_col: Option<usize> = None;
+    let mut busy_columns = BusyColumns::default();
     let mut header_found = false;
 
     let mut frame_times: Vec<f64> = Vec::new();
+    let mut telemetry = FrameTelemetry::default();
     let mut application: Option<String> = None;
     let mut seen_processes: HashSet<String> = HashSet::new();
     let normalized_filter = process_filter.map(focus::normalize_process_name);
@@ -77,6 +81,11 @@ pub fn parse_capframex_csv_for_process<P: AsRef<Path>>(
 
             if frametime_col.is_some() {
                 header_found = true;
+                let columns: Vec<String> = record
+                    .iter()
+                    .map(|field| field.trim().to_ascii_lowercase())
+                    .collect();
+                busy_columns = BusyColumns::from_header(&columns);
                 continue;
             }
         }
@@ -117,6 +126,7 @@ pub fn parse_capframex_csv_for_process<P: AsRef<Path>>(
                     if let Ok(ms) = value.trim().parse::<f64>() {
                         if ms.is_finite() && ms > 0.0 && ms <= 10_000.0 {
                             frame_times.push(ms);
+                            busy_columns.push_row(&record, &mut telemetry);
                         }
                     }
                 }
@@ -150,6 +160,8 @@ pub fn parse_capframex_csv_for_process<P: AsRef<Path>>(
         application,
         duration_secs,
         source: "CapFrameX".to_string(),
+        telemetry,
+        sampled: false,
     })
 }
 
@@ -225,7 +237,7 @@ game.exe,16.68,0.10003
         // Calculate stats
         let stats = result.calculate_stats().unwrap();
         assert!(stats.avg_fps > 0.0);
-        assert!(stats.fps_1_low > 0.0);
+        assert!(stats.fps_1_low.unwrap() > 0.0);
     }
 
     #[test]
@@ -274,4 +286,27 @@ GAME.EXE,16.65,0.06665
         assert_eq!(result.frame_times_ms, vec![16.67, 16.65]);
         assert_eq!(result.application, Some("game.exe".to_string()));
     }
+
+    #[test]
+    fn test_parse_capframex_csv_keeps_busy_columns_for_bottleneck() {
+        let csv_content = r#"Application,MsBetweenPresents,CPUBusy,GPUBusy
+game.exe,10.0,9.5,5.0
+game.exe,10.0,9.4,NA
+game.exe,10.0,9.6,5.5
+"#;
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_content).unwrap();
+
+        let result = parse_capframex_csv(file.path()).unwrap();
+        assert_eq!(result.telemetry.cpu_busy_ms, vec![9.5, 9.4, 9.6]);
+        assert_eq!(result.telemetry.gpu_busy_ms.len(), 3);
+        assert!(result.telemetry.gpu_busy_ms[1].is_nan());
+
+        let bottleneck = result.calculate_stats().unwrap().bottleneck.unwrap();
+        assert_eq!(
+            bottleneck.overall.verdict,
+            crate::import::bottleneck::BottleneckVerdict::CpuBound
+        );
+    }
 }
@@ -2,6 +2,13 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::bottleneck::BottleneckReport;
+
+/// FPS floor used for the time-below-threshold metric.
+pub const DEFAULT_LOW_FPS_THRESHOLD: f64 = 60.0;
+/// 0.1% metrics need enough frames for the tail to mean anything.
+const MIN_FRAMES_FOR_01_LOW: usize = 1000;
+
 /// Raw frame timing data from any source
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrameData {
@@ -13,6 +20,323 @@ pub struct FrameData {
     pub duration_secs: f64,
     /// Source tool name
     pub source: String,
+    /// Hardware telemetry recorded alongside the frame times
+    #[serde(default)]
+    pub telemetry: FrameTelemetry,
+    /// Frame times are polled samples of the current frame time, one per
+    /// polling interval, rather than one entry per rendered frame.
+    #[serde(default)]
+    pub sampled: bool,
+}
+
+/// A hardware metric recorded alongside frame times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelemetryMetric {
+    CpuLoad,
+    GpuLoad,
+    CpuTemp,
+    GpuTemp,
+    GpuCoreClock,
+    GpuPower,
+    RamUsed,
+    VramUsed,
+    CpuBusy,
+    GpuBusy,
+}
+
+impl TelemetryMetric {
+    pub const ALL: [TelemetryMetric; 10] = [
+        TelemetryMetric::CpuLoad,
+        TelemetryMetric::GpuLoad,
+        TelemetryMetric::CpuTemp,
+        TelemetryMetric::GpuTemp,
+        TelemetryMetric::GpuCoreClock,
+        TelemetryMetric::GpuPower,
+        TelemetryMetric::RamUsed,
+        TelemetryMetric::VramUsed,
+        TelemetryMetric::CpuBusy,
+        TelemetryMetric::GpuBusy,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TelemetryMetric::CpuLoad => "CPU load",
+            TelemetryMetric::GpuLoad => "GPU load",
+            TelemetryMetric::CpuTemp => "CPU temp",
+            TelemetryMetric::GpuTemp => "GPU temp",
+            TelemetryMetric::GpuCoreClock => "GPU clock",
+            TelemetryMetric::GpuPower => "GPU power",
+            TelemetryMetric::RamUsed => "RAM used",
+            TelemetryMetric::VramUsed => "VRAM used",
+            TelemetryMetric::CpuBusy => "CPU busy",
+            TelemetryMetric::GpuBusy => "GPU busy",
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            TelemetryMetric::CpuLoad | TelemetryMetric::GpuLoad => "%",
+            TelemetryMetric::CpuTemp | TelemetryMetric::GpuTemp => "°C",
+            TelemetryMetric::GpuCoreClock => "MHz",
+            TelemetryMetric::GpuPower => "W",
+            TelemetryMetric::RamUsed | TelemetryMetric::VramUsed => "GB",
+            TelemetryMetric::CpuBusy | TelemetryMetric::GpuBusy => "ms",
+        }
+    }
+}
+
+/// Hardware telemetry sampled alongside frame times.
+///
+/// Each series is either empty (not recorded) or the same length as
+/// `frame_times_ms`; samples the tool left blank are stored as NaN.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrameTelemetry {
+    /// CPU utilisation (%)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpu_load_pct: Vec<f64>,
+    /// GPU utilisation (%)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gpu_load_pct: Vec<f64>,
+    /// CPU temperature (°C)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpu_temp_c: Vec<f64>,
+    /// GPU temperature (°C)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gpu_temp_c: Vec<f64>,
+    /// GPU core clock (MHz)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gpu_core_clock_mhz: Vec<f64>,
+    /// GPU board power (W)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gpu_power_w: Vec<f64>,
+    /// System RAM in use (GB)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ram_used_gb: Vec<f64>,
+    /// VRAM in use (GB)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vram_used_gb: Vec<f64>,
+    /// CPU time spent on the frame (ms), from PresentMon `CPUBusy`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpu_busy_ms: Vec<f64>,
+    /// GPU time spent on the frame (ms), from PresentMon `GPUBusy`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gpu_busy_ms: Vec<f64>,
+}
+
+impl FrameTelemetry {
+    pub fn series(&self, metric: TelemetryMetric) -> &Vec<f64> {
+        match metric {
+            TelemetryMetric::CpuLoad => &self.cpu_load_pct,
+            TelemetryMetric::GpuLoad => &self.gpu_load_pct,
+            TelemetryMetric::CpuTemp => &self.cpu_temp_c,
+            TelemetryMetric::GpuTemp => &self.gpu_temp_c,
+            TelemetryMetric::GpuCoreClock => &self.gpu_core_clock_mhz,
+            TelemetryMetric::GpuPower => &self.gpu_power_w,
+            TelemetryMetric::RamUsed => &self.ram_used_gb,
+            TelemetryMetric::VramUsed => &self.vram_used_gb,
+            TelemetryMetric::CpuBusy => &self.cpu_busy_ms,
+            TelemetryMetric::GpuBusy => &self.gpu_busy_ms,
+        }
+    }
+
+    pub fn series_mut(&mut self, metric: TelemetryMetric) -> &mut Vec<f64> {
+        match metric {
+            TelemetryMetric::CpuLoad => &mut self.cpu_load_pct,
+            TelemetryMetric::GpuLoad => &mut self.gpu_load_pct,
+            TelemetryMetric::CpuTemp => &mut self.cpu_temp_c,
+            TelemetryMetric::GpuTemp => &mut self.gpu_temp_c,
+            TelemetryMetric::GpuCoreClock => &mut self.gpu_core_clock_mhz,
+            TelemetryMetric::GpuPower => &mut self.gpu_power_w,
+            TelemetryMetric::RamUsed => &mut self.ram_used_gb,
+            TelemetryMetric::VramUsed => &mut self.vram_used_gb,
+            TelemetryMetric::CpuBusy => &mut self.cpu_busy_ms,
+            TelemetryMetric::GpuBusy => &mut self.gpu_busy_ms,
+        }
+    }
+
+    /// Keep samples `start..end`, matching a slice of the frame times.
+    pub fn slice(&self, start: usize, end: usize) -> Self {
+        let mut sliced = Self::default();
+        for metric in TelemetryMetric::ALL {
+            let series = self.series(metric);
+            if !series.is_empty() {
+                *sliced.series_mut(metric) =
+                    series[start.min(series.len())..end.min(series.len())].to_vec();
+            }
+        }
+        sliced
+    }
+
+    /// Keep the samples whose `keep` flag is set.
+    ///
+    /// Series that are not the same length as `keep` cannot be attributed to
+    /// frames and are dropped.
+    pub fn select(&self, keep: &[bool]) -> Self {
+        let mut selected = Self::default();
+        for metric in TelemetryMetric::ALL {
+            let series = self.series(metric);
+            if series.len() == keep.len() {
+                *selected.series_mut(metric) = series
+                    .iter()
+                    .zip(keep)
+                    .filter(|(_, keep)| **keep)
+                    .map(|(value, _)| *value)
+                    .collect();
+            }
+        }
+        selected
+    }
+
+    /// Join consecutive captures. A series is kept only when every part recorded it.
+    pub fn concat<'a>(parts: impl IntoIterator<Item = &'a FrameTelemetry> + Clone) -> Self {
+        let mut joined = Self::default();
+        for metric in TelemetryMetric::ALL {
+            if parts
+                .clone()
+                .into_iter()
+                .all(|part| !part.series(metric).is_empty())
+            {
+                *joined.series_mut(metric) = parts
+                    .clone()
+                    .into_iter()
+                    .flat_map(|part| part.series(metric).iter().copied())
+                    .collect();
+            }
+        }
+        joined
+    }
+
+    /// Mean and max of every recorded series, or `None` when nothing was recorded.
+    pub fn summary(&self) -> Option<TelemetrySummary> {
+        let mut summary = TelemetrySummary::default();
+        for metric in TelemetryMetric::ALL {
+            *summary.stat_mut(metric) = TelemetryStat::from_samples(self.series(metric));
+        }
+        (!summary.is_empty()).then_some(summary)
+    }
+}
+
+/// Mean and peak of one telemetry series.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TelemetryStat {
+    pub mean: f64,
+    pub max: f64,
+}
+
+impl TelemetryStat {
+    /// Summarise finite samples; NaN gaps are ignored.
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        let finite: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+        if finite.is_empty() {
+            return None;
+        }
+        Some(Self {
+            mean: finite.iter().sum::<f64>() / finite.len() as f64,
+            max: finite.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        })
+    }
+}
+
+/// Per-metric telemetry summary shown in results and offered for submission.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TelemetrySummary {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_load_pct: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu_load_pct: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_temp_c: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu_temp_c: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu_core_clock_mhz: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu_power_w: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ram_used_gb: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vram_used_gb: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_busy_ms: Option<TelemetryStat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu_busy_ms: Option<TelemetryStat>,
+}
+
+impl TelemetrySummary {
+    pub fn stat(&self, metric: TelemetryMetric) -> Option<TelemetryStat> {
+        match metric {
+            TelemetryMetric::CpuLoad => self.cpu_load_pct,
+            TelemetryMetric::GpuLoad => self.gpu_load_pct,
+            TelemetryMetric::CpuTemp => self.cpu_temp_c,
+            TelemetryMetric::GpuTemp => self.gpu_temp_c,
+            TelemetryMetric::GpuCoreClock => self.gpu_core_clock_mhz,
+            TelemetryMetric::GpuPower => self.gpu_power_w,
+            TelemetryMetric::RamUsed => self.ram_used_gb,
+            TelemetryMetric::VramUsed => self.vram_used_gb,
+            TelemetryMetric::CpuBusy => self.cpu_busy_ms,
+            TelemetryMetric::GpuBusy => self.gpu_busy_ms,
+        }
+    }
+
+    fn stat_mut(&mut self, metric: TelemetryMetric) -> &mut Option<TelemetryStat> {
+        match metric {
+            TelemetryMetric::CpuLoad => &mut self.cpu_load_pct,
+            TelemetryMetric::GpuLoad => &mut self.gpu_load_pct,
+            TelemetryMetric::CpuTemp => &mut self.cpu_temp_c,
+            TelemetryMetric::GpuTemp => &mut self.gpu_temp_c,
+            TelemetryMetric::GpuCoreClock => &mut self.gpu_core_clock_mhz,
+            TelemetryMetric::GpuPower => &mut self.gpu_power_w,
+            TelemetryMetric::RamUsed => &mut self.ram_used_gb,
+            TelemetryMetric::VramUsed => &mut self.vram_used_gb,
+            TelemetryMetric::CpuBusy => &mut self.cpu_busy_ms,
+            TelemetryMetric::GpuBusy => &mut self.gpu_busy_ms,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        TelemetryMetric::ALL
+            .iter()
+            .all(|metric| self.stat(*metric).is_none())
+    }
+
+    /// Combine per-pass summaries: means are averaged, peaks take the max.
+    pub fn merge<'a>(summaries: impl IntoIterator<Item = &'a TelemetrySummary>) -> Option<Self> {
+        let summaries: Vec<&TelemetrySummary> = summaries.into_iter().collect();
+        let mut merged = Self::default();
+        for metric in TelemetryMetric::ALL {
+            let stats: Vec<TelemetryStat> =
+                summaries.iter().filter_map(|s| s.stat(metric)).collect();
+            if stats.is_empty() {
+                continue;
+            }
+            *merged.stat_mut(metric) = Some(TelemetryStat {
+                mean: stats.iter().map(|s| s.mean).sum::<f64>() / stats.len() as f64,
+                max: stats
+                    .iter()
+                    .map(|s| s.max)
+                    .fold(f64::NEG_INFINITY, f64::max),
+            });
+        }
+        (!merged.is_empty()).then_some(merged)
+    }
+}
+
+impl std::fmt::Display for TelemetrySummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for metric in TelemetryMetric::ALL {
+            if let Some(stat) = self.stat(metric) {
+                writeln!(
+                    f,
+                    "{:<10} avg {:>7.1} / max {:>7.1} {}",
+                    format!("{}:", metric.label()),
+                    stat.mean,
+                    stat.max,
+                    metric.unit()
+                )?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Calculated benchmark result
@@ -20,8 +344,8 @@ pub struct FrameData {
 pub struct BenchmarkResult {
     /// Average FPS
     pub avg_fps: f64,
-    /// 1% low FPS (99th percentile frame time)
-    pub fps_1_low: f64,
+    /// 1% low FPS (99th percentile frame time); `None` for sampled sources
+    pub fps_1_low: Option<f64>,
     /// 0.1% low FPS (99.9th percentile frame time)
     pub fps_01_low: Option<f64>,
     /// Minimum FPS
@@ -38,6 +362,146 @@ pub struct BenchmarkResult {
     pub application: Option<String>,
     /// Source tool
     pub source: String,
+    /// Frame pacing and consistency metrics
+    #[serde(default)]
+    pub frame_time_stats: FrameTimeStats,
+    /// Hardware telemetry summary, when the source recorded any
+    #[serde(default)]
+    pub telemetry: Option<TelemetrySummary>,
+    /// CPU- vs GPU-bound verdict, when GPU utilisation was recorded
+    #[serde(default)]
+    pub bottleneck: Option<BottleneckReport>,
+}
+
+/// Frame pacing and consistency metrics shared by imports and live capture.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrameTimeStats {
+    /// Sample standard deviation of frame times (ms)
+    pub stddev_ms: f64,
+    /// Median frame time (ms)
+    pub median_ms: f64,
+    /// 95th percentile frame time (ms)
+    pub p95_ms: f64,
+    /// 99th percentile frame time (ms)
+    pub p99_ms: f64,
+    /// 99.9th percentile frame time (ms), with at least 1000 frames
+    pub p999_ms: Option<f64>,
+    /// Average FPS over the slowest 1% of frames; `None` for sampled sources
+    pub fps_1_low_avg: Option<f64>,
+    /// Average FPS over the slowest 0.1% of frames, with at least 1000 frames
+    pub fps_01_low_avg: Option<f64>,
+    /// Mean absolute frame-to-frame delta (ms)
+    pub jitter_avg_ms: f64,
+    /// 99th percentile absolute frame-to-frame delta (ms)
+    pub jitter_p99_ms: f64,
+    /// Largest absolute frame-to-frame delta (ms)
+    pub jitter_max_ms: f64,
+    /// FPS floor used for `time_below_threshold_pct`
+    pub threshold_fps: f64,
+    /// Share of capture time (%) spent on frames slower than `threshold_fps`
+    pub time_below_threshold_pct: f64,
+}
+
+impl FrameTimeStats {
+    /// Compute pacing metrics from validated frame times in capture order.
+    pub fn from_frame_times(frame_times_ms: &[f64], threshold_fps: f64) -> Option<Self> {
+        Self::from_segments(&[frame_times_ms], threshold_fps)
+    }
+
+    /// Compute pacing metrics over several separate runs (e.g. benchmark
+    /// passes). Frame-to-frame deltas are taken within each run only, so the
+    /// gap between two runs never counts as jitter.
+    pub fn from_segments(segments: &[&[f64]], threshold_fps: f64) -> Option<Self> {
+        let frame_times_ms: Vec<f64> = segments.iter().flat_map(|s| s.iter().copied()).collect();
+        let frame_count = frame_times_ms.len();
+        if frame_count == 0 {
+            return None;
+        }
+
+        let mut sorted = frame_times_ms.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let total_ms: f64 = frame_times_ms.iter().sum();
+        let mean = total_ms / frame_count as f64;
+        let stddev_ms = if frame_count > 1 {
+            let variance = frame_times_ms
+                .iter()
+                .map(|ft| (ft - mean).powi(2))
+                .sum::<f64>()
+                / (frame_count - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        let has_01_tail = frame_count >= MIN_FRAMES_FOR_01_LOW;
+
+        let mut deltas: Vec<f64> = segments
+            .iter()
+            .flat_map(|segment| segment.windows(2))
+            .map(|pair| (pair[1] - pair[0]).abs())
+            .collect();
+        deltas.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let (jitter_avg_ms, jitter_p99_ms, jitter_max_ms) = if deltas.is_empty() {
+            (0.0, 0.0, 0.0)
+        } else {
+            (
+                deltas.iter().sum::<f64>() / deltas.len() as f64,
+                percentile_sorted(&deltas, 0.99),
+                deltas[deltas.len() - 1],
+            )
+        };
+
+        let threshold_fps = if threshold_fps.is_finite() && threshold_fps > 0.0 {
+            threshold_fps
+        } else {
+            DEFAULT_LOW_FPS_THRESHOLD
+        };
+        let threshold_ms = 1000.0 / threshold_fps;
+        let slow_ms: f64 = frame_times_ms.iter().filter(|ft| **ft > threshold_ms).sum();
+        let time_below_threshold_pct = if total_ms > 0.0 {
+            slow_ms / total_ms * 100.0
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            stddev_ms,
+            median_ms: median_sorted(&sorted),
+            p95_ms: percentile_sorted(&sorted, 0.95),
+            p99_ms: percentile_sorted(&sorted, 0.99),
+            p999_ms: has_01_tail.then(|| percentile_sorted(&sorted, 0.999)),
+            fps_1_low_avg: Some(average_fps_of_slowest(&sorted, 0.01)),
+            fps_01_low_avg: has_01_tail.then(|| average_fps_of_slowest(&sorted, 0.001)),
+            jitter_avg_ms,
+            jitter_p99_ms,
+            jitter_max_ms,
+            threshold_fps,
+            time_below_threshold_pct,
+        })
+    }
+}
+
+/// Nearest-rank percentile of an ascending slice (same indexing as the lows).
+fn percentile_sorted(sorted: &[f64], quantile: f64) -> f64 {
+    let idx = ((sorted.len() as f64 * quantile) as usize).min(sorted.len() - 1);
+    sorted[idx]
+}
+
+fn median_sorted(sorted: &[f64]) -> f64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// FPS over the slowest `share` of frames: total frames / total time.
+fn average_fps_of_slowest(sorted: &[f64], share: f64) -> f64 {
+    let count = ((sorted.len() as f64 * share).ceil() as usize).clamp(1, sorted.len());
+    let slowest = &sorted[sorted.len() - count..];
+    1000.0 * count as f64 / slowest.iter().sum::<f64>()
 }
 
 impl FrameData {
@@ -63,21 +527,27 @@ impl FrameData {
         }
 
         let avg_frame_time: f64 = valid_times.iter().sum::<f64>() / frame_count as f64;
-        let avg_fps = 1000.0 / avg_frame_time;
-
-        let mut sorted_times = valid_times.clone();
-        sorted_times.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
 
-        let idx_99 = ((frame_count as f64 * 0.99) as usize).min(frame_count - 1);
-        let p99_frame_time = sorted_times[idx_99];
-        let fps_1_low = 1000.0 / p99_frame_time;
-
-        let fps_01_low = if frame_count >= 1000 {
-            let idx_999 = ((frame_count as f64 * 0.999) as usize).min(frame_count - 1);
-            let p999_frame_time = sorted_times[idx_999];
-            Some(1000.0 / p999_frame_time)
+        let mut frame_time_stats =
+            FrameTimeStats::from_frame_times(&valid_times, DEFAULT_LOW_FPS_THRESHOLD)?;
+        let (avg_fps, fps_1_low, fps_01_low) = if self.sampled {
+            // Each sample stands for one polling interval, so the plain mean of
+            // the instantaneous FPS is the time-weighted average. Which frames
+            // were sampled is arbitrary, so there is no honest 1%/0.1% tail.
+            frame_time_stats.fps_1_low_avg = None;
+            frame_time_stats.fps_01_low_avg = None;
+            let threshold_ms = 1000.0 / frame_time_stats.threshold_fps;
+            let slow_samples = valid_times.iter().filter(|ft| **ft > threshold_ms).count();
+            frame_time_stats.time_below_threshold_pct =
+                slow_samples as f64 / frame_count as f64 * 100.0;
+            let avg_fps = fps_values.iter().sum::<f64>() / frame_count as f64;
+            (avg_fps, None, None)
         } else {
-            None
+            (
+                1000.0 / avg_frame_time,
+                Some(1000.0 / frame_time_stats.p99_ms),
+                frame_time_stats.p999_ms.map(|p999| 1000.0 / p999),
+            )
         };
 
         let min_fps = fps_values.iter().cloned().fold(f64::INFINITY, f64::min);
@@ -94,6 +564,13 @@ impl FrameData {
             avg_frame_time_ms: avg_frame_time,
             application: self.application.clone(),
             source: self.source.clone(),
+            frame_time_stats,
+            telemetry: self.telemetry.summary(),
+            bottleneck: BottleneckReport::classify(
+                &self.frame_times_ms,
+                &self.telemetry,
+                max_frame_time_ms,
+            ),
         })
     }
 
@@ -119,12 +596,7 @@ impl FrameData {
             return None;
         }
         values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-        let mid = values.len() / 2;
-        if values.len().is_multiple_of(2) {
-            Some((values[mid - 1] + values[mid]) / 2.0)
-        } else {
-            Some(values[mid])
-        }
+        Some(median_sorted(&values))
     }
 
     /// Count stutter spikes using a threshold derived from median frame time.
@@ -166,11 +638,9 @@ impl std::fmt::Display for BenchmarkResult {
         writeln!(f, "│ Source:      {:<WIDTH$}│", self.source)?;
         writeln!(f, "├{}┤", "─".repeat(WIDTH))?;
         writeln!(f, "│ Average FPS:     {:>8.1}              │", self.avg_fps)?;
-        writeln!(
-            f,
-            "│ 1% Low FPS:      {:>8.1}              │",
-            self.fps_1_low
-        )?;
+        if let Some(fps_1) = self.fps_1_low {
+            writeln!(f, "│ 1% Low FPS:      {:>8.1}              │", fps_1)?;
+        }
 
         if let Some(fps_01) = self.fps_01_low {
             writeln!(f, "│ 0.1% Low FPS:    {:>8.1}              │", fps_01)?;
@@ -178,6 +648,38 @@ impl std::fmt::Display for BenchmarkResult {
 
         writeln!(f, "│ Min FPS:         {:>8.1}              │", self.min_fps)?;
         writeln!(f, "│ Max FPS:         {:>8.1}              │", self.max_fps)?;
+        if let Some(fps_1_avg) = self.frame_time_stats.fps_1_low_avg {
+            writeln!(f, "│ 1% Low (avg):    {:>8.1}              │", fps_1_avg)?;
+        }
+        writeln!(f, "├{}┤", "─".repeat(WIDTH))?;
+        writeln!(
+            f,
+            "│ Median / P95:    {:>6.2} / {:<6.2}ms      │",
+            self.frame_time_stats.median_ms, self.frame_time_stats.p95_ms
+        )?;
+        writeln!(
+            f,
+            "│ P99 Frame Time:  {:>8.2}ms            │",
+            self.frame_time_stats.p99_ms
+        )?;
+        if let Some(p999) = self.frame_time_stats.p999_ms {
+            writeln!(f, "│ P99.9 Frame Time:{:>8.2}ms            │", p999)?;
+        }
+        writeln!(
+            f,
+            "│ Std Dev:         {:>8.2}ms            │",
+            self.frame_time_stats.stddev_ms
+        )?;
+        writeln!(
+            f,
+            "│ Jitter (avg/p99):{:>6.2} / {:<6.2}ms      │",
+            self.frame_time_stats.jitter_avg_ms, self.frame_time_stats.jitter_p99_ms
+        )?;
+        writeln!(
+            f,
+            "│ Time < {:>3.0} FPS:  {:>8.1}%             │",
+            self.frame_time_stats.threshold_fps, self.frame_time_stats.time_below_threshold_pct
+        )?;
         writeln!(f, "├{}┤", "─".repeat(WIDTH))?;
         writeln!(
             f,
@@ -196,13 +698,23 @@ impl std::fmt::Display for BenchmarkResult {
         )?;
         writeln!(f, "└{}┘", "─".repeat(WIDTH))?;
 
+        if let Some(telemetry) = &self.telemetry {
+            writeln!(f, "Telemetry:")?;
+            for line in telemetry.to_string().lines() {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+        if let Some(bottleneck) = &self.bottleneck {
+            write!(f, "{}", bottleneck)?;
+        }
+
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::FrameData;
+    use super::{FrameData, FrameTelemetry, FrameTimeStats, TelemetryMetric, TelemetrySummary};
 
     #[test]
     fn spike_metrics_detect_tail_events() {
@@ -211,10 +723,82 @@ mod tests {
             application: Some("test-game".to_string()),
             duration_secs: 1.0,
             source: "test".to_string(),
+            telemetry: FrameTelemetry::default(),
+            sampled: false,
         };
 
         assert_eq!(data.stutter_spike_count(1000.0), 2);
         let ratio = data.stutter_spike_ratio(1000.0);
         assert!(ratio > 0.30 && ratio < 0.35);
     }
+
+    #[test]
+    fn frame_time_stats_cover_percentiles_jitter_and_threshold() {
+        let mut frames = vec![10.0; 990];
+        frames.extend(vec![25.0; 10]);
+
+        let stats = FrameTimeStats::from_frame_times(&frames, 60.0).unwrap();
+        assert!((stats.median_ms - 10.0).abs() < 1e-9);
+        assert!((stats.p95_ms - 10.0).abs() < 1e-9);
+        assert!((stats.p99_ms - 25.0).abs() < 1e-9);
+        assert!((stats.p999_ms.unwrap() - 25.0).abs() < 1e-9);
+        assert!((stats.fps_1_low_avg.unwrap() - 40.0).abs() < 1e-9);
+        assert!(stats.stddev_ms > 1.0 && stats.stddev_ms < 2.0);
+
+        // Only the single 10 -> 25 transition produces a delta.
+        assert!((stats.jitter_max_ms - 15.0).abs() < 1e-9);
+        assert!((stats.jitter_avg_ms - 15.0 / 999.0).abs() < 1e-9);
+
+        // 250ms of 10_150ms total is spent below 60 FPS.
+        assert!((stats.time_below_threshold_pct - 250.0 / 10_150.0 * 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn short_captures_skip_01_metrics() {
+        let stats = FrameTimeStats::from_frame_times(&[16.0, 17.0, 15.0], 60.0).unwrap();
+        assert!(stats.p999_ms.is_none());
+        assert!(stats.fps_01_low_avg.is_none());
+        assert!(FrameTimeStats::from_frame_times(&[], 60.0).is_none());
+    }
+
+    #[test]
+    fn telemetry_summary_ignores_gaps_and_merges_passes() {
+        let telemetry = FrameTelemetry {
+            gpu_load_pct: vec![90.0, f64::NAN, 100.0],
+            gpu_power_w: vec![200.0, 220.0, 240.0],
+            ..FrameTelemetry::default()
+        };
+
+        let summary = telemetry.summary().unwrap();
+        let gpu_load = summary.stat(TelemetryMetric::GpuLoad).unwrap();
+        assert!((gpu_load.mean - 95.0).abs() < 1e-9);
+        assert!((gpu_load.max - 100.0).abs() < 1e-9);
+        assert!(summary.stat(TelemetryMetric::CpuTemp).is_none());
+        assert!(FrameTelemetry::default().summary().is_none());
+
+        let other = FrameTelemetry {
+            gpu_power_w: vec![180.0, 300.0],
+            ..FrameTelemetry::default()
+        }
+        .summary()
+        .unwrap();
+        let merged = TelemetrySummary::merge([&summary, &other]).unwrap();
+        let power = merged.stat(TelemetryMetric::GpuPower).unwrap();
+        assert!((power.mean - 230.0).abs() < 1e-9);
+        assert!((power.max - 300.0).abs() < 1e-9);
+        assert!(merged.stat(TelemetryMetric::GpuLoad).is_some());
+    }
+
+    #[test]
+    fn telemetry_select_keeps_flagged_samples_and_drops_misaligned_series() {
+        let telemetry = FrameTelemetry {
+            gpu_load_pct: vec![90.0, 50.0, 99.0],
+            gpu_power_w: vec![200.0],
+            ..FrameTelemetry::default()
+        };
+
+        let selected = telemetry.select(&[true, false, true]);
+        assert_eq!(selected.gpu_load_pct, vec![90.0, 99.0]);
+        assert!(selected.gpu_power_w.is_empty());
+    }
 }
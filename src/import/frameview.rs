@@ -0,0 +1,82 @@
+//! NVIDIA FrameView CSV parser
+//!
+//! FrameView is built on PresentMon and writes one row per present, followed
+//! by GPU/CPU clocks, utilisation and (with PCAT hardware) board power.
+//!
+//! CSV Format (simplified):
+//! - "MsBetweenPresents" column contains frame times in milliseconds
+//! - "Application" column contains the game/app name
+//! - FrameView-only columns such as "GPU0Clk(MHz)", "MsPCLatency" and
+//!   "PCAT Power Total(W)" identify the tool
+
+use anyhow::Result;
+use std::path::Path;
+
+use super::common::FrameData;
+use super::presentmon::{header_columns, parse_present_csv, PresentCsvSchema};
+
+/// Tool name reported for FrameView captures.
+pub const FRAMEVIEW_SOURCE: &str = "FrameView";
+
+const FRAMEVIEW_SCHEMA: PresentCsvSchema = PresentCsvSchema {
+    source: FRAMEVIEW_SOURCE,
+    frame_time_column: "MsBetweenPresents",
+};
+
+/// Columns PresentMon, CapFrameX and OCAT never write.
+const FRAMEVIEW_MARKER_COLUMNS: [&str; 6] = [
+    "gpu0clk(mhz)",
+    "gpu0util(%)",
+    "mspclatency",
+    "msrenderpresentlatency",
+    "pcat power total(w)",
+    "perf/watt(f/j) - tgp",
+];
+
+/// Whether a header line looks like a FrameView export.
+pub fn looks_like_frameview_header(line: &str) -> bool {
+    let columns = header_columns(line);
+    columns.iter().any(|column| column == "msbetweenpresents")
+        && columns
+            .iter()
+            .any(|column| FRAMEVIEW_MARKER_COLUMNS.contains(&column.as_str()))
+}
+
+/// Parse a FrameView capture CSV file
+pub fn parse_frameview_csv<P: AsRef<Path>>(path: P) -> Result<FrameData> {
+    parse_present_csv(path, FRAMEVIEW_SCHEMA, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_parse_frameview_csv_ignores_display_change_column() {
+        let csv_content = "Application,GPU,Resolution,Runtime,AllowsTearing,ProcessID,SwapChainAddress,SyncInterval,PresentFlags,PresentMode,Dropped,TimeInSeconds,MsBetweenPresents,MsBetweenDisplayChange,MsInPresentAPI,MsUntilRenderComplete,MsUntilDisplayed,GPU0Clk(MHz),GPU0Util(%),MsPCLatency
+Cyberpunk2077.exe,NVIDIA GeForce RTX 4080,2560x1440,DXGI,1,4321,0x1,0,0,Hardware: Independent Flip,0,0.016,16.50,0.00,0.20,9.1,17.0,2715,97,31.2
+Cyberpunk2077.exe,NVIDIA GeForce RTX 4080,2560x1440,DXGI,1,4321,0x1,0,0,Hardware: Independent Flip,0,0.033,16.80,33.30,0.20,9.1,17.0,2715,98,30.8
+Cyberpunk2077.exe,NVIDIA GeForce RTX 4080,2560x1440,DXGI,1,4321,0x1,0,0,Hardware: Independent Flip,0,0.050,16.60,16.60,0.20,9.1,17.0,2715,97,31.0
+";
+        assert!(looks_like_frameview_header(
+            csv_content.lines().next().unwrap()
+        ));
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_content).unwrap();
+
+        let result = parse_frameview_csv(file.path()).unwrap();
+        assert_eq!(result.frame_times_ms, vec![16.50, 16.80, 16.60]);
+        assert_eq!(result.application, Some("Cyberpunk2077.exe".to_string()));
+        assert_eq!(result.source, FRAMEVIEW_SOURCE);
+    }
+
+    #[test]
+    fn test_plain_presentmon_header_is_not_frameview() {
+        assert!(!looks_like_frameview_header(
+            "Application,ProcessID,SwapChainAddress,Runtime,TimeInSeconds,MsBetweenPresents,MsBetweenDisplayChange"
+        ));
+    }
+}
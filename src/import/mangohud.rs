@@ -11,14 +11,57 @@
 //!
 //! Log format:
 //!   fps,frametime,cpu_load,gpu_load,...
-//! Where frametime is in milliseconds
+//! Where frametime is in milliseconds. The remaining columns (loads, temps,
+//! clocks, power, memory) are kept as per-frame telemetry.
 
 use anyhow::{Context, Result};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-use super::common::FrameData;
+use super::common::{FrameData, FrameTelemetry, TelemetryMetric};
+
+/// Maps MangoHud log columns to telemetry series.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MangoHudTelemetryColumns {
+    columns: Vec<(usize, TelemetryMetric)>,
+}
+
+impl MangoHudTelemetryColumns {
+    /// Locate known telemetry columns in a header row.
+    pub(crate) fn from_header(fields: &[&str]) -> Self {
+        let columns = fields
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, field)| {
+                let metric = match field.trim().to_ascii_lowercase().as_str() {
+                    "cpu_load" => TelemetryMetric::CpuLoad,
+                    "gpu_load" => TelemetryMetric::GpuLoad,
+                    "cpu_temp" => TelemetryMetric::CpuTemp,
+                    "gpu_temp" => TelemetryMetric::GpuTemp,
+                    "gpu_core_clock" => TelemetryMetric::GpuCoreClock,
+                    "gpu_power" => TelemetryMetric::GpuPower,
+                    "ram_used" => TelemetryMetric::RamUsed,
+                    "gpu_vram_used" | "vram_used" => TelemetryMetric::VramUsed,
+                    _ => return None,
+                };
+                Some((idx, metric))
+            })
+            .collect();
+        Self { columns }
+    }
+
+    /// Append one sample per known column; unparsable values become NaN.
+    pub(crate) fn push_row(&self, fields: &[&str], telemetry: &mut FrameTelemetry) {
+        for (idx, metric) in &self.columns {
+            let value = fields
+                .get(*idx)
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .unwrap_or(f64::NAN);
+            telemetry.series_mut(*metric).push(value);
+        }
+    }
+}
 
 fn parse_valid_frametime(value: &str) -> Option<f64> {
     let ms = value.trim().parse::<f64>().ok()?;
@@ -88,8 +131,10 @@ pub fn parse_mangohud_log<P: AsRef<Path>>(path: P) -> Result<FrameData> {
     // Find header row
     let mut frametime_col: Option<usize> = None;
     let mut header_found = false;
+    let mut telemetry_columns = MangoHudTelemetryColumns::default();
 
     let mut frame_times: Vec<f64> = Vec::new();
+    let mut telemetry = FrameTelemetry::default();
 
     for line_result in lines {
         let line = line_result?;
@@ -112,6 +157,7 @@ pub fn parse_mangohud_log<P: AsRef<Path>>(path: P) -> Result<FrameData> {
 
             if frametime_col.is_some() {
                 header_found = true;
+                telemetry_columns = MangoHudTelemetryColumns::from_header(&fields);
                 continue;
             }
 
@@ -141,6 +187,7 @@ pub fn parse_mangohud_log<P: AsRef<Path>>(path: P) -> Result<FrameData> {
             if let Some(value) = fields.get(col) {
                 if let Some(ms) = parse_valid_frametime(value) {
                     frame_times.push(ms);
+                    telemetry_columns.push_row(&fields, &mut telemetry);
                 }
             }
         }
@@ -173,9 +220,44 @@ pub fn parse_mangohud_log<P: AsRef<Path>>(path: P) -> Result<FrameData> {
         application,
         duration_secs,
         source: "MangoHud".to_string(),
+        telemetry,
+        sampled: false,
     })
 }
 
+/// Read the system header MangoHud writes before the frame rows:
+///   os,cpu,gpu,ram,kernel,driver,cpuscheduler
+///   Arch Linux,AMD Ryzen 7 7800X3D,AMD Radeon RX 7900 XTX,32768,6.9.1-arch1-1,Mesa 24.1.0,performance
+/// Returns lower-cased key/value pairs; empty if the log has no such header.
+pub fn read_mangohud_system_header<P: AsRef<Path>>(path: P) -> Vec<(String, String)> {
+    let Ok(file) = File::open(path.as_ref()) else {
+        return Vec::new();
+    };
+
+    let mut lines = BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .take(4);
+    let Some(keys) = lines.next() else {
+        return Vec::new();
+    };
+    let keys: Vec<String> = keys
+        .split(',')
+        .map(|key| key.trim().to_ascii_lowercase())
+        .collect();
+    if !keys.iter().any(|key| key == "os") || keys.iter().any(|key| key == "frametime") {
+        return Vec::new();
+    }
+    let Some(values) = lines.next() else {
+        return Vec::new();
+    };
+
+    keys.into_iter()
+        .zip(values.split(',').map(|value| value.trim().to_string()))
+        .collect()
+}
+
 /// Find the most recent MangoHud log in common locations
 pub fn find_latest_mangohud_log() -> Option<std::path::PathBuf> {
     let mut possible_paths = Vec::new();
@@ -254,6 +336,51 @@ mod tests {
         assert_eq!(result.source, "MangoHud");
     }
 
+    #[test]
+    fn test_read_mangohud_system_header() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            "os,cpu,gpu,ram,kernel,driver,cpuscheduler\n\
+             Arch Linux,AMD Ryzen 7 7800X3D,AMD Radeon RX 7900 XTX,32,6.9.1,Mesa 24.1.0,performance\n\
+             fps,frametime\n\
+             144,6.94\n"
+        )
+        .unwrap();
+        let header = read_mangohud_system_header(file.path());
+        assert!(header.contains(&("driver".to_string(), "Mesa 24.1.0".to_string())));
+        assert!(header.contains(&("kernel".to_string(), "6.9.1".to_string())));
+
+        let mut plain = NamedTempFile::new().unwrap();
+        write!(plain, "fps,frametime\n144,6.94\n").unwrap();
+        assert!(read_mangohud_system_header(plain.path()).is_empty());
+    }
+
+    #[test]
+    fn test_parse_mangohud_log_keeps_telemetry_columns() {
+        let log_content = r#"os,cpu,gpu,ram,kernel,driver,cpuscheduler
+Arch Linux,AMD Ryzen 7 7800X3D,AMD Radeon RX 7900 XTX,32,6.9.1,Mesa 24.1,
+fps,frametime,cpu_load,gpu_load,cpu_temp,gpu_temp,gpu_core_clock,gpu_mem_clock,gpu_vram_used,gpu_power,ram_used,swap_used,process_rss,elapsed
+144,6.94,35,97,68,71,2500,1250,9.1,310,12.5,0,3.2,100000
+143,6.99,37,98,69,72,2510,1250,9.1,315,12.5,0,3.2,106990
+0,0,37,98,69,72,2510,1250,9.1,315,12.5,0,3.2,106990
+"#;
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", log_content).unwrap();
+
+        let result = parse_mangohud_log(file.path()).unwrap();
+        assert_eq!(result.frame_times_ms, vec![6.94, 6.99]);
+        assert_eq!(result.telemetry.gpu_load_pct, vec![97.0, 98.0]);
+        assert_eq!(result.telemetry.gpu_power_w, vec![310.0, 315.0]);
+        assert_eq!(result.telemetry.vram_used_gb, vec![9.1, 9.1]);
+
+        let summary = result.calculate_stats().unwrap().telemetry.unwrap();
+        let cpu_temp = summary.cpu_temp_c.unwrap();
+        assert!((cpu_temp.mean - 68.5).abs() < 1e-9);
+        assert!((cpu_temp.max - 69.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_parse_mangohud_simple_format() {
         // Some MangoHud configs output just frame times
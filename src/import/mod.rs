@@ -1,14 +1,30 @@
 //! Import module
 //!
 //! Parses benchmark data from external FPS overlay tools:
+//! - MSI Afterburner / RTSS (Windows) - HML log format
 //! - CapFrameX (Windows) - CSV format
 //! - MangoHud (Linux) - CSV format
-//! - FrameView (Windows) - CSV format (similar to CapFrameX)
+//! - FrameView (Windows) - CSV format (PresentMon columns plus GPU telemetry)
+//! - OCAT (Windows) - CSV format (PresentMon columns plus VR metrics)
+//! - PresentMon 2.x (Windows) - CSV format (`FrameTime` schema)
 
+pub mod afterburner;
+pub mod bottleneck;
 pub mod capframex;
 mod common;
+pub mod frameview;
 pub mod mangohud;
+pub mod ocat;
+pub mod presentmon;
+pub mod trim;
 
+pub use afterburner::parse_afterburner_hml;
 pub use capframex::parse_capframex_csv;
-pub(crate) use common::FrameData;
+pub(crate) use common::{
+    BenchmarkResult, FrameData, FrameTelemetry, FrameTimeStats, TelemetrySummary,
+    DEFAULT_LOW_FPS_THRESHOLD,
+};
+pub use frameview::parse_frameview_csv;
 pub use mangohud::parse_mangohud_log;
+pub use ocat::parse_ocat_csv;
+pub use presentmon::parse_presentmon2_csv;
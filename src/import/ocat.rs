@@ -0,0 +1,79 @@
+//! AMD OCAT CSV parser
+//!
+//! OCAT (Open Capture and Analytics Tool) records with PresentMon and saves
+//! captures as `OCAT-<process>-<timestamp>.csv`.
+//!
+//! CSV Format (simplified):
+//! - "MsBetweenPresents" column contains frame times in milliseconds
+//! - "Application" column contains the game/app name
+//! - VR columns such as "Motion-to-Photon Latency", "AppMissed" and
+//!   "WarpMissed" identify the tool
+
+use anyhow::Result;
+use std::path::Path;
+
+use super::common::FrameData;
+use super::presentmon::{header_columns, parse_present_csv, PresentCsvSchema};
+
+/// Tool name reported for OCAT captures.
+pub const OCAT_SOURCE: &str = "OCAT";
+
+const OCAT_SCHEMA: PresentCsvSchema = PresentCsvSchema {
+    source: OCAT_SOURCE,
+    frame_time_column: "MsBetweenPresents",
+};
+
+/// Columns PresentMon, CapFrameX and FrameView never write.
+const OCAT_MARKER_COLUMNS: [&str; 4] = [
+    "motion-to-photon latency",
+    "appmissed",
+    "warpmissed",
+    "reprojectionstart",
+];
+
+/// Whether a header line looks like an OCAT recording.
+pub fn looks_like_ocat_header(line: &str) -> bool {
+    let columns = header_columns(line);
+    columns.iter().any(|column| column == "msbetweenpresents")
+        && columns
+            .iter()
+            .any(|column| OCAT_MARKER_COLUMNS.contains(&column.as_str()))
+}
+
+/// Whether a file name follows OCAT's `OCAT-<process>-<timestamp>.csv` pattern.
+pub fn looks_like_ocat_file_name(file_name: &str) -> bool {
+    let lower = file_name.to_ascii_lowercase();
+    lower.starts_with("ocat-") && lower.ends_with(".csv")
+}
+
+/// Parse an OCAT capture CSV file
+pub fn parse_ocat_csv<P: AsRef<Path>>(path: P) -> Result<FrameData> {
+    parse_present_csv(path, OCAT_SCHEMA, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_parse_ocat_csv() {
+        let csv_content = "Application,ProcessID,SwapChainAddress,Runtime,SyncInterval,PresentFlags,AllowsTearing,PresentMode,WasBatched,DwmNotified,Dropped,TimeInSeconds,MsBetweenPresents,MsBetweenDisplayChange,MsInPresentAPI,MsUntilRenderComplete,MsUntilDisplayed,Motion-to-Photon Latency,AppMissed,WarpMissed
+eldenring.exe,777,0x1,DXGI,1,0,0,Hardware: Legacy Flip,0,0,0,0.016,16.66,16.66,0.1,8.0,16.9,,0,0
+eldenring.exe,777,0x1,DXGI,1,0,0,Hardware: Legacy Flip,0,0,0,0.033,16.67,16.67,0.1,8.0,16.9,,0,0
+";
+        assert!(looks_like_ocat_header(csv_content.lines().next().unwrap()));
+        assert!(looks_like_ocat_file_name(
+            "OCAT-eldenring.exe-2024-05-01T101010.csv"
+        ));
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_content).unwrap();
+
+        let result = parse_ocat_csv(file.path()).unwrap();
+        assert_eq!(result.frame_times_ms, vec![16.66, 16.67]);
+        assert_eq!(result.application, Some("eldenring.exe".to_string()));
+        assert_eq!(result.source, OCAT_SOURCE);
+    }
+}
@@ -0,0 +1,284 @@
+//! PresentMon-family CSV parser
+//!
+//! PresentMon, NVIDIA FrameView and AMD OCAT all write one row per present.
+//! PresentMon 1.x, FrameView and OCAT report frame times in `MsBetweenPresents`.
+//! PresentMon 2.x renamed it to `FrameTime` and added `CPUStartTime`,
+//! `CPUBusy`, `GPUTime` and related columns.
+//!
+//! Columns are matched by exact name so sibling columns such as
+//! `MsBetweenDisplayChange` are never mistaken for the frame time.
+//! `CPUBusy` and `GPUBusy` (or 1.x `MsGPUActive`) are kept as per-frame
+//! telemetry for bottleneck classification.
+
+use anyhow::{Context, Result};
+use csv::ReaderBuilder;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::benchmark::focus;
+
+use super::common::{FrameData, FrameTelemetry};
+
+/// Tool name reported for PresentMon 2.x captures.
+pub const PRESENTMON2_SOURCE: &str = "PresentMon 2.x";
+
+/// Column layout of a PresentMon-family CSV.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PresentCsvSchema {
+    /// Tool name stored in `FrameData::source`
+    pub source: &'static str,
+    /// Frame time column (ms), compared case-insensitively
+    pub frame_time_column: &'static str,
+}
+
+const PRESENTMON2_SCHEMA: PresentCsvSchema = PresentCsvSchema {
+    source: PRESENTMON2_SOURCE,
+    frame_time_column: "FrameTime",
+};
+
+/// PresentMon busy-time columns, kept as per-frame telemetry.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct BusyColumns {
+    cpu: Option<usize>,
+    gpu: Option<usize>,
+}
+
+impl BusyColumns {
+    /// Locate busy-time columns among lowercase header names.
+    pub(super) fn from_header(columns: &[String]) -> Self {
+        let find = |names: &[&str]| columns.iter().position(|c| names.contains(&c.as_str()));
+        Self {
+            cpu: find(&["cpubusy", "mscpubusy"]),
+            gpu: find(&["gpubusy", "msgpubusy", "msgpuactive"]),
+        }
+    }
+
+    /// Append one sample per located column; "NA" and blanks become NaN.
+    pub(super) fn push_row(&self, record: &csv::StringRecord, telemetry: &mut FrameTelemetry) {
+        let value = |col: usize| {
+            record
+                .get(col)
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .filter(|value| value.is_finite())
+                .unwrap_or(f64::NAN)
+        };
+        if let Some(col) = self.cpu {
+            telemetry.cpu_busy_ms.push(value(col));
+        }
+        if let Some(col) = self.gpu {
+            telemetry.gpu_busy_ms.push(value(col));
+        }
+    }
+}
+
+/// Split a CSV header line into lowercase column names.
+pub(super) fn header_columns(line: &str) -> Vec<String> {
+    line.split(',')
+        .map(|field| field.trim().trim_matches('"').trim().to_ascii_lowercase())
+        .collect()
+}
+
+/// Whether a header line uses the PresentMon 2.x column schema.
+pub fn looks_like_presentmon2_header(line: &str) -> bool {
+    let columns = header_columns(line);
+    let has = |name: &str| columns.iter().any(|column| column == name);
+    has("frametime") && (has("cpustarttime") || has("presentruntime"))
+}
+
+/// Heuristically detect whether a file is a PresentMon 2.x capture.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub fn looks_like_presentmon2_capture_file<P: AsRef<Path>>(path: P) -> bool {
+    let Ok(file) = File::open(path.as_ref()) else {
+        return false;
+    };
+
+    BufReader::new(file)
+        .lines()
+        .take(25)
+        .map_while(|line| line.ok())
+        .any(|line| looks_like_presentmon2_header(line.trim()))
+}
+
+/// Parse a PresentMon 2.x capture CSV file
+pub fn parse_presentmon2_csv<P: AsRef<Path>>(path: P) -> Result<FrameData> {
+    parse_presentmon2_csv_for_process(path, None)
+}
+
+/// Parse a PresentMon 2.x CSV file and optionally filter rows to a target process.
+pub fn parse_presentmon2_csv_for_process<P: AsRef<Path>>(
+    path: P,
+    process_filter: Option<&str>,
+) -> Result<FrameData> {
+    parse_present_csv(path, PRESENTMON2_SCHEMA, process_filter)
+}
+
+/// Parse any PresentMon-family CSV using the given column layout.
+pub(super) fn parse_present_csv<P: AsRef<Path>>(
+    path: P,
+    schema: PresentCsvSchema,
+    process_filter: Option<&str>,
+) -> Result<FrameData> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_path(path.as_ref())
+        .with_context(|| format!("Failed to open file: {:?}", path.as_ref()))?;
+
+    let frame_time_name = schema.frame_time_column.to_ascii_lowercase();
+    let mut frametime_col: Option<usize> = None;
+    let mut application_col: Option<usize> = None;
+    let mut process_name_col: Option<usize> = None;
+    let mut busy_columns = BusyColumns::default();
+
+    let mut frame_times: Vec<f64> = Vec::new();
+    let mut telemetry = FrameTelemetry::default();
+    let mut application: Option<String> = None;
+    let mut seen_processes: HashSet<String> = HashSet::new();
+    let normalized_filter = process_filter.map(focus::normalize_process_name);
+
+    for record_result in reader.records() {
+        let record = record_result.with_context(|| {
+            format!("Failed to parse CSV record from file: {:?}", path.as_ref())
+        })?;
+
+        if record.iter().all(|field| field.trim().is_empty()) {
+            continue;
+        }
+
+        // Skip any preamble until the header row naming the frame time column.
+        let Some(frametime_idx) = frametime_col else {
+            let columns: Vec<String> = record
+                .iter()
+                .map(|field| field.trim().trim_matches('"').to_ascii_lowercase())
+                .collect();
+            let find = |names: &[&str]| columns.iter().position(|c| names.contains(&c.as_str()));
+            frametime_col = find(&[frame_time_name.as_str()]);
+            if frametime_col.is_some() {
+                application_col = find(&["application"]);
+                process_name_col = find(&["processname", "process"]);
+                busy_columns = BusyColumns::from_header(&columns);
+            }
+            continue;
+        };
+
+        let field_value = |col: Option<usize>| {
+            col.and_then(|col| record.get(col))
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let process_value = field_value(process_name_col).or_else(|| field_value(application_col));
+
+        if let Some(proc_name) = process_value.as_deref() {
+            seen_processes.insert(proc_name.to_string());
+        }
+
+        if let Some(filter) = normalized_filter.as_deref() {
+            let matches = process_value
+                .as_deref()
+                .map(focus::normalize_process_name)
+                .map(|candidate| candidate == filter)
+                .unwrap_or(false);
+            if !matches {
+                continue;
+            }
+        }
+
+        // PresentMon writes "NA" for metrics it could not compute on a row.
+        if let Some(ms) = record
+            .get(frametime_idx)
+            .and_then(|value| value.trim().parse::<f64>().ok())
+        {
+            if ms.is_finite() && ms > 0.0 && ms <= 10_000.0 {
+                frame_times.push(ms);
+                busy_columns.push_row(&record, &mut telemetry);
+            }
+        }
+
+        if application.is_none() {
+            application = process_value;
+        }
+    }
+
+    if frametime_col.is_none() {
+        anyhow::bail!(
+            "No {} column found; this does not look like a {} capture",
+            schema.frame_time_column,
+            schema.source
+        );
+    }
+
+    if frame_times.is_empty() {
+        if process_filter.is_some() && !seen_processes.is_empty() {
+            let mut observed: Vec<String> = seen_processes.into_iter().collect();
+            observed.sort_unstable();
+            anyhow::bail!(
+                "No frame time data found for requested process. Observed processes: {}",
+                observed.join(", ")
+            );
+        }
+        anyhow::bail!("No frame time data found in CSV file");
+    }
+
+    let total_ms: f64 = frame_times.iter().sum();
+    let duration_secs = total_ms / 1000.0;
+
+    Ok(FrameData {
+        frame_times_ms: frame_times,
+        application,
+        duration_secs,
+        source: schema.source.to_string(),
+        telemetry,
+        sampled: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    const PRESENTMON2_CSV: &str = "Application,ProcessID,SwapChainAddress,PresentRuntime,SyncInterval,PresentFlags,AllowsTearing,PresentMode,FrameType,CPUStartTime,FrameTime,CPUBusy,CPUWait,GPULatency,GPUTime,GPUBusy,GPUWait,DisplayLatency,DisplayedTime
+game.exe,1234,0x1,DXGI,0,0,1,Hardware: Independent Flip,Application,1000.000,16.70,8.1,8.6,2.0,12.0,11.5,0.5,20.1,16.7
+game.exe,1234,0x1,DXGI,0,0,1,Hardware: Independent Flip,Application,1016.700,16.60,8.0,8.6,2.0,12.0,11.4,0.6,NA,NA
+other.exe,99,0x2,DXGI,0,0,1,Composed: Flip,Application,1020.000,33.30,8.0,25.3,2.0,12.0,11.4,0.6,NA,NA
+game.exe,1234,0x1,DXGI,0,0,1,Hardware: Independent Flip,Application,1033.300,NA,8.0,8.6,2.0,12.0,11.4,0.6,NA,NA
+game.exe,1234,0x1,DXGI,0,0,1,Hardware: Independent Flip,Application,1050.000,16.80,8.0,8.6,2.0,12.0,11.4,0.6,NA,NA
+";
+
+    #[test]
+    fn test_parse_presentmon2_csv_uses_frametime_column() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", PRESENTMON2_CSV).unwrap();
+
+        assert!(looks_like_presentmon2_capture_file(file.path()));
+
+        let result = parse_presentmon2_csv_for_process(file.path(), Some("game.exe")).unwrap();
+        assert_eq!(result.frame_times_ms, vec![16.70, 16.60, 16.80]);
+        assert_eq!(result.application, Some("game.exe".to_string()));
+        assert_eq!(result.source, PRESENTMON2_SOURCE);
+        assert_eq!(result.telemetry.cpu_busy_ms, vec![8.1, 8.0, 8.0]);
+        assert_eq!(result.telemetry.gpu_busy_ms, vec![11.5, 11.4, 11.4]);
+    }
+
+    #[test]
+    fn test_presentmon2_header_detection_ignores_v1_and_mangohud() {
+        assert!(!looks_like_presentmon2_header(
+            "Application,ProcessID,Runtime,TimeInSeconds,MsBetweenPresents"
+        ));
+        assert!(!looks_like_presentmon2_header(
+            "fps,frametime,cpu_load,gpu_load"
+        ));
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            "Application,MsBetweenPresents,TimeInSeconds\ngame.exe,16.67,0.01667\n"
+        )
+        .unwrap();
+        assert!(parse_presentmon2_csv(file.path()).is_err());
+    }
+}
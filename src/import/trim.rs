@@ -0,0 +1,380 @@
+//! Start/end trimming of frametime captures
+//!
+//! Loading hitches and menu frames at either end of a capture skew the lows.
+//! Trims can be given explicitly (seconds or frames) and/or detected from the
+//! frametime pattern of leading and trailing load-screen segments.
+//!
+//! Sampled captures (one row per polling interval, e.g. Afterburner) convert
+//! seconds to rows with the polling interval, and skip load-screen detection:
+//! a handful of polled readings says nothing about frame pacing.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use super::FrameData;
+
+/// Never let auto-detection remove more than this share of frames per side.
+const AUTO_TRIM_MAX_SHARE: f64 = 0.25;
+/// Window length used when scanning for load-screen segments.
+const AUTO_TRIM_WINDOW_MS: f64 = 500.0;
+const AUTO_TRIM_MIN_WINDOW_FRAMES: usize = 10;
+/// Windows this much slower than steady state look like loading.
+const LOADING_SLOW_FACTOR: f64 = 2.0;
+/// Windows this much faster than steady state look like menus/black screens.
+const LOADING_FAST_FACTOR: f64 = 2.5;
+/// Single frames above max(steady * factor, floor) are load hitches.
+const LOADING_HITCH_FACTOR: f64 = 4.0;
+const LOADING_HITCH_FLOOR_MS: f64 = 100.0;
+
+/// An amount to trim from one end of a capture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrimAmount {
+    Seconds(f64),
+    Frames(usize),
+}
+
+impl FromStr for TrimAmount {
+    type Err = String;
+
+    /// Accepts `10`, `10s`, `2.5s` (seconds) or `300f` / `300frames` (frames).
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        let trimmed = value.trim().to_ascii_lowercase();
+        let invalid = || {
+            format!(
+                "invalid trim '{}': use seconds (e.g. 5s) or frames (e.g. 300f)",
+                value
+            )
+        };
+
+        if let Some(frames) = trimmed
+            .strip_suffix("frames")
+            .or_else(|| trimmed.strip_suffix('f'))
+        {
+            return frames
+                .trim()
+                .parse::<usize>()
+                .map(TrimAmount::Frames)
+                .map_err(|_| invalid());
+        }
+
+        let seconds = trimmed.strip_suffix('s').unwrap_or(&trimmed).trim();
+        match seconds.parse::<f64>() {
+            Ok(secs) if secs.is_finite() && secs >= 0.0 => Ok(TrimAmount::Seconds(secs)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for TrimAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrimAmount::Seconds(secs) => write!(f, "{}s", secs),
+            TrimAmount::Frames(frames) => write!(f, "{}f", frames),
+        }
+    }
+}
+
+/// Requested trimming for a capture.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TrimOptions {
+    pub start: Option<TrimAmount>,
+    pub end: Option<TrimAmount>,
+    /// Detect leading/trailing load-screen segments after the explicit trims.
+    pub auto_detect: bool,
+}
+
+impl TrimOptions {
+    pub fn is_noop(&self) -> bool {
+        self.start.is_none() && self.end.is_none() && !self.auto_detect
+    }
+}
+
+/// What was removed from a capture.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrimReport {
+    pub leading_frames: usize,
+    pub leading_secs: f64,
+    pub trailing_frames: usize,
+    pub trailing_secs: f64,
+    /// Portion of the leading/trailing frames found by load-screen detection.
+    pub auto_leading_frames: usize,
+    pub auto_trailing_frames: usize,
+    /// Frames kept after trimming.
+    pub kept_frames: usize,
+}
+
+impl TrimReport {
+    pub fn is_empty(&self) -> bool {
+        self.leading_frames == 0 && self.trailing_frames == 0
+    }
+
+    /// One-line description for submission notes.
+    pub fn summary(&self) -> String {
+        let mut text = format!(
+            "Trimmed start {} frames ({:.1}s), end {} frames ({:.1}s); kept {} frames",
+            self.leading_frames,
+            self.leading_secs,
+            self.trailing_frames,
+            self.trailing_secs,
+            self.kept_frames
+        );
+        if self.auto_leading_frames > 0 || self.auto_trailing_frames > 0 {
+            text.push_str(&format!(
+                " (auto load-screen: {} start, {} end)",
+                self.auto_leading_frames, self.auto_trailing_frames
+            ));
+        }
+        text
+    }
+}
+
+impl FrameData {
+    /// Return a trimmed copy of this capture along with what was removed.
+    pub fn trimmed(&self, options: &TrimOptions) -> Result<(FrameData, TrimReport)> {
+        let sample_interval_ms = self
+            .sampled
+            .then(|| self.duration_secs * 1000.0 / self.frame_times_ms.len() as f64)
+            .filter(|interval| interval.is_finite() && *interval > 0.0);
+        let (frame_times_ms, report) =
+            trim_rows(&self.frame_times_ms, sample_interval_ms, options)?;
+        let removed_secs = report.leading_secs + report.trailing_secs;
+        let kept_end = self.frame_times_ms.len() - report.trailing_frames;
+        Ok((
+            FrameData {
+                frame_times_ms,
+                application: self.application.clone(),
+                duration_secs: (self.duration_secs - removed_secs).max(0.0),
+                source: self.source.clone(),
+                telemetry: self.telemetry.slice(report.leading_frames, kept_end),
+                sampled: self.sampled,
+            },
+            report,
+        ))
+    }
+}
+
+/// Apply explicit trims, then optional load-screen detection, to a frametime series.
+pub fn trim_frame_times(
+    frame_times_ms: &[f64],
+    options: &TrimOptions,
+) -> Result<(Vec<f64>, TrimReport)> {
+    trim_rows(frame_times_ms, None, options)
+}
+
+/// Trim a series whose rows are either frames or, with `sample_interval_ms`,
+/// polled samples taken that far apart.
+fn trim_rows(
+    frame_times_ms: &[f64],
+    sample_interval_ms: Option<f64>,
+    options: &TrimOptions,
+) -> Result<(Vec<f64>, TrimReport)> {
+    let total = frame_times_ms.len();
+    let rows_for = |values: &mut dyn Iterator<Item = &f64>, amount| match sample_interval_ms {
+        Some(interval_ms) => rows_for_sampled_amount(total, interval_ms, amount),
+        None => frames_for_amount(values, amount),
+    };
+    let mut start = options
+        .start
+        .map(|amount| rows_for(&mut frame_times_ms.iter(), amount))
+        .unwrap_or(0);
+    let mut end = total.saturating_sub(
+        options
+            .end
+            .map(|amount| rows_for(&mut frame_times_ms.iter().rev(), amount))
+            .unwrap_or(0),
+    );
+
+    if start >= end {
+        anyhow::bail!(
+            "Trimming removed all {} frames; use a smaller --trim-start/--trim-end",
+            total
+        );
+    }
+
+    let mut auto_leading_frames = 0;
+    let mut auto_trailing_frames = 0;
+    if options.auto_detect && sample_interval_ms.is_none() {
+        let (leading, trailing) = detect_load_segments(&frame_times_ms[start..end]);
+        auto_leading_frames = leading;
+        auto_trailing_frames = trailing;
+        start += leading;
+        end -= trailing;
+    }
+
+    let sum_secs = |values: &[f64]| match sample_interval_ms {
+        Some(interval_ms) => values.len() as f64 * interval_ms / 1000.0,
+        None => values.iter().sum::<f64>() / 1000.0,
+    };
+    let report = TrimReport {
+        leading_frames: start,
+        leading_secs: sum_secs(&frame_times_ms[..start]),
+        trailing_frames: total - end,
+        trailing_secs: sum_secs(&frame_times_ms[end..]),
+        auto_leading_frames,
+        auto_trailing_frames,
+        kept_frames: end - start,
+    };
+
+    Ok((frame_times_ms[start..end].to_vec(), report))
+}
+
+/// Rows covering `amount` when each row is one `interval_ms` poll
+fn rows_for_sampled_amount(total: usize, interval_ms: f64, amount: TrimAmount) -> usize {
+    match amount {
+        TrimAmount::Frames(rows) => rows,
+        TrimAmount::Seconds(secs) => ((secs * 1000.0 / interval_ms).round() as usize).min(total),
+    }
+}
+
+fn frames_for_amount<'a>(values: impl Iterator<Item = &'a f64>, amount: TrimAmount) -> usize {
+    match amount {
+        TrimAmount::Frames(frames) => frames,
+        TrimAmount::Seconds(secs) => {
+            let limit_ms = secs * 1000.0;
+            let mut elapsed_ms = 0.0;
+            let mut frames = 0;
+            for value in values {
+                if elapsed_ms >= limit_ms {
+                    break;
+                }
+                elapsed_ms += value;
+                frames += 1;
+            }
+            frames
+        }
+    }
+}
+
+/// Find contiguous load-screen windows at the start and end of a capture.
+/// Returns `(leading_frames, trailing_frames)`.
+fn detect_load_segments(frame_times_ms: &[f64]) -> (usize, usize) {
+    let Some(steady_ms) = median(frame_times_ms) else {
+        return (0, 0);
+    };
+    if steady_ms <= 0.0 {
+        return (0, 0);
+    }
+
+    let window =
+        ((AUTO_TRIM_WINDOW_MS / steady_ms).round() as usize).max(AUTO_TRIM_MIN_WINDOW_FRAMES);
+    let max_frames = (frame_times_ms.len() as f64 * AUTO_TRIM_MAX_SHARE) as usize;
+    let hitch_ms = (steady_ms * LOADING_HITCH_FACTOR).max(LOADING_HITCH_FLOOR_MS);
+
+    let looks_like_loading = |chunk: &[f64]| {
+        let mean = chunk.iter().sum::<f64>() / chunk.len() as f64;
+        mean > steady_ms * LOADING_SLOW_FACTOR
+            || mean < steady_ms / LOADING_FAST_FACTOR
+            || chunk.iter().any(|ft| *ft >= hitch_ms)
+    };
+
+    let mut leading = 0;
+    for chunk in frame_times_ms.chunks(window) {
+        if leading + chunk.len() > max_frames || !looks_like_loading(chunk) {
+            break;
+        }
+        leading += chunk.len();
+    }
+
+    let mut trailing = 0;
+    for chunk in frame_times_ms[leading..].rchunks(window) {
+        if leading + trailing + chunk.len() > max_frames * 2
+            || trailing + chunk.len() > max_frames
+            || !looks_like_loading(chunk)
+        {
+            break;
+        }
+        trailing += chunk.len();
+    }
+
+    (leading, trailing)
+}
+
+fn median(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        Some((sorted[mid - 1] + sorted[mid]) / 2.0)
+    } else {
+        Some(sorted[mid])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{trim_frame_times, TrimAmount, TrimOptions};
+
+    #[test]
+    fn parses_trim_amounts() {
+        assert_eq!("5".parse::<TrimAmount>(), Ok(TrimAmount::Seconds(5.0)));
+        assert_eq!("2.5s".parse::<TrimAmount>(), Ok(TrimAmount::Seconds(2.5)));
+        assert_eq!("300f".parse::<TrimAmount>(), Ok(TrimAmount::Frames(300)));
+        assert_eq!(
+            "120frames".parse::<TrimAmount>(),
+            Ok(TrimAmount::Frames(120))
+        );
+        assert!("-3s".parse::<TrimAmount>().is_err());
+        assert!("abc".parse::<TrimAmount>().is_err());
+    }
+
+    #[test]
+    fn explicit_trims_use_seconds_and_frames() {
+        let frames = vec![10.0; 1_000];
+        let options = TrimOptions {
+            start: Some(TrimAmount::Seconds(1.0)),
+            end: Some(TrimAmount::Frames(50)),
+            auto_detect: false,
+        };
+
+        let (kept, report) = trim_frame_times(&frames, &options).unwrap();
+        assert_eq!(report.leading_frames, 100);
+        assert_eq!(report.trailing_frames, 50);
+        assert_eq!(kept.len(), 850);
+        assert!((report.leading_secs - 1.0).abs() < 1e-9);
+        assert!(report.summary().contains("kept 850 frames"));
+    }
+
+    #[test]
+    fn rejects_trims_that_remove_everything() {
+        let options = TrimOptions {
+            start: Some(TrimAmount::Frames(60)),
+            end: Some(TrimAmount::Frames(60)),
+            auto_detect: false,
+        };
+        assert!(trim_frame_times(&[10.0; 100], &options).is_err());
+    }
+
+    #[test]
+    fn auto_detects_leading_load_hitches_and_trailing_menu() {
+        let mut frames = vec![250.0; 8];
+        frames.extend(vec![40.0; 20]);
+        frames.extend(vec![10.0; 2_000]);
+        frames.extend(vec![2.0; 200]);
+
+        let options = TrimOptions {
+            auto_detect: true,
+            ..TrimOptions::default()
+        };
+        let (kept, report) = trim_frame_times(&frames, &options).unwrap();
+
+        assert!(report.auto_leading_frames >= 28);
+        assert!(report.auto_trailing_frames >= 200);
+        assert!(kept.iter().all(|ft| (*ft - 10.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn auto_detect_keeps_clean_captures_intact() {
+        let options = TrimOptions {
+            auto_detect: true,
+            ..TrimOptions::default()
+        };
+        let (kept, report) = trim_frame_times(&[16.6; 3_000], &options).unwrap();
+        assert!(report.is_empty());
+        assert_eq!(kept.len(), 3_000);
+    }
+}
@@ -22,13 +22,22 @@ mod storage;
 mod tui;
 
 use crate::benchmark::live::{run_live_capture, CaptureSource, FocusPolicy, LiveCaptureOptions};
+use crate::benchmark::passes::{
+    run_multi_pass_capture, validate_multi_pass_options, MultiPassOptions,
+};
+use crate::benchmark::wait::{
+    run_warmup, validate_wait_options, wait_for_target_process, WaitTarget,
+};
 use crate::benchmark::BenchmarkSubmission;
 use crate::benchmark_runner::{print_benchmark_warning, run_benchmarks, show_benchmark_menu};
 use crate::feedback::FeedbackCategory;
-use crate::games::{GameInfo, KNOWN_GAMES};
+use crate::games::{known_games, AntiCheatRisk, GameInfo, Upscaler};
 use crate::hardware::SystemInfo;
-use crate::import::{parse_capframex_csv, parse_mangohud_log};
-#[cfg(target_os = "windows")]
+use crate::import::trim::{TrimAmount, TrimOptions, TrimReport};
+use crate::import::{
+    parse_afterburner_hml, parse_capframex_csv, parse_frameview_csv, parse_mangohud_log,
+    parse_ocat_csv, parse_presentmon2_csv,
+};
 use anyhow::Context;
 use anyhow::Result;
 use clap::{Parser, Subcommand};
@@ -94,7 +103,16 @@ enum Commands {
     },
 
     /// Detect and display your system hardware
-    Detect,
+    Detect {
+        /// Write the files and command output detection reads to DIR, so a
+        /// detection problem can be reproduced (serial numbers are stripped)
+        #[arg(long, value_name = "DIR")]
+        dump_snapshot: Option<PathBuf>,
+
+        /// Detect from a snapshot written by --dump-snapshot instead of this machine
+        #[arg(long, value_name = "DIR", conflicts_with = "dump_snapshot")]
+        from_snapshot: Option<PathBuf>,
+    },
 
     /// Quick submit (for experienced users)
     Submit {
@@ -145,6 +163,22 @@ enum Commands {
         /// Auto-detect the latest capture file
         #[arg(long)]
         auto: bool,
+
+        /// Trim from the start of the capture, in seconds (5s) or frames (300f)
+        #[arg(long, value_name = "AMOUNT")]
+        trim_start: Option<TrimAmount>,
+
+        /// Trim from the end of the capture, in seconds (5s) or frames (300f)
+        #[arg(long, value_name = "AMOUNT")]
+        trim_end: Option<TrimAmount>,
+
+        /// Detect and trim leading/trailing load-screen segments
+        #[arg(long, default_value_t = false)]
+        auto_trim: bool,
+
+        /// Write a self-contained HTML report (charts, diagnostics, hardware) to this path
+        #[arg(long, value_name = "FILE")]
+        report: Option<PathBuf>,
     },
 
     /// Manage and check PC builds for compatibility
@@ -156,7 +190,46 @@ enum Commands {
     /// Run real benchmark capture preview (frametime-based)
     Benchmark {
         #[command(subcommand)]
-        command: BenchmarkCommands,
+        command: Box<BenchmarkCommands>,
+    },
+
+    /// Manage saved frametime traces from live captures and imports
+    Captures {
+        #[command(subcommand)]
+        command: CapturesCommands,
+    },
+
+    /// Compare two captures (A = baseline, B = candidate) with significance tests
+    Compare {
+        /// Baseline capture: a capture file or a saved capture id
+        a: String,
+
+        /// Candidate capture: a capture file or a saved capture id
+        b: String,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        format: OutputFormat,
+    },
+
+    /// Browse and export the local history of benchmark results
+    History {
+        #[command(subcommand)]
+        command: HistoryCommands,
+    },
+
+    /// Detect FPS shifts in your history after GPU driver, kernel or OS updates
+    Regressions {
+        #[command(flatten)]
+        filter: HistoryFilterArgs,
+
+        /// Smallest shift (percent) to report, even when runs are very consistent
+        #[arg(long, default_value_t = benchmark::regressions::DEFAULT_MIN_CHANGE_PCT)]
+        min_change_pct: f64,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        format: OutputFormat,
     },
 
     /// Show configuration and data paths
@@ -199,7 +272,7 @@ enum BenchmarkCommands {
         #[arg(short, long, default_value_t = 90)]
         duration: u64,
 
-        /// Capture file path (MangoHud log path or PresentMon output file)
+        /// Capture file path (MangoHud log path, PresentMon output file, or log to replay)
         #[arg(short, long)]
         file: Option<PathBuf>,
 
@@ -239,6 +312,50 @@ enum BenchmarkCommands {
         #[arg(long)]
         strict_unfocus_grace_ms: Option<u64>,
 
+        /// Number of capture passes to run and aggregate (1-10)
+        #[arg(long, default_value_t = 1)]
+        passes: u32,
+
+        /// Cooldown between passes in seconds (0-600)
+        #[arg(long, default_value_t = 30)]
+        pass_cooldown: u64,
+
+        /// Wait until this game (or process name) is running and focused before capturing
+        #[arg(long, value_name = "NAME")]
+        wait_for_process: Option<String>,
+
+        /// Give up waiting for --wait-for-process after this many seconds (10-3600)
+        #[arg(long, default_value_t = 600)]
+        wait_timeout: u64,
+
+        /// Discard the first N seconds before capture starts (0-300)
+        #[arg(long, default_value_t = 0)]
+        warmup: u64,
+
+        /// Playback speed for --source replay (0.1-100, 1 = real time)
+        #[arg(long, default_value_t = 1.0)]
+        replay_speed: f64,
+
+        /// Trim from the start of each pass, in seconds (5s) or frames (300f)
+        #[arg(long, value_name = "AMOUNT")]
+        trim_start: Option<TrimAmount>,
+
+        /// Trim from the end of each pass, in seconds (5s) or frames (300f)
+        #[arg(long, value_name = "AMOUNT")]
+        trim_end: Option<TrimAmount>,
+
+        /// Detect and trim leading/trailing load-screen segments
+        #[arg(long, default_value_t = false)]
+        auto_trim: bool,
+
+        /// Include the hardware telemetry summary (loads, temps, clocks, power) in the submission
+        #[arg(long, default_value_t = false)]
+        include_telemetry: bool,
+
+        /// Write a self-contained HTML report (charts, diagnostics, hardware) to this path
+        #[arg(long, value_name = "FILE")]
+        report: Option<PathBuf>,
+
         /// Submit captured result immediately
         #[arg(long, default_value_t = false)]
         submit: bool,
@@ -266,6 +383,8 @@ enum BenchmarkSourceArg {
     Auto,
     Mangohud,
     Presentmon,
+    /// Stream a recorded MangoHud/CapFrameX log (testing without a game)
+    Replay,
 }
 
 impl From<BenchmarkSourceArg> for CaptureSource {
@@ -274,6 +393,7 @@ impl From<BenchmarkSourceArg> for CaptureSource {
             BenchmarkSourceArg::Auto => CaptureSource::Auto,
             BenchmarkSourceArg::Mangohud => CaptureSource::MangoHud,
             BenchmarkSourceArg::Presentmon => CaptureSource::PresentMon,
+            BenchmarkSourceArg::Replay => CaptureSource::Replay,
         }
     }
 }
@@ -330,6 +450,95 @@ enum BuildCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum CapturesCommands {
+    /// List saved capture traces
+    List,
+
+    /// Show metadata and recomputed stats for a saved trace
+    Show {
+        /// Capture id (see `captures list`)
+        id: String,
+    },
+
+    /// Re-submit a saved trace as a benchmark
+    Submit {
+        /// Capture id (see `captures list`)
+        id: String,
+    },
+
+    /// Delete a saved trace
+    Delete {
+        /// Capture id (see `captures list`)
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum HistoryCommands {
+    /// List recorded results, newest first
+    List {
+        #[command(flatten)]
+        filter: HistoryFilterArgs,
+    },
+
+    /// Show a recorded result with its hardware snapshot
+    Show {
+        /// History id (see `history list`)
+        id: String,
+    },
+
+    /// Export recorded results
+    Export {
+        #[command(flatten)]
+        filter: HistoryFilterArgs,
+
+        /// Export format
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: ExportFormat,
+
+        /// Write to this file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(clap::Args)]
+struct HistoryFilterArgs {
+    /// Only results for games whose name contains this text
+    #[arg(long)]
+    game: Option<String>,
+
+    /// Only results at this resolution (e.g. 1440p)
+    #[arg(long)]
+    resolution: Option<String>,
+
+    /// Only results recorded on or after this date (YYYY-MM-DD)
+    #[arg(long, value_name = "DATE")]
+    since: Option<chrono::NaiveDate>,
+
+    /// Only results recorded on or before this date (YYYY-MM-DD)
+    #[arg(long, value_name = "DATE")]
+    until: Option<chrono::NaiveDate>,
+}
+
+impl From<HistoryFilterArgs> for storage::HistoryFilter {
+    fn from(args: HistoryFilterArgs) -> Self {
+        Self {
+            game: args.game,
+            resolution: args.resolution,
+            since: args.since,
+            until: args.until,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum ExportFormat {
+    Json,
+    Csv,
+}
+
 #[derive(Clone, Copy, Debug, clap::ValueEnum)]
 enum OutputFormat {
     Text,
@@ -501,6 +710,7 @@ fn main() -> Result<()> {
     init_windows_console();
 
     let cli = Cli::parse();
+    load_games_catalogue();
 
     match cli.command {
         Some(Commands::Start) | None => {
@@ -550,9 +760,37 @@ fn main() -> Result<()> {
             let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(server::start_server(port, !no_open))?;
         }
-        Some(Commands::Detect) => {
-            let system_info = SystemInfo::detect()?;
+        Some(Commands::Detect {
+            dump_snapshot: Some(dir),
+            ..
+        }) => {
+            let summary = hardware::probe::write_snapshot(&dir)?;
+            println!(
+                "{} {} ({} files, {} command outputs)",
+                "Snapshot written to".bright_green(),
+                dir.display().to_string().bright_white(),
+                summary.files,
+                summary.commands
+            );
+            if let Some(error) = summary.detect_error {
+                println!("{} {}", "Detection failed:".bright_yellow(), error);
+            }
+            println!(
+                "{}",
+                "Zip the folder and attach it when reporting a detection problem.".bright_white()
+            );
+        }
+        Some(Commands::Detect { from_snapshot, .. }) => {
+            let system_info = match from_snapshot {
+                Some(dir) => SystemInfo::detect_with(&hardware::probe::Probe::snapshot(&dir)?)?,
+                None => SystemInfo::detect()?,
+            };
             println!("{}", system_info.display());
+            println!(
+                "{} {}",
+                "Hardware fingerprint:".bright_green(),
+                system_info.hardware_fingerprint().bright_white()
+            );
         }
         Some(Commands::Submit {
             game,
@@ -630,14 +868,46 @@ fn main() -> Result<()> {
                 );
             }
         }
-        Some(Commands::Import { file, auto }) => {
-            run_import_flow(file, auto)?;
+        Some(Commands::Import {
+            file,
+            auto,
+            trim_start,
+            trim_end,
+            auto_trim,
+            report,
+        }) => {
+            run_import_flow(
+                file,
+                auto,
+                TrimOptions {
+                    start: trim_start,
+                    end: trim_end,
+                    auto_detect: auto_trim,
+                },
+                report.as_deref(),
+            )?;
         }
         Some(Commands::Build { command }) => {
             run_build_command(command)?;
         }
         Some(Commands::Benchmark { command }) => {
-            run_benchmark_command(command)?;
+            run_benchmark_command(*command)?;
+        }
+        Some(Commands::Captures { command }) => {
+            run_captures_command(command)?;
+        }
+        Some(Commands::History { command }) => {
+            run_history_command(command)?;
+        }
+        Some(Commands::Regressions {
+            filter,
+            min_change_pct,
+            format,
+        }) => {
+            run_regressions_command(filter.into(), min_change_pct, format)?;
+        }
+        Some(Commands::Compare { a, b, format }) => {
+            run_compare_command(&a, &b, format)?;
         }
         Some(Commands::Config) => {
             show_config_info()?;
@@ -1159,6 +1429,10 @@ fn run_guided_flow() -> Result<()> {
             let confirm = read_line().to_lowercase();
             if confirm == "n" || confirm == "no" {
                 println!("{}", "Cancelled. Your data was not sent.".bright_yellow());
+                record_history(&storage::HistoryEntry::new(
+                    &submission,
+                    storage::HistoryStatus::NotSubmitted,
+                ));
             } else {
                 println!("\n{}", "Submitting...".bright_cyan());
                 match submit_with_offline_fallback(&rt, &submission)? {
@@ -1475,7 +1749,12 @@ fn render_consent_page(pages: &[ConsentPage], page_index: usize) {
 }
 
 /// Import benchmark from external tool (CapFrameX/MangoHud)
-fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
+fn run_import_flow(
+    file: Option<PathBuf>,
+    auto: bool,
+    trim: TrimOptions,
+    report: Option<&Path>,
+) -> Result<()> {
     clear_screen();
     println!(
         "{}",
@@ -1493,6 +1772,13 @@ fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
                 path.display().to_string().bright_cyan()
             );
             path
+        } else if let Some(path) = import::afterburner::find_latest_afterburner_log() {
+            println!(
+                "{} {}",
+                "✓ Found Afterburner log:".bright_green(),
+                path.display().to_string().bright_cyan()
+            );
+            path
         } else if let Some(path) = import::mangohud::find_latest_mangohud_log() {
             println!(
                 "{} {}",
@@ -1507,6 +1793,9 @@ fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
             );
             println!("\n{}", "To enable capture:".bright_yellow());
             println!("  Windows (CapFrameX): Install from capframex.com");
+            println!(
+                "  Windows (Afterburner): Enable \"Log history to file\" with the Frametime graph"
+            );
             println!("  Linux (MangoHud): Set MANGOHUD_LOG=1 before running game");
             return Ok(());
         }
@@ -1522,16 +1811,84 @@ fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
 
     println!("\n{}", "Parsing capture file...".bright_white());
 
-    let capture_format = detect_capture_format(&file_path)?;
-    let frame_data = match capture_format {
+    let frame_data = parse_capture_file(&file_path)?;
+    println!(
+        "{} {}",
+        "Detected format:".bright_green(),
+        frame_data.source.bright_white()
+    );
+
+    let (frame_data, trim_report) = if trim.is_noop() {
+        (frame_data, None)
+    } else {
+        let (trimmed, report) = frame_data.trimmed(&trim)?;
+        if !report.is_empty() {
+            println!("{} {}", "✂".bright_cyan(), report.summary().bright_white());
+        }
+        (trimmed, Some(report))
+    };
+
+    let mut trace = storage::CaptureTrace::from_frame_data(&frame_data, Some(file_path.clone()));
+    trace.trim = trim_report.clone();
+    persist_capture_trace(&trace);
+
+    if let Some(path) = report {
+        if let Some(result) = frame_data.calculate_stats() {
+            let mut capture_report =
+                benchmark::report::CaptureReport::from_frame_data(&frame_data, &result);
+            if let Some(trim) = trim_report.as_ref().filter(|trim| !trim.is_empty()) {
+                capture_report.notes.insert(0, trim.summary());
+            }
+            write_capture_report(capture_report, path);
+        }
+    }
+
+    review_and_submit_frame_data(&frame_data, trim_report.as_ref(), Some(&file_path))
+}
+
+/// Print a non-blocking warning when the resolution differs from the primary display.
+fn print_resolution_warning(submission: &BenchmarkSubmission) {
+    if let Some(warning) = submission.resolution_warning() {
+        println!("{} {}", "⚠".bright_yellow(), warning.bright_yellow());
+    }
+}
+
+/// Use the graphics stack recorded in a MangoHud log header, when there is one,
+/// since it describes the captured session rather than the current one.
+fn apply_capture_system_header(system_info: &mut SystemInfo, source_path: Option<&Path>) {
+    let Some(path) = source_path else {
+        return;
+    };
+    let header = import::mangohud::read_mangohud_system_header(path);
+    if header.is_empty() {
+        return;
+    }
+    system_info.apply_mangohud_header(&header);
+}
+
+/// Parse a capture file in any supported import format.
+fn parse_capture_file(file_path: &Path) -> Result<import::FrameData> {
+    let frame_data = match detect_capture_format(file_path)? {
         CaptureFormat::CapFrameX => {
-            parse_capframex_csv(&file_path).or_else(|_| parse_mangohud_log(&file_path))?
+            parse_capframex_csv(file_path).or_else(|_| parse_mangohud_log(file_path))?
         }
         CaptureFormat::MangoHud => {
-            parse_mangohud_log(&file_path).or_else(|_| parse_capframex_csv(&file_path))?
+            parse_mangohud_log(file_path).or_else(|_| parse_capframex_csv(file_path))?
         }
+        CaptureFormat::Afterburner => parse_afterburner_hml(file_path)?,
+        CaptureFormat::FrameView => parse_frameview_csv(file_path)?,
+        CaptureFormat::Ocat => parse_ocat_csv(file_path)?,
+        CaptureFormat::PresentMon2 => parse_presentmon2_csv(file_path)?,
     };
+    Ok(frame_data)
+}
 
+/// Show stats for parsed frame data, collect the remaining benchmark details and submit.
+fn review_and_submit_frame_data(
+    frame_data: &import::FrameData,
+    trim: Option<&TrimReport>,
+    source_path: Option<&Path>,
+) -> Result<()> {
     let result = frame_data
         .calculate_stats()
         .ok_or_else(|| anyhow::anyhow!("Failed to calculate statistics from capture"))?;
@@ -1549,7 +1906,8 @@ fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
     });
 
     println!("\n{}", "Detecting hardware...".bright_white());
-    let system_info = SystemInfo::detect()?;
+    let mut system_info = SystemInfo::detect()?;
+    apply_capture_system_header(&mut system_info, source_path);
     println!("{}\n", system_info.display());
 
     print!("{} ", "Resolution (1080p/1440p/4K):".bright_yellow());
@@ -1586,7 +1944,7 @@ fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
         resolution,
         preset,
         result.avg_fps,
-        Some(result.fps_1_low),
+        result.fps_1_low,
         ray_tracing,
         upscaling,
     );
@@ -1595,6 +1953,28 @@ fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
     submission.sample_count = Some(result.frame_count as u32);
     submission.benchmark_tool = Some(result.source.clone());
     submission.capture_method = Some("external_tool".to_string());
+    if let Some(trim) = trim.filter(|trim| !trim.is_empty()) {
+        submission.notes = Some(trim.summary());
+    }
+    submission.bottleneck = result.bottleneck.as_ref().map(|b| b.overall.clone());
+    // Imports may come from another session or display, so no refresh pacing.
+    print_resolution_warning(&submission);
+    if let Some(telemetry) = result.telemetry.as_ref() {
+        println!(
+            "\n{}",
+            "Hardware telemetry from the capture log:".bright_white()
+        );
+        print!("{}", telemetry);
+        print!(
+            "{} ",
+            "Include this telemetry summary in your submission? [y/N]:".bright_yellow()
+        );
+        let _ = io::stdout().flush();
+        let include = read_line().to_lowercase();
+        if include == "y" || include == "yes" {
+            submission.telemetry = Some(telemetry.clone());
+        }
+    }
 
     println!("\n{}\n", "Review your submission:".bright_white());
     println!("{}", submission.display());
@@ -1612,6 +1992,10 @@ fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
     let confirm = read_line().to_lowercase();
     if confirm == "n" || confirm == "no" {
         println!("{}", "Cancelled. Your data was not sent.".bright_yellow());
+        record_history(&storage::HistoryEntry::new(
+            &submission,
+            storage::HistoryStatus::NotSubmitted,
+        ));
         return Ok(());
     }
     ensure_submission_consent()?;
@@ -1650,6 +2034,55 @@ fn run_import_flow(file: Option<PathBuf>, auto: bool) -> Result<()> {
     Ok(())
 }
 
+/// Write an HTML report with the detected hardware. Failures are reported but never abort the flow.
+fn write_capture_report(mut report: benchmark::report::CaptureReport, path: &Path) {
+    report.system = SystemInfo::detect().ok();
+    match report.write_html(path) {
+        Ok(()) => println!(
+            "{} {}",
+            "Report written:".bright_green(),
+            path.display().to_string().bright_white()
+        ),
+        Err(err) => println!(
+            "{} {}",
+            "Could not write report:".bright_yellow(),
+            format!("{:#}", err).bright_yellow()
+        ),
+    }
+}
+
+/// Record a result in the local history. Failures are reported but never abort the flow.
+fn record_history(entry: &storage::HistoryEntry) {
+    match storage::init_storage().and_then(|storage| storage.record_history(entry)) {
+        Ok(id) => println!(
+            "{} {}",
+            "Saved to history:".bright_green(),
+            id.bright_white()
+        ),
+        Err(err) => println!(
+            "{} {}",
+            "Could not save history entry:".bright_yellow(),
+            err.to_string().bright_yellow()
+        ),
+    }
+}
+
+/// Save a capture trace locally. Failures are reported but never abort the flow.
+fn persist_capture_trace(trace: &storage::CaptureTrace) {
+    match storage::init_storage().and_then(|storage| storage.save_capture_trace(trace)) {
+        Ok(id) => println!(
+            "{} {}",
+            "Saved capture trace:".bright_green(),
+            id.bright_white()
+        ),
+        Err(err) => println!(
+            "{} {}",
+            "Could not save capture trace:".bright_yellow(),
+            err.to_string().bright_yellow()
+        ),
+    }
+}
+
 fn print_welcome() {
     let banner = r#"
 ╔═══════════════════════════════════════════════════════════════════════════╗
@@ -1762,43 +2195,15 @@ fn print_welcome() {
     );
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum AntiCheatRiskLevel {
-    Low,
-    Medium,
-    High,
-}
-
-impl std::fmt::Display for AntiCheatRiskLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AntiCheatRiskLevel::Low => write!(f, "Low"),
-            AntiCheatRiskLevel::Medium => write!(f, "Medium"),
-            AntiCheatRiskLevel::High => write!(f, "High"),
-        }
-    }
-}
-
-fn anti_cheat_risk_for_game_name(game_name: &str) -> AntiCheatRiskLevel {
-    let Some(game) = GameInfo::find(game_name) else {
-        return AntiCheatRiskLevel::Medium;
-    };
-
-    match game.name {
-        // Games with strict anti-cheat stacks where tooling compatibility changes often.
-        "Valorant" | "League of Legends" => AntiCheatRiskLevel::High,
-        // Popular competitive titles where capture overlays can occasionally conflict.
-        "Counter-Strike 2" | "Fortnite" | "Apex Legends" | "Call of Duty: Warzone" => {
-            AntiCheatRiskLevel::Medium
-        }
-        _ => AntiCheatRiskLevel::Low,
-    }
+fn anti_cheat_risk_for_game_name(game_name: &str) -> AntiCheatRisk {
+    anti_cheat_risk_for_game(GameInfo::find(game_name))
 }
 
-fn anti_cheat_risk_for_game(game_info: Option<&'static GameInfo>) -> AntiCheatRiskLevel {
+fn anti_cheat_risk_for_game(game_info: Option<&'static GameInfo>) -> AntiCheatRisk {
+    // Unknown games get the cautious default.
     game_info
-        .map(|game| anti_cheat_risk_for_game_name(game.name))
-        .unwrap_or(AntiCheatRiskLevel::Medium)
+        .map(|game| game.anti_cheat)
+        .unwrap_or(AntiCheatRisk::Medium)
 }
 
 fn print_anti_cheat_guidance(game_info: Option<&'static GameInfo>) {
@@ -1806,7 +2211,7 @@ fn print_anti_cheat_guidance(game_info: Option<&'static GameInfo>) {
 
     println!("\n{}", "ANTI-CHEAT SAFETY:".bright_yellow().bold());
     match (game_info, risk) {
-        (Some(game), AntiCheatRiskLevel::High) => {
+        (Some(game), AntiCheatRisk::High) => {
             println!(
                 "{}",
                 format!(
@@ -1827,7 +2232,7 @@ fn print_anti_cheat_guidance(game_info: Option<&'static GameInfo>) {
                     .bright_white()
             );
         }
-        (Some(game), AntiCheatRiskLevel::Medium) => {
+        (Some(game), AntiCheatRisk::Medium) => {
             println!(
                 "{}",
                 format!(
@@ -1842,7 +2247,7 @@ fn print_anti_cheat_guidance(game_info: Option<&'static GameInfo>) {
                     .bright_white()
             );
         }
-        (Some(_), AntiCheatRiskLevel::Low) => {
+        (Some(_), AntiCheatRisk::Low) => {
             println!(
                 "{}",
                 "  ✓ Low known anti-cheat risk for external frame capture.".bright_green()
@@ -1889,13 +2294,13 @@ fn guard_live_capture_safety(game_name: Option<&str>, allow_anti_cheat_risk: boo
     };
 
     match anti_cheat_risk_for_game_name(name) {
-        AntiCheatRiskLevel::High if !allow_anti_cheat_risk => {
+        AntiCheatRisk::High if !allow_anti_cheat_risk => {
             anyhow::bail!(
                 "Live capture is blocked for '{}' due to high anti-cheat risk.\nUse in-game FPS counters/manual mode instead.\nIf you accept risk, re-run with --allow-anti-cheat-risk.",
                 name
             );
         }
-        AntiCheatRiskLevel::High => {
+        AntiCheatRisk::High => {
             println!(
                 "{}",
                 format!(
@@ -1906,7 +2311,7 @@ fn guard_live_capture_safety(game_name: Option<&str>, allow_anti_cheat_risk: boo
                 .bold()
             );
         }
-        AntiCheatRiskLevel::Medium => {
+        AntiCheatRisk::Medium => {
             println!(
                 "{}",
                 format!(
@@ -1920,7 +2325,7 @@ fn guard_live_capture_safety(game_name: Option<&str>, allow_anti_cheat_risk: boo
                 "Prefer built-in game counters/benchmarks if available.".bright_white()
             );
         }
-        AntiCheatRiskLevel::Low => {
+        AntiCheatRisk::Low => {
             println!(
                 "{}",
                 format!("Anti-cheat risk check for '{}': low.", name).bright_green()
@@ -1933,8 +2338,8 @@ fn guard_live_capture_safety(game_name: Option<&str>, allow_anti_cheat_risk: boo
 
 fn prompt_anti_cheat_capture_consent(game_info: Option<&'static GameInfo>) -> bool {
     match anti_cheat_risk_for_game(game_info) {
-        AntiCheatRiskLevel::Low => true,
-        AntiCheatRiskLevel::Medium => {
+        AntiCheatRisk::Low => true,
+        AntiCheatRisk::Medium => {
             println!("\n{}", "ANTI-CHEAT CONSENT CHECK".bright_yellow().bold());
             println!(
                 "{}",
@@ -1954,7 +2359,7 @@ fn prompt_anti_cheat_capture_consent(game_info: Option<&'static GameInfo>) -> bo
             let input = read_line();
             input.eq_ignore_ascii_case("OK")
         }
-        AntiCheatRiskLevel::High => {
+        AntiCheatRisk::High => {
             println!("\n{}", "STRICT ANTI-CHEAT CONSENT".bright_red().bold());
             if let Some(game) = game_info {
                 println!(
@@ -2017,12 +2422,18 @@ fn print_benchmark_guidance(game_info: Option<&'static GameInfo>) {
         if game.supports_rt {
             features.push("Ray Tracing");
         }
-        if game.supports_dlss {
+        if game.supports(Upscaler::Dlss) {
             features.push("DLSS (NVIDIA cards)");
         }
-        if game.supports_fsr {
+        if game.supports(Upscaler::Fsr) {
             features.push("FSR (AMD/Intel cards)");
         }
+        if game.supports(Upscaler::Xess) {
+            features.push("XeSS (Intel/any GPU)");
+        }
+        if !game.frame_generation.is_empty() {
+            features.push("Frame Generation");
+        }
 
         if !features.is_empty() {
             println!(
@@ -2132,13 +2543,7 @@ fn prompt_ray_tracing(game_info: Option<&'static GameInfo>) -> bool {
 
 fn prompt_upscaling_mode(game_info: Option<&'static GameInfo>) -> Option<String> {
     if let Some(game) = game_info {
-        let mut supported = Vec::new();
-        if game.supports_dlss {
-            supported.push("DLSS");
-        }
-        if game.supports_fsr {
-            supported.push("FSR");
-        }
+        let supported: Vec<String> = game.upscalers.iter().map(|u| u.to_string()).collect();
 
         if supported.is_empty() {
             println!(
@@ -2179,10 +2584,10 @@ fn prompt_upscaling_mode(game_info: Option<&'static GameInfo>) -> Option<String>
 }
 
 fn anti_cheat_list_tag(game: &GameInfo) -> colored::ColoredString {
-    match anti_cheat_risk_for_game_name(game.name) {
-        AntiCheatRiskLevel::High => "[Strict AC]".bright_red(),
-        AntiCheatRiskLevel::Medium => "[AC Caution]".bright_yellow(),
-        AntiCheatRiskLevel::Low => "".normal(),
+    match game.anti_cheat {
+        AntiCheatRisk::High => "[Strict AC]".bright_red(),
+        AntiCheatRisk::Medium => "[AC Caution]".bright_yellow(),
+        AntiCheatRisk::Low => "".normal(),
     }
 }
 
@@ -2195,7 +2600,7 @@ fn print_games_list() {
     );
 
     println!("{}", "EXTREME (Most demanding):".bright_red().bold());
-    for (i, game) in KNOWN_GAMES.iter().enumerate() {
+    for (i, game) in known_games().iter().enumerate() {
         if game.difficulty == games::GameDifficulty::Extreme {
             let bench = if game.has_benchmark {
                 "[Benchmark]".bright_green()
@@ -2214,7 +2619,7 @@ fn print_games_list() {
     }
 
     println!("\n{}", "HEAVY:".bright_magenta().bold());
-    for (i, game) in KNOWN_GAMES.iter().enumerate() {
+    for (i, game) in known_games().iter().enumerate() {
         if game.difficulty == games::GameDifficulty::Heavy {
             let bench = if game.has_benchmark {
                 "[Benchmark]".bright_green()
@@ -2233,7 +2638,7 @@ fn print_games_list() {
     }
 
     println!("\n{}", "MEDIUM:".bright_yellow().bold());
-    for (i, game) in KNOWN_GAMES.iter().enumerate() {
+    for (i, game) in known_games().iter().enumerate() {
         if game.difficulty == games::GameDifficulty::Medium {
             let bench = if game.has_benchmark {
                 "[Benchmark]".bright_green()
@@ -2252,7 +2657,7 @@ fn print_games_list() {
     }
 
     println!("\n{}", "LIGHT:".bright_green().bold());
-    for (i, game) in KNOWN_GAMES.iter().enumerate() {
+    for (i, game) in known_games().iter().enumerate() {
         if game.difficulty == games::GameDifficulty::Light {
             let bench = if game.has_benchmark {
                 "[Benchmark]".bright_green()
@@ -2290,6 +2695,27 @@ fn print_games_list() {
         "{}",
         "Tags: [Strict AC] = high anti-cheat risk, [AC Caution] = medium risk.".bright_cyan()
     );
+    if let Ok(path) = games::GameCatalogue::overrides_path() {
+        println!(
+            "{} {}",
+            "Add or override games in:".bright_cyan(),
+            path.display().to_string().bright_white()
+        );
+    }
+}
+
+/// Load the games catalogue with the user's overrides once, before any UI
+/// starts, so problems are reported on a plain terminal
+fn load_games_catalogue() {
+    let warn = |message: &str| eprintln!("{} {}", "⚠".bright_yellow(), message.bright_yellow());
+    let catalogue = games::GameCatalogue::load().unwrap_or_else(|err| {
+        warn(&format!("{err:#}. Using the bundled games list."));
+        games::GameCatalogue::bundled().unwrap_or_default()
+    });
+    for warning in catalogue.warnings() {
+        warn(warning);
+    }
+    games::set_catalogue(catalogue);
 }
 
 fn print_game_details(game: &'static GameInfo) {
@@ -2312,10 +2738,10 @@ fn print_game_details(game: &'static GameInfo) {
     println!(
         "{} {}",
         "Anti-Cheat Capture Risk:".bright_yellow(),
-        match anti_cheat_risk_for_game_name(game.name) {
-            AntiCheatRiskLevel::High => "High".bright_red(),
-            AntiCheatRiskLevel::Medium => "Medium".bright_yellow(),
-            AntiCheatRiskLevel::Low => "Low".bright_green(),
+        match game.anti_cheat {
+            AntiCheatRisk::High => "High".bright_red(),
+            AntiCheatRisk::Medium => "Medium".bright_yellow(),
+            AntiCheatRisk::Low => "Low".bright_green(),
         }
     );
 
@@ -2334,7 +2760,7 @@ fn print_game_details(game: &'static GameInfo) {
         "  {} {}: {}",
         "•".bright_cyan(),
         "DLSS".bright_white(),
-        if game.supports_dlss {
+        if game.supports(Upscaler::Dlss) {
             "Yes".bright_green()
         } else {
             "No".bright_red()
@@ -2344,12 +2770,37 @@ fn print_game_details(game: &'static GameInfo) {
         "  {} {}: {}",
         "•".bright_cyan(),
         "FSR".bright_white(),
-        if game.supports_fsr {
+        if game.supports(Upscaler::Fsr) {
+            "Yes".bright_green()
+        } else {
+            "No".bright_red()
+        }
+    );
+    println!(
+        "  {} {}: {}",
+        "•".bright_cyan(),
+        "XeSS".bright_white(),
+        if game.supports(Upscaler::Xess) {
             "Yes".bright_green()
         } else {
             "No".bright_red()
         }
     );
+    println!(
+        "  {} {}: {}",
+        "•".bright_cyan(),
+        "Frame Generation".bright_white(),
+        if game.frame_generation.is_empty() {
+            "No".bright_red()
+        } else {
+            game.frame_generation
+                .iter()
+                .map(|u| u.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+                .bright_green()
+        }
+    );
 
     println!("\n{}", "Benchmark Notes:".bright_yellow().bold());
     println!("  {}", game.benchmark_notes.bright_white());
@@ -2370,8 +2821,8 @@ fn parse_game_input(input: &str) -> (String, Option<&'static GameInfo>) {
 
     // Try parsing as number
     if let Ok(num) = trimmed.parse::<usize>() {
-        if num > 0 && num <= KNOWN_GAMES.len() {
-            let game = &KNOWN_GAMES[num - 1];
+        if num > 0 && num <= known_games().len() {
+            let game = &known_games()[num - 1];
             return (game.name.to_string(), Some(game));
         }
     }
@@ -2415,11 +2866,11 @@ fn parse_game_batch_input(input: &str) -> (Vec<(String, Option<&'static GameInfo
 
     for part in parts {
         if let Ok(num) = part.parse::<usize>() {
-            if num == 0 || num > KNOWN_GAMES.len() {
+            if num == 0 || num > known_games().len() {
                 warnings.push(format!(
                     "'{}' is out of range (valid game numbers are 1-{}).",
                     part,
-                    KNOWN_GAMES.len()
+                    known_games().len()
                 ));
                 continue;
             }
@@ -2478,6 +2929,10 @@ fn print_submission_receipt(response: &benchmark::SubmissionResponse) {
 enum CaptureFormat {
     CapFrameX,
     MangoHud,
+    Afterburner,
+    FrameView,
+    Ocat,
+    PresentMon2,
 }
 
 fn submit_with_offline_fallback(
@@ -2489,17 +2944,34 @@ fn submit_with_offline_fallback(
         submission,
         &idempotency_key,
     )) {
-        Ok(response) => Ok(SubmissionOutcome::Uploaded(response)),
+        Ok(response) => {
+            let mut entry = storage::HistoryEntry::new(
+                submission,
+                storage::HistoryStatus::from_response(&response),
+            );
+            entry.receipt_id = Some(response.id.clone()).filter(|id| !id.trim().is_empty());
+            entry.idempotency_key = Some(idempotency_key);
+            record_history(&entry);
+            Ok(SubmissionOutcome::Uploaded(response))
+        }
         Err(err) => {
             if api::should_queue_offline(&err) {
                 let storage = storage::init_storage()?;
                 let pending_id = storage
                     .save_pending_benchmark_with_idempotency_key(submission, &idempotency_key)?;
                 let _ = pending_id;
+                let mut entry =
+                    storage::HistoryEntry::new(submission, storage::HistoryStatus::Queued);
+                entry.idempotency_key = Some(idempotency_key);
+                record_history(&entry);
                 Ok(SubmissionOutcome::SavedOffline {
                     reason: err.to_string(),
                 })
             } else {
+                record_history(&storage::HistoryEntry::new(
+                    submission,
+                    storage::HistoryStatus::NotSubmitted,
+                ));
                 Err(anyhow::anyhow!(err.to_string()))
             }
         }
@@ -2575,7 +3047,12 @@ fn sync_pending_benchmarks(rt: &tokio::runtime::Runtime) {
             &pending_record.submission,
             &pending_record.idempotency_key,
         )) {
-            Ok(_) => {
+            Ok(response) => {
+                let _ = storage.update_history_status(
+                    &pending_record.idempotency_key,
+                    storage::HistoryStatus::from_response(&response),
+                    Some(response.id.clone()).filter(|id| !id.trim().is_empty()),
+                );
                 let finalized = storage
                     .mark_pending_benchmark_uploaded(&pending_record.id)
                     .or_else(|_| storage.remove_pending_benchmark(&pending_record.id));
@@ -2596,6 +3073,11 @@ fn sync_pending_benchmarks(rt: &tokio::runtime::Runtime) {
                     continue;
                 }
 
+                let _ = storage.update_history_status(
+                    &pending_record.idempotency_key,
+                    storage::HistoryStatus::Rejected,
+                    None,
+                );
                 match storage.remove_pending_benchmark(&pending_record.id) {
                     Ok(_) => {
                         dropped_permanent += 1;
@@ -2773,6 +3255,15 @@ fn detect_capture_format(path: &Path) -> Result<CaptureFormat> {
     if file_name.contains("mangohud") {
         return Ok(CaptureFormat::MangoHud);
     }
+    if file_name.ends_with(".hml") {
+        return Ok(CaptureFormat::Afterburner);
+    }
+    if file_name.starts_with("frameview") {
+        return Ok(CaptureFormat::FrameView);
+    }
+    if import::ocat::looks_like_ocat_file_name(&file_name) {
+        return Ok(CaptureFormat::Ocat);
+    }
 
     let file = File::open(path)?;
     let reader = BufReader::new(file);
@@ -2784,6 +3275,20 @@ fn detect_capture_format(path: &Path) -> Result<CaptureFormat> {
             continue;
         }
 
+        // FrameView, OCAT and PresentMon 2.x headers also match the generic
+        // CapFrameX/MangoHud checks, so test the specific schemas first.
+        if import::afterburner::looks_like_afterburner_line(trimmed) {
+            return Ok(CaptureFormat::Afterburner);
+        }
+        if import::frameview::looks_like_frameview_header(trimmed) {
+            return Ok(CaptureFormat::FrameView);
+        }
+        if import::ocat::looks_like_ocat_header(trimmed) {
+            return Ok(CaptureFormat::Ocat);
+        }
+        if import::presentmon::looks_like_presentmon2_header(trimmed) {
+            return Ok(CaptureFormat::PresentMon2);
+        }
         if looks_like_capframex_line(trimmed) {
             return Ok(CaptureFormat::CapFrameX);
         }
@@ -2796,7 +3301,7 @@ fn detect_capture_format(path: &Path) -> Result<CaptureFormat> {
         Some(ext) if ext.eq_ignore_ascii_case("log") => Ok(CaptureFormat::MangoHud),
         Some(ext) if ext.eq_ignore_ascii_case("csv") => Ok(CaptureFormat::CapFrameX),
         _ => anyhow::bail!(
-            "Could not detect capture format for {}. Use a CapFrameX CSV or MangoHud log.",
+            "Could not detect capture format for {}. Use a CapFrameX, FrameView, OCAT or PresentMon CSV, an Afterburner HML or a MangoHud log.",
             path.display()
         ),
     }
@@ -2953,6 +3458,7 @@ fn capture_source_arg(source: CaptureSource) -> &'static str {
         CaptureSource::Auto => "auto",
         CaptureSource::MangoHud => "mangohud",
         CaptureSource::PresentMon => "presentmon",
+        CaptureSource::Replay => "replay",
     }
 }
 
@@ -2960,7 +3466,7 @@ fn capture_source_arg(source: CaptureSource) -> &'static str {
 fn source_requires_presentmon(source: CaptureSource, has_mangohud_fallback: bool) -> bool {
     match source {
         CaptureSource::PresentMon => true,
-        CaptureSource::MangoHud => false,
+        CaptureSource::MangoHud | CaptureSource::Replay => false,
         CaptureSource::Auto => !has_mangohud_fallback,
     }
 }
@@ -2974,21 +3480,21 @@ fn is_existing_mangohud_capture_file(path: &Path) -> bool {
 struct ProcessNameHint {
     game_name: &'static str,
     primary: &'static str,
-    alternatives: &'static [&'static str],
+    alternatives: &'static [String],
 }
 
 fn process_name_hint_for_game(game: Option<&str>) -> Option<ProcessNameHint> {
     let game_name = game?;
     let info = GameInfo::find(game_name)?;
     let suggestions = info.process_name_suggestions();
-    let primary = suggestions.first().copied()?;
+    let primary = suggestions.first()?.as_str();
     let alternatives = if suggestions.len() > 1 {
         &suggestions[1..]
     } else {
         &[]
     };
     Some(ProcessNameHint {
-        game_name: info.name,
+        game_name: &info.name,
         primary,
         alternatives,
     })
@@ -3068,19 +3574,52 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
             poll_ms,
             max_frame_time_ms,
             strict_unfocus_grace_ms,
+            passes,
+            pass_cooldown,
+            wait_for_process,
+            wait_timeout,
+            warmup,
+            replay_speed,
+            trim_start,
+            trim_end,
+            auto_trim,
+            include_telemetry,
+            report,
             submit,
             resolution,
             preset,
             ray_tracing,
             upscaling,
         } => {
+            validate_wait_options(wait_timeout, warmup)?;
+            let wait_target = match wait_for_process.as_deref() {
+                Some(name) => Some(WaitTarget::resolve(name, cli_process_name.as_deref())?),
+                None => None,
+            };
+            let game = game.or_else(|| {
+                wait_target
+                    .as_ref()
+                    .and_then(|target| target.game_name)
+                    .map(str::to_string)
+            });
+
             guard_live_capture_safety(game.as_deref(), allow_anti_cheat_risk)?;
+            let pass_options = MultiPassOptions {
+                passes,
+                cooldown_secs: pass_cooldown,
+            };
+            validate_multi_pass_options(&pass_options)?;
+            if matches!(source, BenchmarkSourceArg::Replay) && submit {
+                anyhow::bail!(
+                    "Replay captures are for testing and cannot be submitted. Use `fps-tracker import --file <log>` to submit a recorded capture."
+                );
+            }
 
             #[cfg(target_os = "windows")]
             {
                 let will_use_presentmon = match source {
                     BenchmarkSourceArg::Presentmon => true,
-                    BenchmarkSourceArg::Mangohud => false,
+                    BenchmarkSourceArg::Mangohud | BenchmarkSourceArg::Replay => false,
                     BenchmarkSourceArg::Auto => match file.as_deref() {
                         Some(path) => {
                             !crate::import::mangohud::looks_like_mangohud_capture_file(path)
@@ -3124,7 +3663,7 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
             });
             let effective_pause_on_unfocus =
                 pause_on_unfocus.unwrap_or(cfg.capture.pause_on_unfocus);
-            let mut effective_process_validation =
+            let effective_process_validation =
                 process_validation.unwrap_or(cfg.capture.process_validation);
             let effective_poll_ms = poll_ms.unwrap_or(cfg.capture.default_poll_ms);
             let effective_max_frame_time_ms =
@@ -3151,16 +3690,7 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
                 })
             });
 
-            if effective_process_validation && resolved_process_name.is_none() {
-                println!(
-                    "{}",
-                    "Process validation is enabled but no target process was resolved; proceeding with validation disabled for this run."
-                        .bright_yellow()
-                );
-                effective_process_validation = false;
-            }
-
-            let options = LiveCaptureOptions {
+            let mut options = LiveCaptureOptions {
                 source: source.into(),
                 duration_secs: duration,
                 file,
@@ -3172,6 +3702,12 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
                 process_validation: effective_process_validation,
                 max_frame_time_ms: effective_max_frame_time_ms,
                 strict_unfocus_grace_ms: effective_strict_unfocus_grace_ms,
+                replay_speed,
+                trim: TrimOptions {
+                    start: trim_start,
+                    end: trim_end,
+                    auto_detect: auto_trim,
+                },
             };
 
             #[cfg(target_os = "windows")]
@@ -3348,6 +3884,23 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
                 "Tip: keep your game in the target benchmark scene while capture runs."
                     .bright_white()
             );
+
+            if let Some(target) = wait_target.as_ref() {
+                let matched = wait_for_target_process(target, wait_timeout)?;
+                if options.process_name.is_none() {
+                    options.process_name = Some(matched);
+                }
+            }
+
+            // Only decide once the wait has had a chance to name the process.
+            if options.process_validation && options.process_name.is_none() {
+                println!(
+                    "{}",
+                    "Process validation is enabled but no target process was resolved; proceeding with validation disabled for this run."
+                        .bright_yellow()
+                );
+                options.process_validation = false;
+            }
             println!(
                 "{} {} | {} {} | {} {}ms | {} {}ms",
                 "Focus policy:".bright_cyan(),
@@ -3363,20 +3916,60 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
                 "Strict grace:".bright_cyan(),
                 options.strict_unfocus_grace_ms
             );
+            run_warmup(warmup);
 
-            let result = match run_live_capture(&options) {
-                Ok(value) => value,
-                Err(err) => {
-                    print_capture_retry_guidance(&err, &options);
-                    return Err(err);
+            let (result, pass_summary) = if pass_options.passes > 1 {
+                println!(
+                    "{} {} | {} {}s",
+                    "Passes:".bright_cyan(),
+                    pass_options.passes,
+                    "Cooldown:".bright_cyan(),
+                    pass_options.cooldown_secs
+                );
+                match run_multi_pass_capture(&options, &pass_options) {
+                    Ok(summary) => (summary.aggregate.clone(), Some(summary)),
+                    Err(err) => {
+                        print_capture_retry_guidance(&err, &options);
+                        return Err(err);
+                    }
+                }
+            } else {
+                match run_live_capture(&options) {
+                    Ok(value) => (value, None),
+                    Err(err) => {
+                        print_capture_retry_guidance(&err, &options);
+                        return Err(err);
+                    }
                 }
             };
-            println!("\n{}", result);
-
-            if game.is_none() {
+            if let Some(summary) = pass_summary.as_ref() {
+                for (idx, pass) in summary.passes.iter().enumerate() {
+                    println!("\n{} {}\n{}", "Pass".bright_cyan(), idx + 1, pass);
+                    persist_capture_trace(&storage::CaptureTrace::from_live_result(pass));
+                }
+                println!("{}", summary);
+                if !summary.outliers.is_empty() {
+                    println!(
+                        "{}",
+                        "⚠ Outlier passes were excluded from the aggregate. Check for background load or scene changes."
+                            .bright_yellow()
+                    );
+                }
+            } else {
+                println!("\n{}", result);
+                persist_capture_trace(&storage::CaptureTrace::from_live_result(&result));
+            }
+            if let Some(path) = report.as_deref() {
+                write_capture_report(
+                    benchmark::report::CaptureReport::from_live_result(&result),
+                    path,
+                );
+            }
+
+            if game.is_none() {
                 if let Some(detected_game) = result.game_hint.as_deref() {
                     match anti_cheat_risk_for_game_name(detected_game) {
-                        AntiCheatRiskLevel::High => {
+                        AntiCheatRisk::High => {
                             println!(
                                 "{}",
                                 format!(
@@ -3392,7 +3985,7 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
                                     .bright_white()
                             );
                         }
-                        AntiCheatRiskLevel::Medium => {
+                        AntiCheatRisk::Medium => {
                             println!(
                                 "{}",
                                 format!(
@@ -3402,7 +3995,7 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
                                 .bright_yellow()
                             );
                         }
-                        AntiCheatRiskLevel::Low => {}
+                        AntiCheatRisk::Low => {}
                     }
                 } else {
                     println!(
@@ -3413,85 +4006,502 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
                 }
             }
 
+            let game_name = game.or_else(|| result.game_hint.clone());
             if submit {
                 ensure_submission_consent()?;
-                let game_name = if let Some(name) = game.or_else(|| result.game_hint.clone()) {
-                    name
-                } else {
+                if game_name.is_none() {
                     println!(
                         "{}",
                         "Game name is required for submission. Re-run with --game \"<name>\"."
                             .bright_red()
                     );
                     return Ok(());
-                };
+                }
 
-                let resolution = if let Some(value) = resolution {
-                    value
-                } else {
+                if resolution.is_none() {
                     println!(
                         "{}",
                         "Resolution is required for submission. Re-run with --resolution."
                             .bright_red()
                     );
                     return Ok(());
-                };
+                }
 
-                let preset = if let Some(value) = preset {
-                    value
-                } else {
+                if preset.is_none() {
                     println!(
                         "{}",
                         "Preset is required for submission. Re-run with --preset.".bright_red()
                     );
                     return Ok(());
-                };
+                }
+            }
 
-                let mut submission = BenchmarkSubmission::new(
-                    SystemInfo::detect()?,
-                    game_name,
-                    resolution,
-                    preset,
-                    result.avg_fps,
-                    Some(result.fps_1_low),
-                    ray_tracing,
-                    upscaling,
-                );
-                submission.fps_01_low = result.fps_01_low;
-                submission.duration_secs = Some(result.duration_secs);
-                submission.sample_count = Some(result.frame_count as u32);
-                submission.benchmark_tool = Some(result.source.clone());
-                submission.capture_quality_score = Some(result.capture_quality_score);
-                submission.unstable_capture = Some(result.unstable_capture);
-                submission.capture_method = Some("external_tool".to_string());
-                submission.notes = Some(format!(
-                    "Live capture preview: {} (started {})",
-                    result.source,
-                    result.started_at.to_rfc3339()
-                ));
+            // Unsubmitted previews only go to history when they name a game and resolution;
+            // without one there is nothing to record and no reason to probe the hardware.
+            if !submit && (game_name.is_none() || resolution.is_none()) {
+                return Ok(());
+            }
 
-                let rt = tokio::runtime::Runtime::new()?;
-                sync_pending_uploads(&rt);
-                println!("\n{}", "Submitting captured benchmark...".bright_cyan());
-                match submit_with_offline_fallback(&rt, &submission)? {
-                    SubmissionOutcome::Uploaded(response) => {
-                        print_submission_receipt(&response);
+            // Hardware detection is best-effort for history-only previews.
+            let mut system_info = match SystemInfo::detect() {
+                Ok(info) => info,
+                Err(err) if submit => return Err(err),
+                Err(_) => return Ok(()),
+            };
+            apply_capture_system_header(&mut system_info, result.capture_path.as_deref());
+
+            let mut submission = BenchmarkSubmission::new(
+                system_info,
+                game_name.unwrap_or_else(|| "Unknown".to_string()),
+                resolution.unwrap_or_else(|| "Unknown".to_string()),
+                preset.unwrap_or_else(|| "Unknown".to_string()),
+                result.avg_fps,
+                Some(result.fps_1_low),
+                ray_tracing,
+                upscaling,
+            );
+            submission.fps_01_low = result.fps_01_low;
+            submission.duration_secs = Some(result.duration_secs);
+            submission.sample_count = Some(result.frame_count as u32);
+            submission.benchmark_tool = Some(result.source.clone());
+            submission.capture_quality_score = Some(result.capture_quality_score);
+            submission.unstable_capture = Some(result.unstable_capture);
+            submission.capture_method = Some("external_tool".to_string());
+            if include_telemetry {
+                submission.telemetry = result.telemetry.clone();
+            }
+            submission.bottleneck = result.bottleneck.as_ref().map(|b| b.overall.clone());
+            submission.power = result.power.clone();
+            submission.set_refresh_pacing(&result.frame_times_ms);
+            print_resolution_warning(&submission);
+            let mut notes = format!(
+                "Live capture preview: {} (started {})",
+                result.source,
+                result.started_at.to_rfc3339()
+            );
+            if let Some(summary) = pass_summary.as_ref() {
+                notes.push_str(&format!(" | {}", summary.submission_note()));
+                for (idx, pass) in summary.passes.iter().enumerate() {
+                    if let Some(trim) = pass.trim.as_ref().filter(|trim| !trim.is_empty()) {
+                        notes.push_str(&format!(" | pass {}: {}", idx + 1, trim.summary()));
                     }
-                    SubmissionOutcome::SavedOffline { reason } => {
-                        println!(
-                            "{} {}",
-                            "⚠ Could not submit right now:".bright_yellow(),
-                            reason.bright_red()
-                        );
-                        println!("{}", "Saved locally for automatic retry.".bright_yellow());
-                        let _ = feedback::cli::offer_feedback_prompt(
+                }
+            } else if let Some(trim) = result.trim.as_ref().filter(|trim| !trim.is_empty()) {
+                notes.push_str(&format!(" | {}", trim.summary()));
+            }
+            submission.notes = Some(notes);
+
+            if !submit {
+                record_history(&storage::HistoryEntry::new(
+                    &submission,
+                    storage::HistoryStatus::NotSubmitted,
+                ));
+                return Ok(());
+            }
+
+            let rt = tokio::runtime::Runtime::new()?;
+            sync_pending_uploads(&rt);
+            println!("\n{}", "Submitting captured benchmark...".bright_cyan());
+            match submit_with_offline_fallback(&rt, &submission)? {
+                SubmissionOutcome::Uploaded(response) => {
+                    print_submission_receipt(&response);
+                }
+                SubmissionOutcome::SavedOffline { reason } => {
+                    println!(
+                        "{} {}",
+                        "⚠ Could not submit right now:".bright_yellow(),
+                        reason.bright_red()
+                    );
+                    println!("{}", "Saved locally for automatic retry.".bright_yellow());
+                    let _ = feedback::cli::offer_feedback_prompt(
                             &rt,
                             FeedbackCategory::SubmissionSync,
                             "upload_failed",
                             "Auto-submit from capture preview failed and was queued locally.\nIf possible include:\n- OS\n- Game\n- Capture tool you used\n- Any error message shown\n",
                         );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the compare command
+fn run_compare_command(a: &str, b: &str, format: OutputFormat) -> Result<()> {
+    let capture_a = load_comparison_capture(a)?;
+    let capture_b = load_comparison_capture(b)?;
+    let comparison = benchmark::compare::compare_captures(a, &capture_a, b, &capture_b)?;
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&comparison)?),
+        OutputFormat::Text => print_capture_comparison(&comparison),
+    }
+    Ok(())
+}
+
+/// Load a capture for `compare` from a file path or a saved capture id.
+fn load_comparison_capture(arg: &str) -> Result<import::FrameData> {
+    let path = Path::new(arg);
+    if path.is_file() {
+        return parse_capture_file(path);
+    }
+
+    let trace = storage::init_storage()?
+        .load_capture_trace(arg)
+        .with_context(|| format!("'{}' is neither a capture file nor a saved capture id", arg))?;
+    Ok(trace.to_frame_data())
+}
+
+fn print_capture_comparison(comparison: &benchmark::compare::CaptureComparison) {
+    println!("\n{}", "CAPTURE COMPARISON".bright_cyan().bold());
+    println!("{}", "=".repeat(70).bright_cyan());
+    for (tag, capture) in [("A", &comparison.a), ("B", &comparison.b)] {
+        println!(
+            "{} {} {}",
+            format!("{}:", tag).bright_green(),
+            capture.label.bright_white(),
+            format!(
+                "({}, {} frames, {:.1}s)",
+                capture.source, capture.frame_count, capture.duration_secs
+            )
+            .bright_black()
+        );
+    }
+
+    println!(
+        "\n  {:<22} {:>10} {:>10} {:>10} {:>8}",
+        "Metric".bright_white().bold(),
+        "A",
+        "B",
+        "Delta",
+        "%"
+    );
+    for metric in &comparison.metrics {
+        let delta = format!(
+            "{:>+10.2} {:>8}",
+            metric.delta,
+            metric
+                .delta_pct
+                .map(|pct| format!("{:+.1}%", pct))
+                .unwrap_or_else(|| "n/a".to_string())
+        );
+        let delta = if metric.delta == 0.0 {
+            delta.bright_white()
+        } else if metric.improved() {
+            delta.bright_green()
+        } else {
+            delta.bright_red()
+        };
+        println!(
+            "  {:<22} {:>10.2} {:>10.2} {} {}",
+            metric.metric,
+            metric.a,
+            metric.b,
+            delta,
+            metric.unit.bright_black()
+        );
+    }
+
+    println!();
+    if let Some(test) = &comparison.mann_whitney {
+        let p_value = if test.p_value < 0.0001 {
+            "< 0.0001".to_string()
+        } else {
+            format!("{:.4}", test.p_value)
+        };
+        println!(
+            "{} p = {} {}",
+            "Mann-Whitney U:".bright_green(),
+            p_value.bright_white(),
+            format!(
+                "(a B frame is slower than an A frame in {:.1}% of pairs)",
+                test.prob_b_slower * 100.0
+            )
+            .bright_black()
+        );
+    }
+    if let Some(ci) = &comparison.avg_fps_delta_ci {
+        println!(
+            "{} {} .. {} FPS",
+            format!("Avg FPS delta {:.0}% CI:", ci.confidence * 100.0).bright_green(),
+            format!("{:+.2}", ci.low).bright_white(),
+            format!("{:+.2}", ci.high).bright_white()
+        );
+    }
+    if comparison.significant {
+        println!(
+            "{}",
+            "Verdict: B differs significantly from A."
+                .bright_cyan()
+                .bold()
+        );
+    } else {
+        println!(
+            "{}",
+            "Verdict: no significant difference (within run-to-run noise).".bright_yellow()
+        );
+    }
+}
+
+/// Run captures subcommands
+fn run_captures_command(command: CapturesCommands) -> Result<()> {
+    let storage = storage::init_storage()?;
+
+    match command {
+        CapturesCommands::List => {
+            let traces = storage.list_capture_traces()?;
+
+            if traces.is_empty() {
+                println!("{}", "No saved captures found.".bright_yellow());
+                println!(
+                    "{} Captures are saved automatically by 'fps-tracker import' and 'fps-tracker benchmark preview'.",
+                    "Tip:".bright_cyan()
+                );
+            } else {
+                println!("{}", "Saved Captures:\n".bright_cyan().bold());
+                for trace in &traces {
+                    println!(
+                        "  {} {} | {} | {} | {} samples | {:.1}s | {}",
+                        trace.id.bright_white(),
+                        format!("[{}]", trace.kind).bright_black(),
+                        trace
+                            .saved_at
+                            .format("%Y-%m-%d %H:%M")
+                            .to_string()
+                            .bright_white(),
+                        trace
+                            .game
+                            .as_deref()
+                            .unwrap_or("unknown game")
+                            .bright_cyan(),
+                        trace.frame_count,
+                        trace.duration_secs,
+                        trace.source
+                    );
+                }
+                println!(
+                    "\n{} Use 'fps-tracker captures show <id>' to re-analyse a capture.",
+                    "Tip:".bright_cyan()
+                );
+            }
+        }
+
+        CapturesCommands::Show { id } => {
+            let trace = storage.load_capture_trace(&id)?;
+
+            println!("{} {}", "Capture:".bright_green(), trace.id.bright_white());
+            println!(
+                "{} {}",
+                "Kind:".bright_green(),
+                trace.kind.to_string().bright_white()
+            );
+            println!(
+                "{} {}",
+                "Game:".bright_green(),
+                trace.game.as_deref().unwrap_or("unknown").bright_white()
+            );
+            println!(
+                "{} {}",
+                "Source:".bright_green(),
+                trace.source.bright_white()
+            );
+            println!(
+                "{} {}",
+                "Saved:".bright_green(),
+                trace.saved_at.to_rfc3339().bright_white()
+            );
+            if let (Some(started), Some(ended)) = (trace.started_at, trace.ended_at) {
+                println!(
+                    "{} {} → {}",
+                    "Captured:".bright_green(),
+                    started.to_rfc3339().bright_white(),
+                    ended.to_rfc3339().bright_white()
+                );
+            }
+            if let Some(process) = trace.target_process.as_deref() {
+                println!(
+                    "{} {}",
+                    "Target process:".bright_green(),
+                    process.bright_white()
+                );
+            }
+            if let Some(path) = trace.source_path.as_deref() {
+                println!(
+                    "{} {}",
+                    "Original file:".bright_green(),
+                    path.display().to_string().bright_white()
+                );
+            }
+            if let Some(diag) = trace.diagnostics.as_ref() {
+                println!(
+                    "{} {} | {} {} | {} {} ({:.2}%) | {} {}",
+                    "Quality:".bright_green(),
+                    diag.capture_quality_score,
+                    "Focus pauses:".bright_green(),
+                    diag.focus_pauses,
+                    "Stutter spikes:".bright_green(),
+                    diag.stutter_spike_count,
+                    diag.stutter_spike_ratio * 100.0,
+                    "Unstable:".bright_green(),
+                    if diag.unstable_capture { "yes" } else { "no" }
+                );
+            }
+
+            match trace.to_frame_data().calculate_stats() {
+                Some(result) => println!("\n{}", result),
+                None => println!(
+                    "{}",
+                    "Not enough frame samples to compute statistics.".bright_yellow()
+                ),
+            }
+        }
+
+        CapturesCommands::Submit { id } => {
+            let trace = storage.load_capture_trace(&id)?;
+            println!(
+                "{} {}",
+                "Re-submitting capture:".bright_cyan(),
+                trace.id.bright_white()
+            );
+            review_and_submit_frame_data(
+                &trace.to_frame_data(),
+                trace.trim.as_ref(),
+                trace.source_path.as_deref(),
+            )?;
+        }
+
+        CapturesCommands::Delete { id } => match storage.delete_capture_trace(&id) {
+            Ok(_) => {
+                println!(
+                    "{} Capture '{}' deleted.",
+                    "✓".bright_green(),
+                    id.bright_cyan()
+                );
+            }
+            Err(err) => {
+                println!(
+                    "{} {}",
+                    "Error:".bright_red(),
+                    err.to_string().bright_yellow()
+                );
+            }
+        },
+    }
+
+    Ok(())
+}
+
+/// Run history subcommands
+fn run_history_command(command: HistoryCommands) -> Result<()> {
+    let storage = storage::init_storage()?;
+
+    match command {
+        HistoryCommands::List { filter } => {
+            let entries = storage.list_history(&filter.into())?;
+
+            if entries.is_empty() {
+                println!("{}", "No matching history entries found.".bright_yellow());
+                println!(
+                    "{} Results are recorded automatically when you submit, queue or preview a benchmark.",
+                    "Tip:".bright_cyan()
+                );
+            } else {
+                println!("{}", "Benchmark History:\n".bright_cyan().bold());
+                for (idx, entry) in entries.iter().enumerate() {
+                    let submission = &entry.submission;
+                    println!(
+                        "  {} {} | {} | {} | {} {} | {:.1} FPS avg{} | {}",
+                        entry.id.bright_white(),
+                        format!("[{}]", entry.status).bright_black(),
+                        entry
+                            .recorded_at
+                            .format("%Y-%m-%d %H:%M")
+                            .to_string()
+                            .bright_white(),
+                        submission.game.bright_cyan(),
+                        submission.resolution,
+                        submission.preset,
+                        submission.avg_fps,
+                        submission
+                            .fps_1_low
+                            .map(|low| format!(", {:.1} 1% low", low))
+                            .unwrap_or_default(),
+                        submission.system_info.gpu.name
+                    );
+                    // Entries are newest first, so the previous run is the next one.
+                    if let Some(previous) = entries.get(idx + 1) {
+                        if previous.submission.system_info.hardware_fingerprint()
+                            != submission.system_info.hardware_fingerprint()
+                        {
+                            println!(
+                                "    {}",
+                                "⚙ Hardware changed since the previous run".bright_yellow()
+                            );
+                        }
                     }
                 }
+                println!(
+                    "\n{} Use 'fps-tracker history show <id>' for the full record.",
+                    "Tip:".bright_cyan()
+                );
+            }
+        }
+
+        HistoryCommands::Show { id } => {
+            let entry = storage.load_history_entry(&id)?;
+
+            println!("{} {}", "Entry:".bright_green(), entry.id.bright_white());
+            println!(
+                "{} {}",
+                "Status:".bright_green(),
+                entry.status.to_string().bright_white()
+            );
+            println!(
+                "{} {}",
+                "Recorded:".bright_green(),
+                entry.recorded_at.to_rfc3339().bright_white()
+            );
+            if let Some(receipt_id) = entry.receipt_id.as_deref() {
+                println!(
+                    "{} {}",
+                    "Receipt id:".bright_green(),
+                    receipt_id.bright_white()
+                );
+            }
+            println!("\n{}", entry.submission.display());
+        }
+
+        HistoryCommands::Export {
+            filter,
+            format,
+            output,
+        } => {
+            let entries = storage.list_history(&filter.into())?;
+            let mut writer: Box<dyn Write> = match output.as_deref() {
+                Some(path) => Box::new(
+                    File::create(path)
+                        .with_context(|| format!("Failed to create {}", path.display()))?,
+                ),
+                None => Box::new(io::stdout().lock()),
+            };
+
+            match format {
+                ExportFormat::Json => {
+                    serde_json::to_writer_pretty(&mut writer, &entries)?;
+                    writeln!(writer)?;
+                }
+                ExportFormat::Csv => storage::write_history_csv(&entries, &mut writer)?,
+            }
+            writer.flush()?;
+
+            if let Some(path) = output.as_deref() {
+                println!(
+                    "{} {} entries to {}",
+                    "Exported".bright_green(),
+                    entries.len(),
+                    path.display().to_string().bright_white()
+                );
             }
         }
     }
@@ -3499,11 +4509,147 @@ fn run_benchmark_command(command: BenchmarkCommands) -> Result<()> {
     Ok(())
 }
 
+/// Run the regressions command
+fn run_regressions_command(
+    filter: storage::HistoryFilter,
+    min_change_pct: f64,
+    format: OutputFormat,
+) -> Result<()> {
+    if !min_change_pct.is_finite() || min_change_pct < 0.0 {
+        anyhow::bail!("--min-change-pct must be a non-negative number");
+    }
+    let entries = storage::init_storage()?.list_history(&filter)?;
+    let report = benchmark::regressions::detect_regressions(&entries, min_change_pct);
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Text => print_regression_report(&report, entries.len()),
+    }
+    Ok(())
+}
+
+fn print_regression_report(
+    report: &benchmark::regressions::RegressionReport,
+    history_entries: usize,
+) {
+    println!("{}", "Regression Check:\n".bright_cyan().bold());
+    println!(
+        "{} {} history entries, {} configurations with a driver/kernel/OS change",
+        "Scanned:".bright_green(),
+        history_entries,
+        report.groups_compared
+    );
+
+    if report.groups_compared == 0 {
+        println!(
+            "\n{} Record the same game, resolution and preset before and after an update to compare them.",
+            "Tip:".bright_cyan()
+        );
+        return;
+    }
+    if report.findings.is_empty() {
+        println!(
+            "\n{}",
+            "✓ No FPS shifts beyond run-to-run noise after any version change.".bright_green()
+        );
+        return;
+    }
+
+    for finding in &report.findings {
+        let heading = if finding.is_regression() {
+            "✗ Regression:".bright_red().bold()
+        } else {
+            "✓ Improvement:".bright_green().bold()
+        };
+        println!("\n{} {}", heading, finding.group.to_string().bright_white());
+        println!(
+            "  {} {:.1} → {:.1} ({:+.1}%, noise ±{:.1}%, {} → {} runs)",
+            format!("{}:", finding.metric).bright_green(),
+            finding.before_mean,
+            finding.after_mean,
+            finding.delta_pct,
+            finding.noise_pct,
+            finding.before_runs,
+            finding.after_runs
+        );
+        let causes = finding
+            .suspected_causes
+            .iter()
+            .map(|change| change.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "  {} {} (since {})",
+            "Suspected cause:".bright_green(),
+            causes.bright_yellow(),
+            finding.changed_at.format("%Y-%m-%d")
+        );
+    }
+}
+
+/// Build components from detected hardware
+fn detected_build_components(system_info: &SystemInfo) -> storage::BuildComponents {
+    use storage::{BuildComponents, ComponentSpec};
+
+    let motherboard = system_info.motherboard.as_ref().and_then(|board| {
+        let mut spec = ComponentSpec::new(board.model.clone().or_else(|| board.name())?);
+        if let Some(ref vendor) = board.vendor {
+            spec = spec.with_brand(vendor);
+        }
+        if let Some(ref version) = board.bios_version {
+            spec = spec.with_spec("bios_version", version.as_str());
+        }
+        if let Some(ref date) = board.bios_date {
+            spec = spec.with_spec("bios_date", date.as_str());
+        }
+        Some(spec)
+    });
+    let storage = system_info
+        .drives
+        .iter()
+        .map(|drive| {
+            ComponentSpec::new(drive.model.as_deref().unwrap_or(&drive.device))
+                .with_spec("type", drive.kind.to_string())
+                .with_spec("capacity_gb", drive.capacity_gb as i64)
+        })
+        .collect();
+
+    BuildComponents {
+        cpu: Some(
+            ComponentSpec::new(&system_info.cpu.name)
+                .with_brand(&system_info.cpu.vendor)
+                .with_spec("cores", system_info.cpu.cores as i32)
+                .with_spec("threads", system_info.cpu.threads as i32)
+                .with_spec(
+                    "frequency_mhz",
+                    system_info.cpu.frequency_mhz.unwrap_or(0) as i32,
+                ),
+        ),
+        gpu: Some(
+            ComponentSpec::new(&system_info.gpu.name)
+                .with_brand(system_info.gpu.vendor.to_string())
+                .with_spec("vram_mb", system_info.gpu.vram_mb.unwrap_or(0) as i32),
+        ),
+        motherboard,
+        ram: Some(
+            ComponentSpec::new(format!("{}MB RAM", system_info.ram.usable_mb))
+                .with_spec("usable_mb", system_info.ram.usable_mb as i32)
+                .with_spec(
+                    "type",
+                    system_info.ram.ram_type.as_deref().unwrap_or("Unknown"),
+                )
+                .with_spec("speed_mhz", system_info.ram.speed_mhz.unwrap_or(0) as i32),
+        ),
+        storage,
+        ..Default::default()
+    }
+}
+
 /// Run build subcommands
 fn run_build_command(command: BuildCommands) -> Result<()> {
     use chrono::Utc;
     use colored::*;
-    use storage::{BuildComponents, BuildConfig, ComponentSpec};
+    use storage::BuildConfig;
 
     let storage = storage::init_storage()?;
 
@@ -3521,36 +4667,7 @@ fn run_build_command(command: BuildCommands) -> Result<()> {
                 let build = BuildConfig {
                     name: "Current System".to_string(),
                     created_at: Utc::now(),
-                    components: BuildComponents {
-                        cpu: Some(
-                            ComponentSpec::new(&system_info.cpu.name)
-                                .with_brand(&system_info.cpu.vendor)
-                                .with_spec("cores", system_info.cpu.cores as i32)
-                                .with_spec("threads", system_info.cpu.threads as i32)
-                                .with_spec(
-                                    "frequency_mhz",
-                                    system_info.cpu.frequency_mhz.unwrap_or(0) as i32,
-                                ),
-                        ),
-                        gpu: Some(
-                            ComponentSpec::new(&system_info.gpu.name)
-                                .with_brand(system_info.gpu.vendor.to_string())
-                                .with_spec("vram_mb", system_info.gpu.vram_mb.unwrap_or(0) as i32),
-                        ),
-                        ram: Some(
-                            ComponentSpec::new(format!("{}MB RAM", system_info.ram.usable_mb))
-                                .with_spec("usable_mb", system_info.ram.usable_mb as i32)
-                                .with_spec(
-                                    "type",
-                                    system_info.ram.ram_type.as_deref().unwrap_or("Unknown"),
-                                )
-                                .with_spec(
-                                    "speed_mhz",
-                                    system_info.ram.speed_mhz.unwrap_or(0) as i32,
-                                ),
-                        ),
-                        ..Default::default()
-                    },
+                    components: detected_build_components(&system_info),
                     notes: Some("Auto-detected from current system".to_string()),
                 };
 
@@ -3604,33 +4721,7 @@ fn run_build_command(command: BuildCommands) -> Result<()> {
             let build = BuildConfig {
                 name: name.clone(),
                 created_at: Utc::now(),
-                components: BuildComponents {
-                    cpu: Some(
-                        ComponentSpec::new(&system_info.cpu.name)
-                            .with_brand(&system_info.cpu.vendor)
-                            .with_spec("cores", system_info.cpu.cores as i32)
-                            .with_spec("threads", system_info.cpu.threads as i32)
-                            .with_spec(
-                                "frequency_mhz",
-                                system_info.cpu.frequency_mhz.unwrap_or(0) as i32,
-                            ),
-                    ),
-                    gpu: Some(
-                        ComponentSpec::new(&system_info.gpu.name)
-                            .with_brand(system_info.gpu.vendor.to_string())
-                            .with_spec("vram_mb", system_info.gpu.vram_mb.unwrap_or(0) as i32),
-                    ),
-                    ram: Some(
-                        ComponentSpec::new(format!("{}MB RAM", system_info.ram.usable_mb))
-                            .with_spec("usable_mb", system_info.ram.usable_mb as i32)
-                            .with_spec(
-                                "type",
-                                system_info.ram.ram_type.as_deref().unwrap_or("Unknown"),
-                            )
-                            .with_spec("speed_mhz", system_info.ram.speed_mhz.unwrap_or(0) as i32),
-                    ),
-                    ..Default::default()
-                },
+                components: detected_build_components(&system_info),
                 notes,
             };
 
@@ -3723,6 +4814,24 @@ fn check_build_compatibility(
         }
     }
 
+    // Games load slowly (and some stream assets badly) from spinning disks
+    let drive_type = |drive: &storage::ComponentSpec| {
+        drive
+            .specs
+            .get("type")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+    if !build.components.storage.is_empty()
+        && build
+            .components
+            .storage
+            .iter()
+            .all(|drive| drive_type(drive).as_deref() == Some("HDD"))
+    {
+        warnings.push("No SSD detected (an SSD is recommended for modern games)".to_string());
+    }
+
     // Output results
     match format {
         OutputFormat::Json => {
@@ -3735,6 +4844,8 @@ fn check_build_compatibility(
                     "cpu": build.components.cpu.as_ref().map(|c| &c.name),
                     "gpu": build.components.gpu.as_ref().map(|c| &c.name),
                     "ram": build.components.ram.as_ref().map(|c| &c.name),
+                    "motherboard": build.components.motherboard.as_ref().map(|c| &c.name),
+                    "storage": build.components.storage.iter().map(|c| &c.name).collect::<Vec<_>>(),
                 }
             });
             println!("{}", serde_json::to_string_pretty(&result)?);
@@ -3784,6 +4895,38 @@ fn check_build_compatibility(
                     format!("({}MHz)", speed).bright_black()
                 );
             }
+            if let Some(ref board) = build.components.motherboard {
+                let bios = board
+                    .specs
+                    .get("bios_version")
+                    .and_then(|v| v.as_str())
+                    .map(|version| format!("(BIOS {})", version))
+                    .unwrap_or_default();
+                println!(
+                    "  {} {} {}",
+                    "Board:".bright_yellow(),
+                    board.name.bright_white(),
+                    bios.bright_black()
+                );
+            }
+            for drive in &build.components.storage {
+                let capacity_gb = drive
+                    .specs
+                    .get("capacity_gb")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(0);
+                println!(
+                    "  {} {} {}",
+                    "Disk:".bright_yellow(),
+                    drive.name.bright_white(),
+                    format!(
+                        "({}, {} GB)",
+                        drive_type(drive).as_deref().unwrap_or("Unknown"),
+                        capacity_gb
+                    )
+                    .bright_black()
+                );
+            }
 
             // Issues
             if !issues.is_empty() {
@@ -4552,19 +5695,20 @@ fn dependency_install_hint(tool: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::{
-        guard_live_capture_safety, is_existing_mangohud_capture_file, parse_game_batch_input,
-        process_name_hint_for_game, source_requires_presentmon, CaptureSource, Cli,
+        detect_capture_format, guard_live_capture_safety, is_existing_mangohud_capture_file,
+        parse_game_batch_input, process_name_hint_for_game, source_requires_presentmon,
+        CaptureFormat, CaptureSource, Cli,
     };
-    use crate::games::KNOWN_GAMES;
+    use crate::games::known_games;
     use clap::Parser;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
     fn game_index_1_based(game_name: &str) -> usize {
-        KNOWN_GAMES
+        known_games()
             .iter()
             .position(|g| g.name == game_name)
-            .unwrap_or_else(|| panic!("Expected game to exist in KNOWN_GAMES: {game_name}"))
+            .unwrap_or_else(|| panic!("Expected game to exist in known games: {game_name}"))
             + 1
     }
 
@@ -4639,6 +5783,7 @@ mod tests {
     fn source_requirement_for_presentmon_matches_auto_fallback_rules() {
         assert!(source_requires_presentmon(CaptureSource::PresentMon, true));
         assert!(!source_requires_presentmon(CaptureSource::MangoHud, false));
+        assert!(!source_requires_presentmon(CaptureSource::Replay, false));
         assert!(source_requires_presentmon(CaptureSource::Auto, false));
         assert!(!source_requires_presentmon(CaptureSource::Auto, true));
     }
@@ -4651,6 +5796,43 @@ mod tests {
         assert!(is_existing_mangohud_capture_file(file.path()));
     }
 
+    #[test]
+    fn capture_format_detection_prefers_specific_presentmon_schemas() {
+        let cases = [
+            (
+                "Application,ProcessID,Runtime,MsBetweenPresents,MsBetweenDisplayChange,GPU0Clk(MHz)",
+                CaptureFormat::FrameView,
+            ),
+            (
+                "Application,ProcessID,MsBetweenPresents,Motion-to-Photon Latency,AppMissed",
+                CaptureFormat::Ocat,
+            ),
+            (
+                "Application,ProcessID,PresentRuntime,CPUStartTime,FrameTime,CPUBusy",
+                CaptureFormat::PresentMon2,
+            ),
+            (
+                "Application,ProcessID,TimeInSeconds,MsBetweenPresents",
+                CaptureFormat::CapFrameX,
+            ),
+            ("fps,frametime,cpu_load", CaptureFormat::MangoHud),
+            (
+                "00, 16-10-2024 20:15:41, Hardware monitoring log v1.6",
+                CaptureFormat::Afterburner,
+            ),
+        ];
+
+        for (header, expected) in cases {
+            let mut file = NamedTempFile::new().expect("temp file");
+            writeln!(file, "{header}").expect("write header");
+            assert_eq!(
+                detect_capture_format(file.path()).expect("format"),
+                expected,
+                "{header}"
+            );
+        }
+    }
+
     #[test]
     fn doctor_yes_flag_requires_fix_flag() {
         assert!(Cli::try_parse_from(["fps-tracker", "doctor", "--yes"]).is_err());
@@ -0,0 +1,353 @@
+//! Persisted frametime traces
+//!
+//! Every live capture and import is kept under `captures/` as a compact JSON
+//! trace so it can be re-analysed or re-submitted without re-running the game.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+use super::{is_valid_pending_id, open_private_file_new, LocalStorage};
+use crate::benchmark::live::LiveCaptureResult;
+use crate::import::trim::TrimReport;
+use crate::import::{FrameData, FrameTelemetry};
+
+const TRACE_PREFIX: &str = "trace_";
+
+/// Where a trace came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureTraceKind {
+    Live,
+    Import,
+}
+
+impl std::fmt::Display for CaptureTraceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureTraceKind::Live => write!(f, "live"),
+            CaptureTraceKind::Import => write!(f, "import"),
+        }
+    }
+}
+
+/// Live capture diagnostics kept alongside the trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureTraceDiagnostics {
+    pub focus_pauses: u32,
+    pub samples_dropped_unfocused: usize,
+    pub capture_quality_score: u8,
+    pub longest_unfocused_ms: u64,
+    pub total_unfocused_ms: u64,
+    pub dropped_sample_ratio: f64,
+    pub stutter_spike_count: usize,
+    pub stutter_spike_ratio: f64,
+    pub unstable_capture: bool,
+}
+
+/// A stored frametime trace with its capture metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureTrace {
+    #[serde(default)]
+    pub id: String,
+    pub saved_at: DateTime<Utc>,
+    pub kind: CaptureTraceKind,
+    pub game: Option<String>,
+    pub source: String,
+    pub started_at: Option<DateTime<Utc>>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_secs: f64,
+    pub target_process: Option<String>,
+    /// Original capture or import file, if any.
+    pub source_path: Option<PathBuf>,
+    pub diagnostics: Option<CaptureTraceDiagnostics>,
+    /// Trimming applied before the trace was stored.
+    #[serde(default)]
+    pub trim: Option<TrimReport>,
+    pub frame_times_ms: Vec<f64>,
+    /// Per-frame hardware telemetry aligned with `frame_times_ms`.
+    #[serde(default)]
+    pub telemetry: FrameTelemetry,
+    /// Frame times are polled samples rather than one per frame.
+    #[serde(default)]
+    pub sampled: bool,
+}
+
+/// Lightweight view of a stored trace for listings.
+#[derive(Debug, Clone)]
+pub struct CaptureTraceSummary {
+    pub id: String,
+    pub saved_at: DateTime<Utc>,
+    pub kind: CaptureTraceKind,
+    pub game: Option<String>,
+    pub source: String,
+    pub frame_count: usize,
+    pub duration_secs: f64,
+}
+
+impl CaptureTrace {
+    pub fn from_live_result(result: &LiveCaptureResult) -> Self {
+        Self {
+            id: String::new(),
+            saved_at: Utc::now(),
+            kind: CaptureTraceKind::Live,
+            game: result.game_hint.clone(),
+            source: result.source.clone(),
+            started_at: Some(result.started_at),
+            ended_at: Some(result.ended_at),
+            duration_secs: result.duration_secs,
+            target_process: result.target_process.clone(),
+            source_path: result.capture_path.clone(),
+            diagnostics: Some(CaptureTraceDiagnostics {
+                focus_pauses: result.focus_pauses,
+                samples_dropped_unfocused: result.samples_dropped_unfocused,
+                capture_quality_score: result.capture_quality_score,
+                longest_unfocused_ms: result.longest_unfocused_ms,
+                total_unfocused_ms: result.total_unfocused_ms,
+                dropped_sample_ratio: result.dropped_sample_ratio,
+                stutter_spike_count: result.stutter_spike_count,
+                stutter_spike_ratio: result.stutter_spike_ratio,
+                unstable_capture: result.unstable_capture,
+            }),
+            trim: result.trim.clone(),
+            frame_times_ms: compact_frame_times(&result.frame_times_ms),
+            telemetry: result.frame_telemetry.clone(),
+            sampled: false,
+        }
+    }
+
+    pub fn from_frame_data(data: &FrameData, source_path: Option<PathBuf>) -> Self {
+        Self {
+            id: String::new(),
+            saved_at: Utc::now(),
+            kind: CaptureTraceKind::Import,
+            game: data.application.clone(),
+            source: data.source.clone(),
+            started_at: None,
+            ended_at: None,
+            duration_secs: data.duration_secs,
+            target_process: None,
+            source_path,
+            diagnostics: None,
+            trim: None,
+            frame_times_ms: compact_frame_times(&data.frame_times_ms),
+            telemetry: data.telemetry.clone(),
+            sampled: data.sampled,
+        }
+    }
+
+    /// Rebuild frame data so the trace can go through the import pipeline again.
+    pub fn to_frame_data(&self) -> FrameData {
+        FrameData {
+            frame_times_ms: self.frame_times_ms.clone(),
+            application: self.game.clone(),
+            duration_secs: self.duration_secs,
+            source: self.source.clone(),
+            telemetry: self.telemetry.clone(),
+            sampled: self.sampled,
+        }
+    }
+
+    fn summary(&self) -> CaptureTraceSummary {
+        CaptureTraceSummary {
+            id: self.id.clone(),
+            saved_at: self.saved_at,
+            kind: self.kind,
+            game: self.game.clone(),
+            source: self.source.clone(),
+            frame_count: self.frame_times_ms.len(),
+            duration_secs: self.duration_secs,
+        }
+    }
+}
+
+/// Round to microsecond precision; more digits only bloat the file.
+fn compact_frame_times(frame_times_ms: &[f64]) -> Vec<f64> {
+    frame_times_ms
+        .iter()
+        .map(|value| (value * 1000.0).round() / 1000.0)
+        .collect()
+}
+
+impl LocalStorage {
+    /// Save a capture trace and return its id.
+    pub fn save_capture_trace(&self, trace: &CaptureTrace) -> Result<String> {
+        if trace.frame_times_ms.is_empty() {
+            anyhow::bail!("Capture trace has no frame times");
+        }
+
+        let captures_dir = self.data_dir.join("captures");
+        fs::create_dir_all(&captures_dir).with_context(|| {
+            format!(
+                "Failed to create capture directory: {}",
+                captures_dir.display()
+            )
+        })?;
+
+        let id = format!(
+            "{TRACE_PREFIX}{}_{}",
+            trace.saved_at.timestamp_millis(),
+            Uuid::new_v4().simple()
+        );
+        let mut record = trace.clone();
+        record.id = id.clone();
+
+        let path = captures_dir.join(format!("{id}.json"));
+        let json = serde_json::to_string(&record).context("Failed to serialize capture trace")?;
+        let mut file = open_private_file_new(&path)
+            .with_context(|| format!("Failed to create capture trace at {}", path.display()))?;
+        file.write_all(json.as_bytes())
+            .with_context(|| format!("Failed to write capture trace to {}", path.display()))?;
+
+        Ok(id)
+    }
+
+    /// Load a stored capture trace by id.
+    pub fn load_capture_trace(&self, id: &str) -> Result<CaptureTrace> {
+        let path = self.capture_trace_path(id)?;
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Capture '{}' not found", id.trim()))?;
+        let mut trace: CaptureTrace = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse capture trace: {}", path.display()))?;
+        trace.id = id.trim().to_string();
+        Ok(trace)
+    }
+
+    /// List stored capture traces, newest first. Unreadable files are skipped.
+    pub fn list_capture_traces(&self) -> Result<Vec<CaptureTraceSummary>> {
+        let captures_dir = self.data_dir.join("captures");
+        let mut traces = Vec::new();
+
+        if !captures_dir.exists() {
+            return Ok(traces);
+        }
+
+        for entry in fs::read_dir(&captures_dir)? {
+            let path = entry?.path();
+            if !path.extension().map(|e| e == "json").unwrap_or(false) {
+                continue;
+            }
+            let Some(id) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if !id.starts_with(TRACE_PREFIX) {
+                continue;
+            }
+            if let Ok(trace) = self.load_capture_trace(id) {
+                traces.push(trace.summary());
+            }
+        }
+
+        traces.sort_by_key(|trace| std::cmp::Reverse(trace.saved_at));
+
+        Ok(traces)
+    }
+
+    /// Delete a stored capture trace.
+    pub fn delete_capture_trace(&self, id: &str) -> Result<()> {
+        let path = self.capture_trace_path(id)?;
+        if !path.exists() {
+            anyhow::bail!("Capture '{}' not found", id.trim());
+        }
+        fs::remove_file(&path)
+            .with_context(|| format!("Failed to delete capture trace: {}", path.display()))?;
+        Ok(())
+    }
+
+    fn capture_trace_path(&self, id: &str) -> Result<PathBuf> {
+        let id = id.trim();
+        if !id.starts_with(TRACE_PREFIX) || !is_valid_pending_id(id) {
+            anyhow::bail!("Invalid capture id: {}", id);
+        }
+        Ok(self.data_dir.join("captures").join(format!("{id}.json")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample_frame_data() -> FrameData {
+        FrameData {
+            frame_times_ms: vec![16.666666666; 120],
+            application: Some("Cyberpunk 2077".to_string()),
+            duration_secs: 2.0,
+            source: "MangoHud".to_string(),
+            telemetry: FrameTelemetry::default(),
+            sampled: false,
+        }
+    }
+
+    #[test]
+    fn test_save_list_load_and_delete_capture_trace() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = LocalStorage {
+            data_dir: temp_dir.path().to_path_buf(),
+        };
+
+        let trace = CaptureTrace::from_frame_data(
+            &sample_frame_data(),
+            Some(PathBuf::from("/tmp/cyberpunk.csv")),
+        );
+        let id = storage.save_capture_trace(&trace).unwrap();
+
+        let listed = storage.list_capture_traces().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, id);
+        assert_eq!(listed[0].kind, CaptureTraceKind::Import);
+        assert_eq!(listed[0].frame_count, 120);
+
+        let loaded = storage.load_capture_trace(&id).unwrap();
+        assert_eq!(loaded.game.as_deref(), Some("Cyberpunk 2077"));
+        assert_eq!(loaded.frame_times_ms[0], 16.667);
+        assert!(loaded.to_frame_data().calculate_stats().is_some());
+
+        storage.delete_capture_trace(&id).unwrap();
+        assert!(storage.list_capture_traces().unwrap().is_empty());
+        assert!(storage.load_capture_trace(&id).is_err());
+    }
+
+    #[test]
+    fn test_capture_trace_keeps_telemetry_for_reanalysis() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = LocalStorage {
+            data_dir: temp_dir.path().to_path_buf(),
+        };
+
+        let mut data = sample_frame_data();
+        data.telemetry.gpu_load_pct = vec![98.0; 120];
+        data.telemetry.gpu_busy_ms = vec![16.0; 120];
+        let id = storage
+            .save_capture_trace(&CaptureTrace::from_frame_data(&data, None))
+            .unwrap();
+
+        let restored = storage.load_capture_trace(&id).unwrap().to_frame_data();
+        assert_eq!(restored.telemetry, data.telemetry);
+    }
+
+    #[test]
+    fn test_capture_listing_ignores_presentmon_csv_and_rejects_bad_ids() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = LocalStorage {
+            data_dir: temp_dir.path().to_path_buf(),
+        };
+        fs::create_dir_all(storage.data_dir.join("captures")).unwrap();
+        fs::write(
+            storage
+                .data_dir
+                .join("captures")
+                .join("presentmon_capture_1_abc.csv"),
+            "Application,MsBetweenPresents\n",
+        )
+        .unwrap();
+
+        assert!(storage.list_capture_traces().unwrap().is_empty());
+        assert!(storage.load_capture_trace("../config").is_err());
+        assert!(storage.delete_capture_trace("pending_123").is_err());
+    }
+}
@@ -0,0 +1,482 @@
+//! Local benchmark history
+//!
+//! Every benchmark result is recorded under `history/` with its hardware snapshot,
+//! game settings and upload outcome, whether it was submitted, queued for retry or
+//! never sent. Entries outlive the pending queue so past runs can be browsed and exported.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+use super::{
+    is_valid_pending_id, open_private_file_new, open_private_file_overwrite, LocalStorage,
+};
+use crate::benchmark::{BenchmarkSubmission, SubmissionResponse};
+
+const HISTORY_PREFIX: &str = "history_";
+
+/// What happened to a recorded result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryStatus {
+    /// Accepted by the backend.
+    Submitted,
+    /// Waiting in the offline retry queue.
+    Queued,
+    /// Reached the backend but was rejected.
+    Rejected,
+    /// Kept locally only (cancelled, preview without `--submit`, or upload error).
+    NotSubmitted,
+}
+
+impl HistoryStatus {
+    pub fn from_response(response: &SubmissionResponse) -> Self {
+        if response.is_rejected() {
+            HistoryStatus::Rejected
+        } else {
+            HistoryStatus::Submitted
+        }
+    }
+}
+
+impl std::fmt::Display for HistoryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryStatus::Submitted => write!(f, "submitted"),
+            HistoryStatus::Queued => write!(f, "queued"),
+            HistoryStatus::Rejected => write!(f, "rejected"),
+            HistoryStatus::NotSubmitted => write!(f, "not submitted"),
+        }
+    }
+}
+
+/// A recorded benchmark result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    #[serde(default)]
+    pub id: String,
+    pub recorded_at: DateTime<Utc>,
+    pub status: HistoryStatus,
+    /// Submission id returned by the backend.
+    #[serde(default)]
+    pub receipt_id: Option<String>,
+    /// Idempotency key of the upload; links queued entries to their retries.
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    /// Full result including the hardware snapshot, game and settings.
+    pub submission: BenchmarkSubmission,
+}
+
+impl HistoryEntry {
+    pub fn new(submission: &BenchmarkSubmission, status: HistoryStatus) -> Self {
+        Self {
+            id: String::new(),
+            recorded_at: Utc::now(),
+            status,
+            receipt_id: None,
+            idempotency_key: None,
+            submission: submission.clone(),
+        }
+    }
+}
+
+/// Filters for `history list` and `history export`. Empty fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    /// Case-insensitive substring of the game name.
+    pub game: Option<String>,
+    /// Case-insensitive resolution label (e.g. "1440p").
+    pub resolution: Option<String>,
+    /// First day to include (UTC).
+    pub since: Option<NaiveDate>,
+    /// Last day to include (UTC).
+    pub until: Option<NaiveDate>,
+}
+
+impl HistoryFilter {
+    pub fn matches(&self, entry: &HistoryEntry) -> bool {
+        if let Some(game) = self.game.as_deref() {
+            if !entry
+                .submission
+                .game
+                .to_lowercase()
+                .contains(&game.trim().to_lowercase())
+            {
+                return false;
+            }
+        }
+        if let Some(resolution) = self.resolution.as_deref() {
+            if !entry
+                .submission
+                .resolution
+                .trim()
+                .eq_ignore_ascii_case(resolution.trim())
+            {
+                return false;
+            }
+        }
+        let day = entry.recorded_at.date_naive();
+        if self.since.is_some_and(|since| day < since) {
+            return false;
+        }
+        if self.until.is_some_and(|until| day > until) {
+            return false;
+        }
+        true
+    }
+}
+
+/// Flat CSV row; nested hardware fields are reduced to the ones people filter on.
+#[derive(Serialize)]
+struct HistoryCsvRow<'a> {
+    id: &'a str,
+    recorded_at: String,
+    status: String,
+    receipt_id: Option<&'a str>,
+    game: &'a str,
+    resolution: &'a str,
+    preset: &'a str,
+    ray_tracing: bool,
+    upscaling: Option<&'a str>,
+    frame_gen: Option<bool>,
+    avg_fps: f64,
+    fps_1_low: Option<f64>,
+    fps_01_low: Option<f64>,
+    duration_secs: Option<f64>,
+    benchmark_tool: Option<&'a str>,
+    cpu: &'a str,
+    gpu: &'a str,
+    gpu_driver: Option<&'a str>,
+    ram_mb: u64,
+    os: &'a str,
+    os_version: Option<&'a str>,
+}
+
+/// Write history entries as CSV, one row per result.
+pub fn write_history_csv<W: io::Write>(entries: &[HistoryEntry], writer: W) -> Result<()> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    for entry in entries {
+        let submission = &entry.submission;
+        let system = &submission.system_info;
+        csv_writer
+            .serialize(HistoryCsvRow {
+                id: &entry.id,
+                recorded_at: entry.recorded_at.to_rfc3339(),
+                status: entry.status.to_string(),
+                receipt_id: entry.receipt_id.as_deref(),
+                game: &submission.game,
+                resolution: &submission.resolution,
+                preset: &submission.preset,
+                ray_tracing: submission.ray_tracing,
+                upscaling: submission.upscaling.as_deref(),
+                frame_gen: submission.frame_gen,
+                avg_fps: submission.avg_fps,
+                fps_1_low: submission.fps_1_low,
+                fps_01_low: submission.fps_01_low,
+                duration_secs: submission.duration_secs,
+                benchmark_tool: submission.benchmark_tool.as_deref(),
+                cpu: &system.cpu.name,
+                gpu: &system.gpu.name,
+                gpu_driver: system.gpu.driver_version.as_deref(),
+                ram_mb: system.ram.usable_mb,
+                os: &system.os,
+                os_version: system.os_version.as_deref(),
+            })
+            .context("Failed to write history CSV row")?;
+    }
+    csv_writer.flush().context("Failed to flush history CSV")?;
+    Ok(())
+}
+
+impl LocalStorage {
+    /// Record a result in the local history and return its id.
+    pub fn record_history(&self, entry: &HistoryEntry) -> Result<String> {
+        let history_dir = self.data_dir.join("history");
+        fs::create_dir_all(&history_dir).with_context(|| {
+            format!(
+                "Failed to create history directory: {}",
+                history_dir.display()
+            )
+        })?;
+
+        let id = format!(
+            "{HISTORY_PREFIX}{}_{}",
+            entry.recorded_at.timestamp_millis(),
+            Uuid::new_v4().simple()
+        );
+        let mut record = entry.clone();
+        record.id = id.clone();
+
+        let path = history_dir.join(format!("{id}.json"));
+        let json =
+            serde_json::to_string_pretty(&record).context("Failed to serialize history entry")?;
+        let mut file = open_private_file_new(&path)
+            .with_context(|| format!("Failed to create history entry at {}", path.display()))?;
+        file.write_all(json.as_bytes())
+            .with_context(|| format!("Failed to write history entry to {}", path.display()))?;
+
+        Ok(id)
+    }
+
+    /// Load a history entry by id.
+    pub fn load_history_entry(&self, id: &str) -> Result<HistoryEntry> {
+        let path = self.history_entry_path(id)?;
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("History entry '{}' not found", id.trim()))?;
+        let mut entry: HistoryEntry = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse history entry: {}", path.display()))?;
+        entry.id = id.trim().to_string();
+        Ok(entry)
+    }
+
+    /// List history entries matching `filter`, newest first. Unreadable files are skipped.
+    pub fn list_history(&self, filter: &HistoryFilter) -> Result<Vec<HistoryEntry>> {
+        let history_dir = self.data_dir.join("history");
+        let mut entries = Vec::new();
+
+        if !history_dir.exists() {
+            return Ok(entries);
+        }
+
+        for dir_entry in fs::read_dir(&history_dir)? {
+            let path = dir_entry?.path();
+            if !path.extension().map(|e| e == "json").unwrap_or(false) {
+                continue;
+            }
+            let Some(id) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if !id.starts_with(HISTORY_PREFIX) {
+                continue;
+            }
+            if let Ok(entry) = self.load_history_entry(id) {
+                if filter.matches(&entry) {
+                    entries.push(entry);
+                }
+            }
+        }
+
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.recorded_at));
+
+        Ok(entries)
+    }
+
+    /// Update the status of the entry uploaded with `idempotency_key`, e.g. once a
+    /// queued submission has been retried. Returns whether an entry was found.
+    pub fn update_history_status(
+        &self,
+        idempotency_key: &str,
+        status: HistoryStatus,
+        receipt_id: Option<String>,
+    ) -> Result<bool> {
+        let entries = self.list_history(&HistoryFilter::default())?;
+        let Some(mut entry) = entries
+            .into_iter()
+            .find(|entry| entry.idempotency_key.as_deref() == Some(idempotency_key))
+        else {
+            return Ok(false);
+        };
+
+        entry.status = status;
+        if receipt_id.is_some() {
+            entry.receipt_id = receipt_id;
+        }
+
+        let path = self.history_entry_path(&entry.id)?;
+        let json =
+            serde_json::to_string_pretty(&entry).context("Failed to serialize history entry")?;
+        let mut file = open_private_file_overwrite(&path)
+            .with_context(|| format!("Failed to update history entry at {}", path.display()))?;
+        file.write_all(json.as_bytes())
+            .with_context(|| format!("Failed to write history entry to {}", path.display()))?;
+
+        Ok(true)
+    }
+
+    fn history_entry_path(&self, id: &str) -> Result<PathBuf> {
+        let id = id.trim();
+        if !id.starts_with(HISTORY_PREFIX) || !is_valid_pending_id(id) {
+            anyhow::bail!("Invalid history id: {}", id);
+        }
+        Ok(self.data_dir.join("history").join(format!("{id}.json")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::cpu::CpuInfo;
+    use crate::hardware::gpu::{GpuInfo, GpuVendor};
+    use crate::hardware::ram::RamInfo;
+    use crate::hardware::SystemInfo;
+    use chrono::Duration;
+    use tempfile::TempDir;
+
+    fn sample_submission(game: &str, resolution: &str) -> BenchmarkSubmission {
+        let system_info = SystemInfo {
+            gpu: GpuInfo {
+                name: "RTX 4070".to_string(),
+                vendor: GpuVendor::Nvidia,
+                pci_id: None,
+                vram_mb: Some(12288),
+                gpu_clock_mhz: None,
+                memory_clock_mhz: None,
+                temperature_c: None,
+                utilization_percent: None,
+                power_w: None,
+                driver_version: None,
+            },
+            cpu: CpuInfo {
+                name: "Ryzen 7 7800X3D".to_string(),
+                cores: 8,
+                threads: 16,
+                frequency_mhz: None,
+                max_frequency_mhz: None,
+                topology: None,
+                architecture: None,
+                vendor: "AMD".to_string(),
+            },
+            ram: RamInfo {
+                installed_mb: None,
+                usable_mb: 32768,
+                speed_mhz: None,
+                ram_type: None,
+                stick_count: None,
+                model: None,
+            },
+            os: "Linux".to_string(),
+            os_version: None,
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
+        };
+        BenchmarkSubmission::new(
+            system_info,
+            game.to_string(),
+            resolution.to_string(),
+            "High".to_string(),
+            120.0,
+            Some(90.0),
+            false,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_record_list_and_filter_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = LocalStorage {
+            data_dir: temp_dir.path().to_path_buf(),
+        };
+
+        let mut older = HistoryEntry::new(
+            &sample_submission("Cyberpunk 2077", "1440p"),
+            HistoryStatus::Submitted,
+        );
+        older.recorded_at = Utc::now() - Duration::days(10);
+        older.receipt_id = Some("sub_123".to_string());
+        let older_id = storage.record_history(&older).unwrap();
+        let newer_id = storage
+            .record_history(&HistoryEntry::new(
+                &sample_submission("Elden Ring", "1080p"),
+                HistoryStatus::NotSubmitted,
+            ))
+            .unwrap();
+
+        let all = storage.list_history(&HistoryFilter::default()).unwrap();
+        assert_eq!(
+            all.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(),
+            vec![newer_id.as_str(), older_id.as_str()]
+        );
+
+        let by_game = storage
+            .list_history(&HistoryFilter {
+                game: Some("cyberpunk".to_string()),
+                ..HistoryFilter::default()
+            })
+            .unwrap();
+        assert_eq!(by_game.len(), 1);
+        assert_eq!(by_game[0].receipt_id.as_deref(), Some("sub_123"));
+        assert_eq!(by_game[0].submission.system_info.gpu.name, "RTX 4070");
+
+        let by_resolution = storage
+            .list_history(&HistoryFilter {
+                resolution: Some("1080P".to_string()),
+                ..HistoryFilter::default()
+            })
+            .unwrap();
+        assert_eq!(by_resolution.len(), 1);
+        assert_eq!(by_resolution[0].submission.game, "Elden Ring");
+
+        let recent = storage
+            .list_history(&HistoryFilter {
+                since: Some((Utc::now() - Duration::days(2)).date_naive()),
+                ..HistoryFilter::default()
+            })
+            .unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].id, newer_id);
+
+        assert!(storage.load_history_entry("../config").is_err());
+    }
+
+    #[test]
+    fn test_update_history_status_marks_queued_entry_submitted() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = LocalStorage {
+            data_dir: temp_dir.path().to_path_buf(),
+        };
+
+        let mut queued = HistoryEntry::new(
+            &sample_submission("Cyberpunk 2077", "1440p"),
+            HistoryStatus::Queued,
+        );
+        queued.idempotency_key = Some("key-1".to_string());
+        let id = storage.record_history(&queued).unwrap();
+
+        assert!(!storage
+            .update_history_status("key-2", HistoryStatus::Submitted, None)
+            .unwrap());
+        assert!(storage
+            .update_history_status(
+                "key-1",
+                HistoryStatus::Submitted,
+                Some("sub_456".to_string())
+            )
+            .unwrap());
+
+        let entry = storage.load_history_entry(&id).unwrap();
+        assert_eq!(entry.status, HistoryStatus::Submitted);
+        assert_eq!(entry.receipt_id.as_deref(), Some("sub_456"));
+    }
+
+    #[test]
+    fn test_write_history_csv_flattens_hardware_snapshot() {
+        let mut entry = HistoryEntry::new(
+            &sample_submission("Cyberpunk 2077", "1440p"),
+            HistoryStatus::Queued,
+        );
+        entry.id = "history_1_abc".to_string();
+
+        let mut out = Vec::new();
+        write_history_csv(&[entry], &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+
+        let header = lines.next().unwrap();
+        assert!(header.starts_with("id,recorded_at,status,receipt_id,game,resolution,preset"));
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("history_1_abc,"));
+        assert!(row.contains(",queued,,Cyberpunk 2077,1440p,High,false,"));
+        assert!(row.contains(",Ryzen 7 7800X3D,RTX 4070,,32768,Linux,"));
+        assert!(lines.next().is_none());
+    }
+}
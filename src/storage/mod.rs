@@ -21,6 +21,12 @@ use crate::benchmark::BenchmarkSubmission;
 use crate::feedback::FeedbackSubmission;
 use crate::idempotency;
 
+mod captures;
+mod history;
+
+pub use captures::CaptureTrace;
+pub use history::{write_history_csv, HistoryEntry, HistoryFilter, HistoryStatus};
+
 /// Local storage manager for fps-tracker data
 pub struct LocalStorage {
     data_dir: PathBuf,
@@ -97,6 +103,7 @@ impl LocalStorage {
         fs::create_dir_all(data_dir.join("uploaded_feedback"))?;
         fs::create_dir_all(data_dir.join("builds"))?;
         fs::create_dir_all(data_dir.join("captures"))?;
+        fs::create_dir_all(data_dir.join("history"))?;
 
         Ok(Self { data_dir })
     }
@@ -906,6 +913,7 @@ mod tests {
                 memory_clock_mhz: None,
                 temperature_c: None,
                 utilization_percent: None,
+                power_w: None,
                 driver_version: None,
             },
             cpu: CpuInfo {
@@ -914,6 +922,7 @@ mod tests {
                 threads: 16,
                 frequency_mhz: Some(4200),
                 max_frequency_mhz: None,
+                topology: None,
                 architecture: Some("x86_64".to_string()),
                 vendor: "Unknown".to_string(),
             },
@@ -927,6 +936,13 @@ mod tests {
             },
             os: "Linux".to_string(),
             os_version: None,
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
         };
 
         let submission = BenchmarkSubmission::new(
@@ -1031,6 +1047,7 @@ mod tests {
                 memory_clock_mhz: None,
                 temperature_c: None,
                 utilization_percent: None,
+                power_w: None,
                 driver_version: None,
             },
             cpu: CpuInfo {
@@ -1039,6 +1056,7 @@ mod tests {
                 threads: 16,
                 frequency_mhz: Some(4200),
                 max_frequency_mhz: None,
+                topology: None,
                 architecture: Some("x86_64".to_string()),
                 vendor: "Unknown".to_string(),
             },
@@ -1052,6 +1070,13 @@ mod tests {
             },
             os: "Linux".to_string(),
             os_version: None,
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
         };
 
         let submission = BenchmarkSubmission::new(
@@ -1108,6 +1133,7 @@ mod tests {
                 memory_clock_mhz: None,
                 temperature_c: None,
                 utilization_percent: None,
+                power_w: None,
                 driver_version: None,
             },
             cpu: CpuInfo {
@@ -1116,6 +1142,7 @@ mod tests {
                 threads: 16,
                 frequency_mhz: Some(4200),
                 max_frequency_mhz: None,
+                topology: None,
                 architecture: Some("x86_64".to_string()),
                 vendor: "Unknown".to_string(),
             },
@@ -1129,6 +1156,13 @@ mod tests {
             },
             os: "Linux".to_string(),
             os_version: None,
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
         };
 
         let submission = BenchmarkSubmission::new(
@@ -1181,6 +1215,7 @@ mod tests {
                 memory_clock_mhz: None,
                 temperature_c: None,
                 utilization_percent: None,
+                power_w: None,
                 driver_version: None,
             },
             cpu: CpuInfo {
@@ -1189,6 +1224,7 @@ mod tests {
                 threads: 16,
                 frequency_mhz: Some(4200),
                 max_frequency_mhz: None,
+                topology: None,
                 architecture: Some("x86_64".to_string()),
                 vendor: "Unknown".to_string(),
             },
@@ -1202,6 +1238,13 @@ mod tests {
             },
             os: "Linux".to_string(),
             os_version: None,
+            kernel_version: None,
+            gpus: Vec::new(),
+            gpu_selection: None,
+            graphics_stack: None,
+            displays: Vec::new(),
+            motherboard: None,
+            drives: Vec::new(),
         };
 
         let submission = BenchmarkSubmission::new(
@@ -18,8 +18,9 @@ use crate::config::Config;
 #[cfg(any(target_os = "windows", target_os = "linux"))]
 use crate::deps;
 use crate::feedback::{self, FeedbackSubmission, FeedbackSurface};
-use crate::games::KNOWN_GAMES;
+use crate::games::known_games;
 use crate::hardware::SystemInfo;
+use crate::storage::{HistoryEntry, HistoryStatus};
 use crate::{api, idempotency, storage};
 
 use super::state::*;
@@ -743,7 +744,11 @@ pub(crate) fn handle_submit_benchmark(rt: &tokio::runtime::Runtime, app: &mut Ap
         &submission,
         &idempotency_key,
     )) {
-        Ok(_response) => {
+        Ok(response) => {
+            let mut entry = HistoryEntry::new(&submission, HistoryStatus::from_response(&response));
+            entry.receipt_id = Some(response.id.clone()).filter(|id| !id.trim().is_empty());
+            entry.idempotency_key = Some(idempotency_key);
+            let _ = storage::init_storage().and_then(|s| s.record_history(&entry));
             app.contribute.result_message = Some(MessageResultState {
                 title: "Submitted".to_string(),
                 body: "Thanks. Your benchmark was submitted.\n\nPress Enter to return.".to_string(),
@@ -756,6 +761,9 @@ pub(crate) fn handle_submit_benchmark(rt: &tokio::runtime::Runtime, app: &mut Ap
                     s.save_pending_benchmark_with_idempotency_key(&submission, &idempotency_key)
                 }) {
                     Ok(_pending_id) => {
+                        let mut entry = HistoryEntry::new(&submission, HistoryStatus::Queued);
+                        entry.idempotency_key = Some(idempotency_key);
+                        let _ = storage::init_storage().and_then(|s| s.record_history(&entry));
                         app.contribute.result_message = Some(MessageResultState {
                             title: "Queued locally".to_string(),
                             body: "Could not submit right now.\nSaved locally for retry.\n\nWe will retry automatically.\n\nPress Enter to return.".to_string(),
@@ -835,7 +843,7 @@ pub(crate) fn build_submission_preview(
         );
     }
 
-    let game = KNOWN_GAMES[game_idx].name.to_string();
+    let game = known_games()[game_idx].name.clone();
 
     let mut submission = BenchmarkSubmission::new(
         info,
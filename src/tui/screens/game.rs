@@ -5,7 +5,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, List, Paragraph};
 
-use crate::games::KNOWN_GAMES;
+use crate::games::known_games;
 use crate::tui::state::{filtered_games, App};
 use crate::tui::theme::Theme;
 use crate::tui::widgets::game_card::game_list_item;
@@ -56,7 +56,7 @@ pub(crate) fn draw_contribute_game(area: Rect, f: &mut ratatui::Frame, app: &App
         .enumerate()
         .map(|(pos, idx)| {
             let selected = (pos + scroll_start) == app.contribute.game.cursor;
-            let g = &KNOWN_GAMES[idx];
+            let g = &known_games()[idx];
             game_list_item(g, selected, &theme)
         })
         .collect();
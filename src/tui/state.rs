@@ -9,7 +9,7 @@ use std::sync::mpsc;
 use crate::benchmark_runner;
 use crate::config::Config;
 use crate::feedback::{self, FeedbackSurface};
-use crate::games::KNOWN_GAMES;
+use crate::games::known_games;
 use crate::hardware::SystemInfo;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -585,7 +585,7 @@ impl App {
 
 pub(crate) fn filtered_games(query: &str) -> Vec<usize> {
     let q = query.trim().to_lowercase();
-    KNOWN_GAMES
+    known_games()
         .iter()
         .enumerate()
         .filter(|(_, g)| {
@@ -4,7 +4,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::ListItem;
 
-use crate::games::{GameDifficulty, GameInfo};
+use crate::games::{AntiCheatRisk, GameDifficulty, GameInfo};
 use crate::tui::theme::Theme;
 
 pub(crate) fn game_list_item<'a>(
@@ -25,7 +25,7 @@ pub(crate) fn game_list_item<'a>(
     let mut spans = vec![
         Span::styled("▌", Style::default().fg(tier_color)),
         Span::styled(if selected { " › " } else { "   " }, name_style),
-        Span::styled(game.name, name_style),
+        Span::styled(game.name.as_str(), name_style),
     ];
 
     // Benchmark badge
@@ -46,40 +46,23 @@ pub(crate) fn game_list_item<'a>(
     }
 
     // Anti-cheat risk badge
-    let ac_risk = anti_cheat_risk(game.name);
-    match ac_risk {
-        AcRisk::High => {
+    match game.anti_cheat {
+        AntiCheatRisk::High => {
             spans.push(Span::raw(" "));
             spans.push(Span::styled(
                 "[AC Risk]",
                 Style::default().fg(theme.critical),
             ));
         }
-        AcRisk::Medium => {
+        AntiCheatRisk::Medium => {
             spans.push(Span::raw(" "));
             spans.push(Span::styled(
                 "[AC Caution]",
                 Style::default().fg(theme.caution),
             ));
         }
-        AcRisk::Low => {}
+        AntiCheatRisk::Low => {}
     }
 
     ListItem::new(Line::from(spans))
 }
-
-enum AcRisk {
-    Low,
-    Medium,
-    High,
-}
-
-fn anti_cheat_risk(name: &str) -> AcRisk {
-    match name {
-        "Valorant" | "League of Legends" => AcRisk::High,
-        "Counter-Strike 2" | "Fortnite" | "Apex Legends" | "Call of Duty: Warzone" => {
-            AcRisk::Medium
-        }
-        _ => AcRisk::Low,
-    }
-}